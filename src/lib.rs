@@ -19,10 +19,21 @@
 //!
 //! By default, the library will:
 //! - Display a "Vulnerabilities" section with vulnerability details when vulnerabilities exist
-//! - Display a "Vulnerabilities" section with a "No Vulnerabilities reported" message when no vulnerabilities exist
+//! - Display a "Vulnerabilities" section with a "No vulnerabilities found" message when the document lists no vulnerabilities,
+//!   or "No vulnerability data" when it has no vulnerabilities section at all
 //! - The "No Vulnerabilities" message display can be controlled with the `VEX2PDF_NOVULNS_MSG` environment variable
 //!   (set to "false" to hide the section entirely when no vulnerabilities exist)
+//! - Display a "Product Not Affected" banner when every listed vulnerability is analysed as
+//!   `not_affected` or `resolved`
 
+//!
+//! ## Cargo Features
+//!
+//! - `pdf` (default): PDF generation with embedded fonts, the `pdf` module, `lib_utils::config`
+//!   and [`run`]. Required by the `vex2pdf` binary. Without it the library only provides the
+//!   CycloneDX parsing with 1.6 compatibility handling, the model re-exports and the JSON and
+//!   SARIF summaries
+//! - `http`: Fetching of input documents from HTTP(S) URLs
 //!
 //! ## Font Configuration
 //!
@@ -32,9 +43,30 @@
 //!
 //! The library is organized into modules:
 //! - `pdf`: PDF generation functionality
+//!   - `badges`: Colored badge element used for analysis responses
 //!   - `font_config`: Font configuration and discovery
+//!   - `font_subset`: Font subsetting of generated PDF files
 //!   - `generator`: PDF document generation
+//!   - `links`: Clickable links added to rendered reports
+//!   - `merge`: Appending generated reports to existing ones
+//!   - `optimize`: Size optimization of generated PDF files
+//!   - `page_decorator`: Page borders, backgrounds and running headers
+//!   - `pdf_version`: PDF version in the header of generated PDF files
+//!   - `redaction`: Redaction of sensitive values in rendered text
+//!   - `score_gauge`: Gauge element visualizing CVSS scores
+//!   - `vulnerability_renderer`: Customization of how each vulnerability is rendered
+//!   - `xmp`: XMP metadata embedding for generated PDF files
 //! - `lib_utils`: Utilities and data models used in this library and accompanying runnable
+//!   - `completeness`: Data quality scorecard of the vulnerabilities of a document
+//!   - `console`: Progress output, moved to standard error when paths are printed
+//!   - `cvss`: Base, temporal and environmental scores of CVSS v3 vectors
+//!   - `io_retry`: Bounded retries for transient file I/O errors
+//!   - `purl`: Package registry pages of package URLs
+//!   - `ref_check`: Detection of dangling `bom-ref` references
+//!   - `remote`: Fetching of documents from HTTP(S) URLs behind the `http` feature
+//!   - `severity`: Qualitative severities of vulnerability ratings
+//!   - `vex_diff`: Comparison of the vulnerabilities of two documents
+//!   - `warnings`: Central warning reporting used to treat warnings as errors
 //!
 //! For installation instructions, usage examples, and project overview,
 //! see the [project README](https://github.com/jurassicLizard/vex2pdf/blob/master/README.md).
@@ -42,23 +74,56 @@
 // Re-export cyclonedx-bom models for use by consumers of this library
 pub use cyclonedx_bom as model;
 
+#[cfg(feature = "pdf")]
 pub mod pdf {
+    pub mod badges;
     pub mod font_config;
+    pub mod font_subset;
     pub mod generator;
+    pub mod links;
+    pub mod merge;
+    pub mod optimize;
+    pub mod page_decorator;
+    pub mod pdf_version;
+    pub mod redaction;
+    pub mod score_gauge;
+    pub mod vulnerability_renderer;
+    pub mod xmp;
 }
 
 pub mod lib_utils {
+    pub mod completeness;
+    #[cfg(feature = "pdf")]
     pub mod config;
+    pub mod console;
+    pub mod cvss;
     pub mod env_vars;
     pub mod input_file_type;
+    pub mod io_retry;
+    pub mod purl;
+    pub mod ref_check;
+    pub mod remote;
     pub mod run_utils;
+    pub mod severity;
+    pub mod vex_diff;
+    pub mod warnings;
 }
 
+#[cfg(feature = "pdf")]
 use crate::lib_utils::run_utils::print_copyright;
+#[cfg(feature = "pdf")]
 use lib_utils::config::Config;
+#[cfg(feature = "pdf")]
 use lib_utils::input_file_type::InputFileType;
-use lib_utils::run_utils::{find_files, parse_files};
+#[cfg(feature = "pdf")]
+use lib_utils::run_utils::{
+    find_files, parse_files, parse_urls, resolve_format_collisions, ProcessingSummary,
+};
+#[cfg(feature = "pdf")]
+use lib_utils::warnings::warning_count;
+#[cfg(feature = "pdf")]
 use pdf::generator::PdfGenerator;
+#[cfg(feature = "pdf")]
 use std::error::Error;
 
 /// Processes CycloneDX VEX documents according to the provided configuration.
@@ -86,6 +151,8 @@ use std::error::Error;
 /// 2. Processes found JSON files to generate PDFs
 /// 3. Finds XML files according to the configuration
 /// 4. Processes found XML files to generate PDFs
+/// 5. Prints a one-line summary of the processed files to stderr
+/// 6. Fails if this run emitted warnings and `warnings_as_errors` is enabled
 ///
 /// # Fonts
 ///
@@ -94,11 +161,10 @@ use std::error::Error;
 ///
 /// # Environment Variables
 ///
-/// Various aspects of PDF generation can be controlled through environment variables:
-/// - `VEX2PDF_NOVULNS_MSG`: Controls whether to show a message when no vulnerabilities exist
-/// - `VEX2PDF_REPORT_TITLE`: Sets a custom title for the report
-/// - `VEX2PDF_PDF_META_NAME`: Sets the PDF metadata name
-/// - `VEX2PDF_VERSION_INFO`: Shows version information before executing normally
+/// Various aspects of PDF generation can be controlled through environment variables,
+/// which [`Config::build`] reads into the configuration passed to this function. See the
+/// [Environment Variables](https://github.com/jurassicLizard/vex2pdf/blob/master/README.md#environment-variables)
+/// table in the README for the full list with defaults.
 ///
 /// # Example
 ///
@@ -118,6 +184,7 @@ use std::error::Error;
 /// process::exit(1);
 /// }
 /// ```
+#[cfg(feature = "pdf")]
 pub fn run(config: &Config) -> Result<(), Box<dyn Error>> {
     if config.show_oss_licenses {
         // show OSS licenses and return
@@ -138,26 +205,49 @@ license text can be found under: https://github.com/jurassicLizard/vex2pdf/blob/
         return Ok(());
     }
 
+    // The warning counter is process-wide, only warnings of this run count
+    let warnings_before = warning_count();
+
     // Begin pdf generation
-    let report_title = config.report_title.as_deref();
-    let pdf_name = config.pdf_meta_name.as_deref();
-
-    let pdf_generator = PdfGenerator::new(
-        report_title,
-        pdf_name,
-        config.show_novulns_msg,
-        config.show_components,
-    );
-
-    // Find json files
-    let json_files = find_files(config, InputFileType::JSON)?;
+    let pdf_generator = PdfGenerator::from_config(config);
+    let mut summary = ProcessingSummary::default();
+
+    // Find json and xml files, a document may be present in both formats
+    let mut json_files = find_files(config, InputFileType::JSON)?;
+    let mut xml_files = find_files(config, InputFileType::XML)?;
+    let format_suffixed = resolve_format_collisions(config, &mut json_files, &mut xml_files);
+
     // Generate PDFs out of given json files
-    parse_files(&pdf_generator, &json_files, InputFileType::JSON);
+    summary.merge(parse_files(
+        config,
+        &pdf_generator,
+        &json_files,
+        InputFileType::JSON,
+        &format_suffixed,
+    ));
 
-    // Find xml files and parse them
-    let xml_files = find_files(config, InputFileType::XML)?;
     // Generate PDFs out of given xml files
-    parse_files(&pdf_generator, &xml_files, InputFileType::XML);
+    summary.merge(parse_files(
+        config,
+        &pdf_generator,
+        &xml_files,
+        InputFileType::XML,
+        &format_suffixed,
+    ));
+
+    // Fetch remote documents, if any, and generate PDFs out of them
+    summary.merge(parse_urls(config, &pdf_generator));
+
+    // Final tally goes to stderr so it stays visible when stdout is redirected
+    eprintln!("{summary}");
+
+    let warnings = warning_count() - warnings_before;
+    if config.warnings_as_errors && warnings > 0 {
+        return Err(format!(
+            "{warnings} warning(s) emitted during processing and warnings are treated as errors"
+        )
+        .into());
+    }
 
     Ok(())
 }
@@ -417,6 +507,143 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_processing_summary_merge_and_display() {
+        use crate::lib_utils::run_utils::ProcessingSummary;
+
+        let mut summary = ProcessingSummary::default();
+        summary.merge(ProcessingSummary {
+            files_processed: 3,
+            files_succeeded: 2,
+            parse_failures: 1,
+            render_failures: 0,
+            pdfs_generated: 2,
+            duplicates_skipped: 0,
+            skipped: 0,
+        });
+        summary.merge(ProcessingSummary {
+            files_processed: 9,
+            files_succeeded: 7,
+            parse_failures: 1,
+            render_failures: 1,
+            pdfs_generated: 7,
+            duplicates_skipped: 0,
+            skipped: 0,
+        });
+
+        assert_eq!(summary.files_failed(), 3);
+        assert_eq!(
+            summary.to_string(),
+            "Processed 12 files: 9 succeeded, 3 failed (2 parse errors, 1 render errors), 9 PDFs generated"
+        );
+
+        summary.duplicates_skipped = 1;
+        assert!(summary.to_string().ends_with(", 1 duplicates skipped"));
+
+        summary.skipped = 2;
+        assert!(summary
+            .to_string()
+            .ends_with(", 1 duplicates skipped, 2 skipped as already rendered"));
+    }
+
+    #[test]
+    fn test_vuln_summary_json_preserves_ratings() {
+        use crate::lib_utils::run_utils::vuln_summary_json;
+
+        let vex = create_sample_vex();
+        let summary = vuln_summary_json(&vex);
+
+        let vulns = summary["vulnerabilities"]
+            .as_array()
+            .expect("vulnerabilities should be an array");
+        assert_eq!(vulns.len(), 2);
+
+        let ratings = vulns[0]["ratings"]
+            .as_array()
+            .expect("ratings should be an array");
+        assert_eq!(ratings.len(), 1);
+        assert_eq!(ratings[0]["score"].as_f64(), Some(8.1));
+        assert!(ratings[0]["method"].is_string());
+    }
+
+    #[test]
+    fn test_sarif_json_maps_severity_to_level() {
+        use crate::lib_utils::run_utils::sarif_json;
+
+        let vex = create_sample_vex();
+        let sarif = sarif_json(&vex);
+
+        assert_eq!(sarif["version"], "2.1.0");
+        let results = sarif["runs"][0]["results"]
+            .as_array()
+            .expect("results should be an array");
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0]["level"], "error");
+        assert_eq!(
+            results[0]["message"]["text"],
+            "Known vulnerability in library that allows unauthorized access"
+        );
+    }
+
+    #[cfg(feature = "pdf")]
+    #[test]
+    fn test_generate_pdf_without_metadata() {
+        use crate::pdf::generator::PdfGenerator;
+
+        let mut vex = create_sample_vex();
+        vex.metadata = None;
+
+        let mut output_path = std::env::temp_dir();
+        output_path.push("test_vex_no_metadata.pdf");
+
+        PdfGenerator::default()
+            .generate_pdf(&vex, &output_path)
+            .expect("failed to generate pdf without metadata");
+
+        let pdf_size = fs::metadata(&output_path)
+            .expect("generated pdf should exist")
+            .len();
+        fs::remove_file(&output_path).expect("Failed to remove temp file");
+
+        assert!(pdf_size > 0, "generated pdf should not be empty");
+    }
+
+    #[cfg(feature = "pdf")]
+    #[test]
+    fn test_append_pdf_combines_pages() {
+        use crate::pdf::generator::PdfGenerator;
+        use crate::pdf::merge::append_pdf;
+
+        let vex = create_sample_vex();
+        let base_path = std::env::temp_dir().join("test_vex_append_base.pdf");
+        let addition_path = std::env::temp_dir().join("test_vex_append_addition.pdf");
+
+        let generator = PdfGenerator::default();
+        generator
+            .generate_pdf(&vex, &base_path)
+            .expect("failed to generate base pdf");
+        generator
+            .generate_pdf(&vex, &addition_path)
+            .expect("failed to generate addition pdf");
+
+        let page_count = |path: &std::path::Path| {
+            lopdf::Document::load(path)
+                .expect("failed to load pdf")
+                .get_pages()
+                .len()
+        };
+        let expected_pages = page_count(&base_path) + page_count(&addition_path);
+
+        append_pdf(&base_path, &addition_path).expect("failed to append pdf");
+        let merged_pages = page_count(&base_path);
+
+        fs::remove_file(&base_path).expect("Failed to remove temp file");
+        fs::remove_file(&addition_path).expect("Failed to remove temp file");
+
+        assert_eq!(merged_pages, expected_pages);
+    }
+
+    #[cfg(feature = "pdf")]
     #[test]
     fn test_embedded_fonts_load_correctly() {
         use crate::pdf::font_config::FontsDir;
@@ -506,6 +733,20 @@ mod tests {
             }
         }
 
+        #[test]
+        fn test_is_on_or_falls_back_to_default() {
+            let var = EnvVarNames::Minimal; // only used by this test to avoid races
+            env::remove_var(var.as_str());
+            assert!(var.is_on_or(true));
+            assert!(!var.is_on_or(false));
+
+            env::set_var(var.as_str(), "off");
+            assert!(!var.is_on_or(true));
+            env::set_var(var.as_str(), "on");
+            assert!(var.is_on_or(false));
+            env::remove_var(var.as_str());
+        }
+
         #[test]
         fn test_get_value() {
             use std::env;
@@ -0,0 +1,174 @@
+//! Data quality scorecard of the vulnerabilities in a VEX document.
+//!
+//! A VEX document is only useful if its vulnerabilities are analysed, rated and described.
+//! The scorecard counts the vulnerabilities lacking any of these, so that authors can see
+//! where their documents need work. Used by `VEX2PDF_COMPLETENESS_REPORT` and
+//! `VEX2PDF_COMPLETENESS_SECTION`.
+//!
+
+use cyclonedx_bom::models::vulnerability::Vulnerability;
+use cyclonedx_bom::prelude::Bom;
+use std::fmt;
+
+/// Number of vulnerabilities lacking each of the checked fields
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Completeness {
+    /// Number of vulnerabilities in the document
+    pub vulnerabilities: usize,
+    /// Number of vulnerabilities without an analysis state
+    pub missing_state: usize,
+    /// Number of vulnerabilities without any rating
+    pub missing_ratings: usize,
+    /// Number of vulnerabilities without a description
+    pub missing_description: usize,
+}
+
+impl Completeness {
+    /// Returns each checked field with the number of vulnerabilities lacking it
+    pub fn gaps(&self) -> [(&'static str, usize); 3] {
+        [
+            ("analysis state", self.missing_state),
+            ("ratings", self.missing_ratings),
+            ("description", self.missing_description),
+        ]
+    }
+
+    /// Returns the share of checked fields that are present in percent, rounded down.
+    /// Documents without vulnerabilities are complete.
+    pub fn percent(&self) -> usize {
+        let checked = self.vulnerabilities * self.gaps().len();
+        if checked == 0 {
+            return 100;
+        }
+        let missing: usize = self.gaps().iter().map(|(_, count)| count).sum();
+
+        (checked - missing) * 100 / checked
+    }
+}
+
+impl fmt::Display for Completeness {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let gaps: Vec<String> = self
+            .gaps()
+            .iter()
+            .map(|(field, count)| format!("{count} without {field}"))
+            .collect();
+
+        write!(
+            f,
+            "{} vulnerabilities: {} ({}% complete)",
+            self.vulnerabilities,
+            gaps.join(", "),
+            self.percent()
+        )
+    }
+}
+
+/// Counts the vulnerabilities of the document that lack an analysis state, ratings or a
+/// description.
+///
+/// Blank descriptions count as missing, as do empty rating lists.
+///
+/// # Examples
+///
+/// ```
+/// use vex2pdf::lib_utils::completeness::completeness;
+/// use vex2pdf::lib_utils::run_utils::parse_vex_json_bytes;
+///
+/// let bom = parse_vex_json_bytes(br#"{
+///     "bomFormat": "CycloneDX",
+///     "specVersion": "1.5",
+///     "version": 1,
+///     "vulnerabilities": [{"id": "CVE-2025-0001", "description": "Overflow"}]
+/// }"#).unwrap();
+///
+/// let scorecard = completeness(&bom);
+/// assert_eq!(scorecard.missing_state, 1);
+/// assert_eq!(scorecard.missing_description, 0);
+/// ```
+pub fn completeness(bom: &Bom) -> Completeness {
+    let mut scorecard = Completeness::default();
+
+    for vuln in bom.vulnerabilities.iter().flat_map(|vulns| &vulns.0) {
+        scorecard.vulnerabilities += 1;
+        if !has_state(vuln) {
+            scorecard.missing_state += 1;
+        }
+        if !vuln
+            .vulnerability_ratings
+            .as_ref()
+            .is_some_and(|ratings| !ratings.0.is_empty())
+        {
+            scorecard.missing_ratings += 1;
+        }
+        if !vuln
+            .description
+            .as_ref()
+            .is_some_and(|description| !description.trim().is_empty())
+        {
+            scorecard.missing_description += 1;
+        }
+    }
+
+    scorecard
+}
+
+/// Returns whether the vulnerability has an analysis with a state
+fn has_state(vuln: &Vulnerability) -> bool {
+    vuln.vulnerability_analysis
+        .as_ref()
+        .is_some_and(|analysis| analysis.state.is_some())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lib_utils::run_utils::parse_vex_json_bytes;
+
+    #[test]
+    fn test_completeness_counts_missing_fields() {
+        let bom = parse_vex_json_bytes(
+            br#"{
+                "bomFormat": "CycloneDX",
+                "specVersion": "1.5",
+                "version": 1,
+                "vulnerabilities": [
+                    {
+                        "id": "CVE-2025-0001",
+                        "description": "Overflow in the parser",
+                        "ratings": [{"severity": "high"}],
+                        "analysis": {"state": "exploitable"}
+                    },
+                    {
+                        "id": "CVE-2025-0002",
+                        "description": " ",
+                        "ratings": [],
+                        "analysis": {"detail": "Still investigating"}
+                    }
+                ]
+            }"#,
+        )
+        .expect("failed to parse test document");
+
+        let scorecard = completeness(&bom);
+        assert_eq!(
+            scorecard,
+            Completeness {
+                vulnerabilities: 2,
+                missing_state: 1,
+                missing_ratings: 1,
+                missing_description: 1,
+            }
+        );
+        assert_eq!(scorecard.percent(), 50);
+        assert_eq!(
+            scorecard.to_string(),
+            "2 vulnerabilities: 1 without analysis state, 1 without ratings, 1 without description (50% complete)"
+        );
+    }
+
+    #[test]
+    fn test_document_without_vulnerabilities_is_complete() {
+        assert_eq!(Completeness::default().percent(), 100);
+    }
+}
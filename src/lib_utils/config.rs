@@ -1,10 +1,22 @@
 use super::super::pdf::font_config::FontsDir;
+use super::super::pdf::generator::{days_since_epoch, seconds_since_epoch, ComponentColumn};
+use super::super::pdf::page_decorator::parse_hex_color;
+use super::super::pdf::pdf_version::{DEFAULT_PDF_VERSION, PDF_VERSIONS};
+use super::console::{progress, reserve_stdout_for_paths};
 use super::env_vars::EnvVarNames;
 use super::input_file_type::InputFileType;
-use crate::lib_utils::run_utils::print_copyright;
+use super::remote::is_url;
+use super::severity::SeverityBands;
+use super::warnings::warn;
+use crate::lib_utils::run_utils::{parse_vex_json, parse_vex_xml, print_copyright, OutputMode};
+use cyclonedx_bom::prelude::Bom;
+use genpdf::style::Color;
 use std::collections::HashMap;
 use std::error::Error;
-use std::path::PathBuf;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 pub struct Config {
     pub working_dir: PathBuf,
@@ -14,16 +26,198 @@ pub struct Config {
     pub show_components: bool,
     pub report_title: Option<String>,
     pub pdf_meta_name: Option<String>,
+    /// Restricts the rendered vulnerabilities to the one with this ID
+    pub only_vuln_id: Option<String>,
+    /// Whether a thin border is drawn around every page
+    pub page_border: bool,
+    /// Background color filling every page, if any
+    pub page_background: Option<Color>,
+    /// Page count above which a generated report triggers a warning. 0 disables the check
+    pub max_pages: usize,
+    /// Whether `run()` fails if any warning was emitted
+    pub warnings_as_errors: bool,
+    /// Whether a `<stem>.vulns.json` vulnerability summary is written next to each input
+    pub emit_vuln_json: bool,
+    /// Running page header text, falls back to the report title when `None`
+    pub header_text: Option<String>,
+    /// Disclaimer text rendered as the final section of the report
+    pub disclaimer: Option<String>,
+    /// Score method whose ratings are exclusively rendered when a vulnerability has them
+    pub preferred_method: Option<String>,
+    /// Whether email addresses and `redact_patterns` are replaced by `[REDACTED]`
+    pub redact: bool,
+    /// Additional case-insensitive patterns whose containing words are redacted
+    pub redact_patterns: Vec<String>,
+    /// Age in days above which vulnerabilities are flagged as aging, no aging logic runs when `None`
+    pub aging_days: Option<u64>,
+    /// HTTP(S) URLs of documents fetched and converted in addition to the files in `working_dir`
+    pub input_urls: Vec<String>,
+    /// Whether the primary tool of the BOM is shown as a `Generated by` line below the title
+    pub show_generated_by: bool,
+    /// Whether vulnerabilities are rendered as a compact one-row-per-vulnerability table
+    pub compact: bool,
+    /// Placeholder rendered for missing values such as IDs, descriptions and rating methods
+    pub na_text: String,
+    /// Whether vulnerabilities reference affected components by their number in the Components section
+    pub cross_refs: bool,
+    /// Whether a `<stem>.sarif` findings file is written next to each input
+    pub emit_sarif: bool,
+    /// Whether components are sorted by name and version instead of rendered in BOM order
+    pub sort_components: bool,
+    /// Whether the `bom-ref` of vulnerabilities and components is rendered
+    pub show_bom_refs: bool,
+    /// Contents of the cover page template, the built-in cover is rendered when `None`
+    pub cover_template: Option<String>,
+    /// Language code used for locale specific formatting such as the decimal separator of scores
+    pub lang: Option<String>,
+    /// Whether the ratings of each vulnerability are sorted by severity in descending order
+    pub sort_ratings: bool,
+    /// Whether vulnerabilities are grouped by the components they affect
+    pub group_by_component: bool,
+    /// Previous document the vulnerabilities are compared against to render the changes since
+    pub previous_bom: Option<Bom>,
+    /// Whether the file name of the input document is rendered
+    pub show_source_file: bool,
+    /// Whether the absolute path of the input document is rendered, takes precedence over `show_source_file`
+    pub show_source_path: bool,
+    /// Number of retries for input reads and report writes failing with a transient I/O error
+    pub io_retries: u32,
+    /// Whether the "No Vulnerabilities" message is framed and padded instead of plain centered text
+    pub novulns_framed: bool,
+    /// Whether only the highest version of input documents sharing a serial number is rendered
+    pub keep_latest: bool,
+    /// Earliest publication date (`YYYY-MM-DD`) of the rendered vulnerabilities
+    pub since: Option<String>,
+    /// Latest publication date (`YYYY-MM-DD`) of the rendered vulnerabilities
+    pub until: Option<String>,
+    /// Whether severities are prefixed with a symbol such as ▲ for high
+    pub severity_icons: bool,
+    /// Number of front matter pages left unnumbered, the page after them is numbered 1
+    pub page_number_offset: usize,
+    /// Whether the license texts attached to components and services are rendered as an appendix
+    pub license_appendix: bool,
+    /// Whether dangling bom-ref references are reported as warnings
+    pub check_refs: bool,
+    /// Whether the highest score of each vulnerability is drawn as a gauge colored by severity
+    pub score_gauge: bool,
+    /// Whether the Components section only lists components targeted by a vulnerability
+    pub vuln_components_only: bool,
+    /// Whether a plain text rendering of the report is written as `<stem>.txt` next to each input file
+    pub emit_txt: bool,
+    /// Whether the base, temporal and environmental scores of CVSS v3 vectors are shown
+    pub cvss_breakdown: bool,
+    /// Name of the issuing organization shown in the running page header
+    pub org_name: Option<String>,
+    /// Number of tools above which the tools list is moved to an appendix. 0 disables the limit
+    pub tools_max: usize,
+    /// Whether analysis responses are rendered as colored badges
+    pub response_badges: bool,
+    /// Whether the creation date and document IDs are fixed so that identical input yields
+    /// identical PDFs. Also enabled by setting `SOURCE_DATE_EPOCH`
+    pub deterministic: bool,
+    /// Creation date of deterministic PDFs in seconds since the Unix epoch
+    pub source_date_epoch: Option<i64>,
+    /// Whether the supplier and its contacts are shown under each component
+    pub show_contacts: bool,
+    /// Whether a data completeness scorecard is printed for each document
+    pub completeness_report: bool,
+    /// Whether the data completeness scorecard is rendered as a section of the report
+    pub completeness_section: bool,
+    /// Columns of the components table in order, `None` to render the components as a list
+    pub component_columns: Option<Vec<ComponentColumn>>,
+    /// Whether component purls are shown and linked to their package registry pages
+    pub purl_links: bool,
+    /// Name of the tool credited as creator of the report in the PDF metadata, `None` to credit none
+    pub attribution: Option<String>,
+    /// Controls whether the warnings noticed during conversion are rendered as a Conversion Notes section
+    pub show_notes: bool,
+    /// Date rendered below the report title, `now` for the date the report is generated
+    pub report_date: Option<String>,
+    /// Controls whether the reports are written to a fresh temporary directory and only their paths are printed to standard output
+    pub temp_output: bool,
+    /// Directory the reports are written to, next to their input files if `None`. Set to a
+    /// fresh temporary directory by `VEX2PDF_TEMP_OUTPUT`
+    pub output_dir: Option<PathBuf>,
+    /// Whether vulnerabilities are grouped under headings of their highest severity, taking precedence over `group_by_component`
+    pub group_by_severity: bool,
+    /// Whether the serial number and spec version of the document are printed in the footer of every page
+    pub meta_footer: bool,
+    /// Score bands used to derive the severity of ratings that only carry a score
+    pub severity_bands: SeverityBands,
+    /// Whether components identical to the metadata component are left out of the Components section
+    pub dedup_metadata_component: bool,
+    /// Whether generated reports are compressed and stripped of unused objects after rendering
+    pub optimize: bool,
+    /// Whether the embedded fonts of generated reports only keep the glyphs drawn in them
+    pub subset_fonts: bool,
+    /// What happens when the PDF of an input already exists
+    pub output_mode: OutputMode,
+    /// Whether components are grouped under the ecosystems of their purls
+    pub group_by_ecosystem: bool,
+    /// Format processed when the JSON and XML files of a document share a name, both are processed if `None`
+    pub prefer_format: Option<InputFileType>,
+    /// Vertical space in lines between sections and larger blocks of the report
+    pub section_spacing: f64,
+    /// Vertical space in lines between the items of a section
+    pub item_spacing: f64,
+    /// Whether a badge with the highest severity and score is shown next to each vulnerability ID
+    pub show_severity_badge: bool,
+    /// Whether the parsed document is written as `<stem>.normalized.json` to show what the parser kept
+    pub dump_normalized: bool,
+    /// Whether the SHA-256 of the input bytes is shown in the Document Information section
+    pub show_source_hash: bool,
+    /// PDF version written to the header of the reports, the version written by genpdf is kept if `None`
+    pub pdf_version: Option<String>,
+    /// Files modified before this time are skipped by the directory scan, all files are processed if `None`
+    pub modified_since: Option<SystemTime>,
+    /// Classification marking printed centered at the top and bottom of every page
+    pub classification: Option<String>,
 }
 
 impl Config {
     pub fn build() -> Result<Self, Box<dyn Error>> {
         let working_dir = std::env::current_dir()?;
-        let show_novulns_msg = EnvVarNames::NoVulnsMsg.is_on_or_unset();
+        let temp_output = EnvVarNames::TempOutput.is_on();
+        if temp_output {
+            // Everything but the paths of the reports goes to standard error from here on
+            reserve_stdout_for_paths();
+        }
+        // The minimal preset only changes defaults, explicitly set toggles take precedence
+        let minimal = EnvVarNames::Minimal.is_on();
+        let show_novulns_msg = EnvVarNames::NoVulnsMsg.is_on_or(!minimal);
         let mut process_json = EnvVarNames::ProcessJson.is_on_or_unset();
         let process_xml = EnvVarNames::ProcessXml.is_on_or_unset();
         let show_oss_licenses = EnvVarNames::ShowOssLicenses.is_on();
-        let show_components = EnvVarNames::ShowComponents.is_on_or_unset();
+        let show_components = EnvVarNames::ShowComponents.is_on_or(!minimal);
+        let page_border = EnvVarNames::PageBorder.is_on();
+        let disclaimer = read_text_file(EnvVarNames::DisclaimerFile, "disclaimer");
+        let input_urls = EnvVarNames::InputUrl
+            .get_list_value()
+            .into_iter()
+            .filter(|url| {
+                let valid = is_url(url);
+                if !valid {
+                    warn(format!(
+                        "{} entry '{url}' is not an http(s) URL. ignoring",
+                        EnvVarNames::InputUrl.as_str()
+                    ));
+                }
+                valid
+            })
+            .collect();
+        let page_background = EnvVarNames::PageBackground.get_value().and_then(|value| {
+            let color = parse_hex_color(&value);
+            if color.is_none() {
+                warn(format!(
+                    "invalid {} value '{value}', expected a hex color like #F5F5F5. ignoring",
+                    EnvVarNames::PageBackground.as_str()
+                ));
+            }
+            color
+        });
+
+        // SOURCE_DATE_EPOCH requests reproducible output on its own, see reproducible-builds.org
+        let source_date_epoch: Option<i64> = EnvVarNames::SourceDateEpoch.get_parsed_value();
 
         // print version info if requested
         if EnvVarNames::VersionInfo.is_on() {
@@ -39,10 +233,18 @@ impl Config {
 
         // validate
         if !(process_json || process_xml) {
-            println!("**** WARNING: we cannot have both json and xml deactivated. defaulting to json processing");
+            warn("we cannot have both json and xml deactivated. defaulting to json processing");
             process_json = true;
         }
 
+        let output_dir = if temp_output {
+            let dir = create_temp_output_dir()?;
+            progress(format!("Writing reports to {}", dir.display()));
+            Some(dir)
+        } else {
+            None
+        };
+
         // init result map
         let mut file_types_to_process: HashMap<InputFileType, bool> = HashMap::new();
         file_types_to_process.insert(InputFileType::JSON, process_json);
@@ -54,8 +256,110 @@ impl Config {
             file_types_to_process,
             show_oss_licenses,
             show_components,
-            report_title: EnvVarNames::ReportTitle.get_value(),
-            pdf_meta_name: EnvVarNames::PdfName.get_value(),
+            report_title: non_empty_title(
+                EnvVarNames::ReportTitle,
+                EnvVarNames::ReportTitle.get_value(),
+                Self::get_default_report_title(),
+            ),
+            pdf_meta_name: non_empty_title(
+                EnvVarNames::PdfName,
+                EnvVarNames::PdfName.get_value(),
+                Self::get_default_pdf_meta_name(),
+            ),
+            only_vuln_id: EnvVarNames::OnlyVulnId
+                .get_value()
+                .filter(|id| !id.trim().is_empty()),
+            page_border,
+            page_background,
+            max_pages: EnvVarNames::MaxPages
+                .get_parsed_value()
+                .unwrap_or(Self::get_default_max_pages()),
+            warnings_as_errors: EnvVarNames::WarningsAsErrors.is_on(),
+            emit_vuln_json: EnvVarNames::EmitVulnJson.is_on(),
+            header_text: EnvVarNames::HeaderText.get_value(),
+            disclaimer,
+            preferred_method: EnvVarNames::PreferredMethod
+                .get_value()
+                .filter(|method| !method.trim().is_empty()),
+            redact: EnvVarNames::Redact.is_on(),
+            redact_patterns: EnvVarNames::RedactPatterns.get_list_value(),
+            aging_days: EnvVarNames::AgingDays.get_parsed_value(),
+            input_urls,
+            show_generated_by: EnvVarNames::ShowGeneratedBy.is_on(),
+            compact: EnvVarNames::Compact.is_on(),
+            na_text: EnvVarNames::NaText
+                .get_value()
+                .unwrap_or_else(|| Self::get_default_na_text().to_string()),
+            cross_refs: EnvVarNames::CrossRefs.is_on(),
+            emit_sarif: EnvVarNames::EmitSarif.is_on(),
+            sort_components: EnvVarNames::SortComponents.is_on(),
+            show_bom_refs: EnvVarNames::ShowBomRefs.is_on(),
+            cover_template: read_text_file(EnvVarNames::CoverTemplate, "cover template"),
+            lang: EnvVarNames::Lang
+                .get_value()
+                .filter(|lang| !lang.trim().is_empty()),
+            sort_ratings: EnvVarNames::SortRatings.is_on(),
+            group_by_component: EnvVarNames::GroupByComponent.is_on(),
+            previous_bom: read_previous_bom(),
+            show_source_file: EnvVarNames::ShowSourceFile.is_on(),
+            show_source_path: EnvVarNames::ShowSourcePath.is_on(),
+            io_retries: EnvVarNames::IoRetries.get_parsed_value().unwrap_or(0),
+            novulns_framed: EnvVarNames::NoVulnsFramed.is_on_or_unset(),
+            keep_latest: EnvVarNames::KeepLatest.is_on(),
+            since: date_bound(EnvVarNames::Since),
+            until: date_bound(EnvVarNames::Until),
+            severity_icons: EnvVarNames::SeverityIcons.is_on(),
+            page_number_offset: EnvVarNames::PageNumberOffset
+                .get_parsed_value()
+                .unwrap_or(0),
+            license_appendix: EnvVarNames::LicenseAppendix.is_on(),
+            check_refs: EnvVarNames::CheckRefs.is_on(),
+            score_gauge: EnvVarNames::ScoreGauge.is_on(),
+            vuln_components_only: EnvVarNames::VulnComponentsOnly.is_on(),
+            emit_txt: EnvVarNames::EmitTxt.is_on(),
+            cvss_breakdown: EnvVarNames::CvssBreakdown.is_on(),
+            org_name: EnvVarNames::OrgName
+                .get_value()
+                .filter(|name| !name.trim().is_empty()),
+            tools_max: EnvVarNames::ToolsMax.get_parsed_value().unwrap_or(0),
+            response_badges: EnvVarNames::ResponseBadges.is_on(),
+            deterministic: EnvVarNames::Deterministic.is_on() || source_date_epoch.is_some(),
+            source_date_epoch,
+            show_contacts: EnvVarNames::ShowContacts.is_on(),
+            completeness_report: EnvVarNames::CompletenessReport.is_on(),
+            completeness_section: EnvVarNames::CompletenessSection.is_on(),
+            component_columns: component_columns(),
+            purl_links: EnvVarNames::PurlLinks.is_on(),
+            attribution: attribution(),
+            show_notes: EnvVarNames::ShowNotes.is_on(),
+            report_date: report_date(),
+            temp_output,
+            output_dir,
+            group_by_severity: EnvVarNames::GroupBySeverity.is_on(),
+            meta_footer: EnvVarNames::MetaFooter.is_on(),
+            severity_bands: EnvVarNames::SeverityBands
+                .get_parsed_value()
+                .unwrap_or_default(),
+            dedup_metadata_component: EnvVarNames::DedupMetadataComponent.is_on(),
+            optimize: EnvVarNames::Optimize.is_on(),
+            subset_fonts: EnvVarNames::SubsetFonts.is_on_or_unset(),
+            output_mode: output_mode(),
+            group_by_ecosystem: EnvVarNames::GroupByEcosystem.is_on(),
+            prefer_format: prefer_format(),
+            section_spacing: spacing(
+                EnvVarNames::SectionSpacing,
+                Self::get_default_section_spacing(),
+            ),
+            item_spacing: spacing(EnvVarNames::ItemSpacing, Self::get_default_item_spacing()),
+            show_severity_badge: EnvVarNames::SeverityBadge.is_on(),
+            dump_normalized: EnvVarNames::DumpNormalized.is_on(),
+            show_source_hash: EnvVarNames::ShowSourceHash.is_on(),
+            pdf_version: pdf_version(),
+            modified_since: modified_since(SystemTime::now()),
+            classification: EnvVarNames::Classification
+                .get_value()
+                .map(|marking| marking.trim().to_string())
+                .filter(|marking| !marking.is_empty()),
         };
 
         Ok(config)
@@ -70,6 +374,317 @@ impl Config {
     pub fn get_default_report_title() -> &'static str {
         "Vulnerability Report Document"
     }
+
+    /// Gets the default page count above which a report is considered runaway
+    pub fn get_default_max_pages() -> usize {
+        500
+    }
+
+    /// Gets the default vertical space in lines between sections of the report
+    pub fn get_default_section_spacing() -> f64 {
+        1.0
+    }
+
+    /// Gets the default vertical space in lines between the items of a section
+    pub fn get_default_item_spacing() -> f64 {
+        0.5
+    }
+
+    /// Gets the default placeholder rendered for missing values
+    pub fn get_default_na_text() -> &'static str {
+        "N/A"
+    }
+
+    /// Gets the default name of the tool credited in the PDF metadata
+    pub fn get_default_attribution() -> &'static str {
+        "vex2pdf"
+    }
+}
+
+/// Reads the text file whose path is given by `var`.
+///
+/// Returns `None` if the variable is unset. Unreadable files are reported with a warning
+/// naming `purpose`, and the feature relying on the file is skipped.
+fn read_text_file(var: EnvVarNames, purpose: &str) -> Option<String> {
+    let path = var.get_value()?;
+    match fs::read_to_string(&path) {
+        Ok(text) => Some(text),
+        Err(e) => {
+            warn(format!(
+                "could not read {purpose} file {path}: {e}. no {purpose} will be rendered"
+            ));
+            None
+        }
+    }
+}
+
+/// Returns the title read from `var`, or `default` with a warning if it is blank.
+///
+/// Variables that are defined but empty, as happens with unset CI variables, would
+/// otherwise produce a report without a title.
+fn non_empty_title(var: EnvVarNames, value: Option<String>, default: &str) -> Option<String> {
+    match value {
+        Some(title) if title.trim().is_empty() => {
+            warn(format!(
+                "{} is set but empty, using the default \"{default}\"",
+                var.as_str()
+            ));
+            Some(default.to_string())
+        }
+        title => title,
+    }
+}
+
+/// Returns the `YYYY-MM-DD` date read from `var`, trimmed. Values that are not a valid
+/// date are reported with a warning and ignored.
+fn date_bound(var: EnvVarNames) -> Option<String> {
+    let value = var.get_value().filter(|value| !value.trim().is_empty())?;
+    let value = value.trim().to_string();
+
+    if days_since_epoch(&value).is_some() {
+        Some(value)
+    } else {
+        warn(format!(
+            "{} is not a valid YYYY-MM-DD date: {value}, ignoring it",
+            var.as_str()
+        ));
+        None
+    }
+}
+
+/// Reads the time before which modified files are skipped from `VEX2PDF_MODIFIED_SINCE`.
+///
+/// Durations such as `30m`, `24h` or `7d` count back from `now`. Dates (`YYYY-MM-DD`) and
+/// timestamps (`YYYY-MM-DDTHH:MM:SSZ`) are taken as UTC. Invalid values are reported with a
+/// warning and ignored.
+fn modified_since(now: SystemTime) -> Option<SystemTime> {
+    let var = EnvVarNames::ModifiedSince;
+    let value = var.get_value().filter(|value| !value.trim().is_empty())?;
+    let value = value.trim();
+
+    let threshold = match (duration(value), seconds_since_epoch(value)) {
+        (Some(duration), _) => now.checked_sub(duration),
+        (None, Some(seconds)) => match u64::try_from(seconds) {
+            Ok(seconds) => UNIX_EPOCH.checked_add(Duration::from_secs(seconds)),
+            Err(_) => UNIX_EPOCH.checked_sub(Duration::from_secs(seconds.unsigned_abs())),
+        },
+        (None, None) => None,
+    };
+    if threshold.is_none() {
+        warn(format!(
+            "{} is neither a duration such as 24h nor a valid YYYY-MM-DD date or \
+             YYYY-MM-DDTHH:MM:SSZ timestamp: {value}, ignoring it",
+            var.as_str()
+        ));
+    }
+
+    threshold
+}
+
+/// Parses a duration of whole seconds (`s`), minutes (`m`), hours (`h`) or days (`d`),
+/// e.g. `24h`
+fn duration(value: &str) -> Option<Duration> {
+    let unit_seconds = match value.chars().last()? {
+        's' => 1,
+        'm' => 60,
+        'h' => 3600,
+        'd' => 86_400,
+        _ => return None,
+    };
+    let amount: u64 = value[..value.len() - 1].parse().ok()?;
+
+    amount.checked_mul(unit_seconds).map(Duration::from_secs)
+}
+
+/// Creates a new, uniquely named directory for the reports in the system temporary
+/// directory
+fn create_temp_output_dir() -> io::Result<PathBuf> {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.subsec_nanos());
+
+    // Another process may have taken the name, creating the directory is the only safe check
+    for attempt in 0..100 {
+        let dir =
+            std::env::temp_dir().join(format!("vex2pdf-{}-{nanos}-{attempt}", std::process::id()));
+        match fs::create_dir(&dir) {
+            Ok(()) => return Ok(dir),
+            Err(err) if err.kind() == io::ErrorKind::AlreadyExists => continue,
+            Err(err) => return Err(err),
+        }
+    }
+
+    Err(io::Error::new(
+        io::ErrorKind::AlreadyExists,
+        "failed to find an unused temporary directory name",
+    ))
+}
+
+/// Reads the date rendered below the report title from `VEX2PDF_REPORT_DATE`, trimmed.
+///
+/// `now` is kept as is and resolved when rendering. Other values must start with a
+/// `YYYY-MM-DD` date, invalid values are reported with a warning and ignored.
+fn report_date() -> Option<String> {
+    let var = EnvVarNames::ReportDate;
+    let value = var.get_value().filter(|value| !value.trim().is_empty())?;
+    let value = value.trim();
+
+    if value.eq_ignore_ascii_case("now") {
+        Some("now".to_string())
+    } else if days_since_epoch(value).is_some() {
+        Some(value.to_string())
+    } else {
+        warn(format!(
+            "{} is neither \"now\" nor a valid YYYY-MM-DD date: {value}, ignoring it",
+            var.as_str()
+        ));
+        None
+    }
+}
+
+/// Reads the tool credited in the PDF metadata from `VEX2PDF_ATTRIBUTION`.
+///
+/// Off values such as `false` remove the attribution, other values replace the default
+/// one. Unset or blank variables keep the default.
+fn attribution() -> Option<String> {
+    let var = EnvVarNames::Attribution;
+    match var.get_value().filter(|value| !value.trim().is_empty()) {
+        Some(_) if !var.is_on() => None,
+        Some(name) => Some(name.trim().to_string()),
+        None => Some(Config::get_default_attribution().to_string()),
+    }
+}
+
+/// Reads the columns of the components table from `VEX2PDF_COMPONENT_COLUMNS`.
+///
+/// Returns `None` if the variable is unset, keeping the components list. Unknown columns
+/// are reported with a warning and skipped, if none is left the default columns are used.
+fn component_columns() -> Option<Vec<ComponentColumn>> {
+    let names = EnvVarNames::ComponentColumns.get_list_value();
+    if names.is_empty() {
+        return None;
+    }
+
+    let columns: Vec<ComponentColumn> = names
+        .iter()
+        .filter_map(|name| {
+            let column = ComponentColumn::from_name(name);
+            if column.is_none() {
+                warn(format!(
+                    "{} entry '{name}' is not a known column, expected one of name, version, type, purl, cpe, license, supplier. ignoring",
+                    EnvVarNames::ComponentColumns.as_str()
+                ));
+            }
+            column
+        })
+        .collect();
+
+    if columns.is_empty() {
+        Some(ComponentColumn::DEFAULT.to_vec())
+    } else {
+        Some(columns)
+    }
+}
+
+/// Parses `VEX2PDF_PREFER_FORMAT`, unknown formats are reported with a warning and both
+/// formats are processed
+fn prefer_format() -> Option<InputFileType> {
+    let value = EnvVarNames::PreferFormat
+        .get_value()
+        .filter(|value| !value.trim().is_empty())?;
+    match value.trim().to_lowercase().as_str() {
+        "json" => Some(InputFileType::JSON),
+        "xml" => Some(InputFileType::XML),
+        _ => {
+            warn(format!(
+                "invalid {} value '{value}', expected json or xml. processing both formats",
+                EnvVarNames::PreferFormat.as_str()
+            ));
+            None
+        }
+    }
+}
+
+/// Parses `VEX2PDF_PDF_VERSION`, unsupported versions are reported with a warning and the
+/// version written by genpdf is kept
+fn pdf_version() -> Option<String> {
+    let value = EnvVarNames::PdfVersion
+        .get_value()
+        .filter(|value| !value.trim().is_empty())?;
+    let version = value.trim();
+    if PDF_VERSIONS.contains(&version) {
+        Some(version.to_string())
+    } else {
+        warn(format!(
+            "invalid {} value '{value}', expected one of {}. keeping PDF {DEFAULT_PDF_VERSION}",
+            EnvVarNames::PdfVersion.as_str(),
+            PDF_VERSIONS.join(", ")
+        ));
+        None
+    }
+}
+
+/// Parses a spacing in lines, negative values are reported with a warning and `default`
+/// is used
+fn spacing(var: EnvVarNames, default: f64) -> f64 {
+    match var.get_parsed_value::<f64>() {
+        Some(spacing) if spacing.is_finite() && spacing >= 0.0 => spacing,
+        Some(spacing) => {
+            warn(format!(
+                "invalid {} value '{spacing}', expected a number of lines of at least 0. ignoring",
+                var.as_str()
+            ));
+            default
+        }
+        None => default,
+    }
+}
+
+/// Parses `VEX2PDF_OUTPUT_MODE`, unknown modes are reported with a warning and the
+/// default mode is used
+fn output_mode() -> OutputMode {
+    EnvVarNames::OutputMode
+        .get_value()
+        .map_or(OutputMode::default(), |name| {
+            OutputMode::from_name(&name).unwrap_or_else(|| {
+                warn(format!(
+                    "invalid {} value '{name}', expected one of overwrite, skip, version. defaulting to overwrite",
+                    EnvVarNames::OutputMode.as_str()
+                ));
+                OutputMode::default()
+            })
+        })
+}
+
+/// Parses the previous document whose path is given by `VEX2PDF_DIFF_AGAINST`.
+///
+/// Returns `None` if the variable is unset. Documents that cannot be read or parsed are
+/// reported with a warning and no changes section is rendered.
+fn read_previous_bom() -> Option<Bom> {
+    let path = EnvVarNames::DiffAgainst
+        .get_value()
+        .filter(|path| !path.trim().is_empty())?;
+    let path = Path::new(&path);
+
+    let is_xml = path
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("xml"));
+    let parsed = if is_xml {
+        parse_vex_xml(path)
+    } else {
+        parse_vex_json(path)
+    };
+
+    match parsed {
+        Ok(bom) => Some(bom),
+        Err(e) => {
+            warn(format!(
+                "could not parse previous document {}: {e}. no changes section will be rendered",
+                path.display()
+            ));
+            None
+        }
+    }
 }
 
 impl Default for Config {
@@ -89,6 +704,78 @@ impl Default for Config {
     /// - **show_components**: `true` - Include component information in reports
     /// - **report_title**: Default report title from `get_default_report_title()`
     /// - **pdf_meta_name**: Default PDF metadata name from `get_default_pdf_meta_name()`
+    /// - **only_vuln_id**: `None` - All vulnerabilities are rendered
+    /// - **page_border**: `false` - No border is drawn around pages
+    /// - **page_background**: `None` - Pages keep the default white background
+    /// - **max_pages**: Default page limit from `get_default_max_pages()`
+    /// - **warnings_as_errors**: `false` - Warnings are reported but do not fail the run
+    /// - **emit_vuln_json**: `false` - No vulnerability JSON summary is written
+    /// - **header_text**: `None` - The running header shows the report title
+    /// - **disclaimer**: `None` - No disclaimer section is rendered
+    /// - **preferred_method**: `None` - Ratings of all score methods are rendered
+    /// - **redact**: `false` - Text is rendered as is
+    /// - **redact_patterns**: empty - Only email addresses are redacted when `redact` is on
+    /// - **aging_days**: `None` - Vulnerabilities are not flagged by age
+    /// - **input_urls**: empty - Only local files are converted
+    /// - **show_generated_by**: `false` - Tools are only listed in the document information
+    /// - **compact**: `false` - Vulnerabilities are rendered with full details
+    /// - **na_text**: Default placeholder from `get_default_na_text()`
+    /// - **cross_refs**: `false` - Affected components are not listed
+    /// - **emit_sarif**: `false` - No SARIF file is written
+    /// - **sort_components**: `false` - Components are rendered in BOM order
+    /// - **show_bom_refs**: `false` - References are not rendered
+    /// - **cover_template**: `None` - The built-in cover is rendered
+    /// - **lang**: `None` - Numbers use `.` as decimal separator
+    /// - **sort_ratings**: `false` - Ratings are rendered in BOM order
+    /// - **group_by_component**: `false` - Vulnerabilities are rendered as a flat list
+    /// - **previous_bom**: `None` - No changes section is rendered
+    /// - **show_source_file**: `false` - The input document is not named in the report
+    /// - **show_source_path**: `false` - The input document path is not rendered
+    /// - **io_retries**: `0` - File operations are attempted once
+    /// - **novulns_framed**: `true` - The "No Vulnerabilities" message is framed
+    /// - **keep_latest**: `false` - Documents sharing a serial number are all rendered
+    /// - **since**: `None` - Vulnerabilities are not filtered by a start date
+    /// - **until**: `None` - Vulnerabilities are not filtered by an end date
+    /// - **severity_icons**: `false` - Severities are rendered as text only
+    /// - **page_number_offset**: `0` - Pages are numbered from the first page on
+    /// - **license_appendix**: `false` - License texts are not rendered
+    /// - **check_refs**: `false` - References are not validated
+    /// - **score_gauge**: `false` - Scores are rendered as text only
+    /// - **vuln_components_only**: `false` - All components are listed
+    /// - **emit_txt**: `false` - No text report is written
+    /// - **cvss_breakdown**: `false` - Only the reported scores are shown
+    /// - **org_name**: `None` - The running header shows no organization name
+    /// - **tools_max**: `0` - All tools are listed in the metadata section
+    /// - **response_badges**: `false` - Responses are rendered as a comma separated list
+    /// - **deterministic**: `false` - PDFs are stamped with the current time
+    /// - **source_date_epoch**: `None` - Deterministic PDFs use the BOM timestamp
+    /// - **show_contacts**: `false` - Suppliers are not shown
+    /// - **completeness_report**: `false` - No scorecard is printed
+    /// - **completeness_section**: `false` - The report has no Data Completeness section
+    /// - **component_columns**: `None` - Components are rendered as a list
+    /// - **purl_links**: `false` - Purls are not linked
+    /// - **attribution**: Default attribution from `get_default_attribution()`
+    /// - **show_notes**: `false` - Warnings are only printed to the console
+    /// - **report_date**: `None` - No report date is rendered below the title
+    /// - **temp_output**: `false` - Progress messages are printed to standard output
+    /// - **output_dir**: `None` - Reports are written next to their input files
+    /// - **group_by_severity**: `false` - Vulnerabilities are not grouped by severity
+    /// - **meta_footer**: `false` - Pages have no footer
+    /// - **severity_bands**: `9.0,7.0,4.0` - The standard CVSS v3 bands
+    /// - **dedup_metadata_component**: `false` - Components identical to the metadata component are listed
+    /// - **optimize**: `false` - Reports are written as rendered
+    /// - **subset_fonts**: `true` - Embedded fonts only keep the glyphs drawn in the report
+    /// - **output_mode**: `OutputMode::Overwrite` - Existing PDFs are replaced
+    /// - **group_by_ecosystem**: `false` - Components are listed without ecosystem headings
+    /// - **prefer_format**: `None` - Both files are processed and their reports are named after their format
+    /// - **section_spacing**: `1.0` lines
+    /// - **item_spacing**: `0.5` lines
+    /// - **show_severity_badge**: `false` - Vulnerability IDs are shown without a severity badge
+    /// - **dump_normalized**: `false` - No normalized document is written
+    /// - **show_source_hash**: `false` - No hash of the input is shown
+    /// - **pdf_version**: `None` - Reports are written as PDF 1.3
+    /// - **modified_since**: `None` - Files are processed regardless of their modification time
+    /// - **classification**: `None` - Pages carry no classification marking
     ///
     /// # Behavior
     ///
@@ -133,6 +820,127 @@ impl Default for Config {
             show_components: true,
             report_title: Some(Self::get_default_report_title().to_string()),
             pdf_meta_name: Some(Self::get_default_pdf_meta_name().to_string()),
+            only_vuln_id: None,
+            page_border: false,
+            page_background: None,
+            max_pages: Self::get_default_max_pages(),
+            warnings_as_errors: false,
+            emit_vuln_json: false,
+            header_text: None,
+            disclaimer: None,
+            preferred_method: None,
+            redact: false,
+            redact_patterns: Vec::new(),
+            aging_days: None,
+            input_urls: Vec::new(),
+            show_generated_by: false,
+            compact: false,
+            na_text: Self::get_default_na_text().to_string(),
+            cross_refs: false,
+            emit_sarif: false,
+            sort_components: false,
+            show_bom_refs: false,
+            cover_template: None,
+            lang: None,
+            sort_ratings: false,
+            group_by_component: false,
+            previous_bom: None,
+            show_source_file: false,
+            show_source_path: false,
+            io_retries: 0,
+            novulns_framed: true,
+            keep_latest: false,
+            since: None,
+            until: None,
+            severity_icons: false,
+            page_number_offset: 0,
+            license_appendix: false,
+            check_refs: false,
+            score_gauge: false,
+            vuln_components_only: false,
+            emit_txt: false,
+            cvss_breakdown: false,
+            org_name: None,
+            tools_max: 0,
+            response_badges: false,
+            deterministic: false,
+            source_date_epoch: None,
+            show_contacts: false,
+            completeness_report: false,
+            completeness_section: false,
+            component_columns: None,
+            purl_links: false,
+            attribution: Some(Self::get_default_attribution().to_string()),
+            show_notes: false,
+            report_date: None,
+            temp_output: false,
+            output_dir: None,
+            group_by_severity: false,
+            meta_footer: false,
+            severity_bands: SeverityBands::default(),
+            dedup_metadata_component: false,
+            optimize: false,
+            subset_fonts: true,
+            output_mode: OutputMode::default(),
+            group_by_ecosystem: false,
+            prefer_format: None,
+            section_spacing: Self::get_default_section_spacing(),
+            item_spacing: Self::get_default_item_spacing(),
+            show_severity_badge: false,
+            dump_normalized: false,
+            show_source_hash: false,
+            pdf_version: None,
+            modified_since: None,
+            classification: None,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_temp_output_dirs_are_unique() {
+        let first = create_temp_output_dir().expect("failed to create temp dir");
+        let second = create_temp_output_dir().expect("failed to create temp dir");
+
+        assert_ne!(first, second);
+        assert!(first.is_dir() && second.is_dir());
+        fs::remove_dir(first).expect("failed to remove temp dir");
+        fs::remove_dir(second).expect("failed to remove temp dir");
+    }
+
+    #[test]
+    fn test_non_empty_title_falls_back_to_default() {
+        let default = Config::get_default_report_title();
+
+        assert_eq!(
+            non_empty_title(EnvVarNames::ReportTitle, Some("  ".to_string()), default),
+            Some(default.to_string())
+        );
+        assert_eq!(
+            non_empty_title(
+                EnvVarNames::ReportTitle,
+                Some("Q3 Report".to_string()),
+                default
+            ),
+            Some("Q3 Report".to_string())
+        );
+        assert_eq!(
+            non_empty_title(EnvVarNames::ReportTitle, None, default),
+            None
+        );
+    }
+
+    #[test]
+    fn test_duration() {
+        assert_eq!(duration("90s"), Some(Duration::from_secs(90)));
+        assert_eq!(duration("30m"), Some(Duration::from_secs(1800)));
+        assert_eq!(duration("24h"), Some(Duration::from_secs(86_400)));
+        assert_eq!(duration("7d"), Some(Duration::from_secs(604_800)));
+        assert_eq!(duration("h"), None);
+        assert_eq!(duration("-1h"), None);
+        assert_eq!(duration("2025-05-01"), None);
+    }
+}
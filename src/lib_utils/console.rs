@@ -0,0 +1,42 @@
+//! Console output of the conversion.
+//!
+//! Progress messages and warnings are printed to standard output. When standard output is
+//! reserved for the paths of the generated files (see `VEX2PDF_TEMP_OUTPUT`), they are
+//! printed to standard error instead, so that a downstream step can read the paths line
+//! by line.
+//!
+
+use std::fmt::Display;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Whether standard output only receives the paths of generated files
+static STDOUT_RESERVED: AtomicBool = AtomicBool::new(false);
+
+/// Reserves standard output for the paths of generated files for the rest of the process
+pub fn reserve_stdout_for_paths() {
+    STDOUT_RESERVED.store(true, Ordering::Relaxed);
+}
+
+/// Returns whether standard output is reserved for the paths of generated files
+pub fn is_stdout_reserved() -> bool {
+    STDOUT_RESERVED.load(Ordering::Relaxed)
+}
+
+/// Prints a progress message to standard output, or to standard error if standard output
+/// is reserved for paths
+pub fn progress(message: impl Display) {
+    if is_stdout_reserved() {
+        eprintln!("{message}");
+    } else {
+        println!("{message}");
+    }
+}
+
+/// Prints the path of a generated file to standard output if it is reserved for paths.
+/// Does nothing otherwise, the progress messages already name the file.
+pub fn output_path(path: &Path) {
+    if is_stdout_reserved() {
+        println!("{}", path.display());
+    }
+}
@@ -0,0 +1,320 @@
+//! Base, temporal and environmental scores of CVSS v3 vectors.
+//!
+//! Scanners usually report a single score, which hides the temporal and environmental
+//! metrics a vector may carry. The scores are computed with the formulas of the CVSS v3.1
+//! specification, which are also applied to v3.0 vectors. Other CVSS versions are not
+//! supported. Used by `VEX2PDF_CVSS_BREAKDOWN`.
+//!
+
+use std::collections::HashMap;
+
+/// Scores of a CVSS v3 vector
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CvssScores {
+    /// Base score
+    pub base: f64,
+    /// Temporal score, `None` if the vector has no temporal metrics
+    pub temporal: Option<f64>,
+    /// Environmental score, `None` if the vector has no environmental metrics
+    pub environmental: Option<f64>,
+}
+
+/// Metrics that adjust the base score for the current state of exploits and fixes
+const TEMPORAL_METRICS: [&str; 3] = ["E", "RL", "RC"];
+
+/// Metrics that adjust the base score to a deployment
+const ENVIRONMENTAL_METRICS: [&str; 11] = [
+    "CR", "IR", "AR", "MAV", "MAC", "MPR", "MUI", "MS", "MC", "MI", "MA",
+];
+
+/// Weights of the security requirement metrics `CR`, `IR` and `AR`
+const REQUIREMENT_WEIGHTS: [(&str, f64); 3] = [("H", 1.5), ("M", 1.0), ("L", 0.5)];
+
+/// The exploitability and impact metrics of a vector, either the base metrics or the
+/// modified ones of the environmental group
+struct Metrics<'v> {
+    attack_vector: &'v str,
+    attack_complexity: &'v str,
+    privileges_required: &'v str,
+    user_interaction: &'v str,
+    scope: &'v str,
+    confidentiality: &'v str,
+    integrity: &'v str,
+    availability: &'v str,
+}
+
+impl Metrics<'_> {
+    /// Returns the exploitability sub score
+    fn exploitability(&self) -> Option<f64> {
+        let attack_vector = weight(
+            self.attack_vector,
+            &[("N", 0.85), ("A", 0.62), ("L", 0.55), ("P", 0.2)],
+        )?;
+        let attack_complexity = weight(self.attack_complexity, &[("L", 0.77), ("H", 0.44)])?;
+        // Privileges weigh more when the impact reaches beyond the vulnerable component
+        let privileges_required = if self.scope_changed()? {
+            weight(
+                self.privileges_required,
+                &[("N", 0.85), ("L", 0.68), ("H", 0.5)],
+            )?
+        } else {
+            weight(
+                self.privileges_required,
+                &[("N", 0.85), ("L", 0.62), ("H", 0.27)],
+            )?
+        };
+        let user_interaction = weight(self.user_interaction, &[("N", 0.85), ("R", 0.62)])?;
+
+        Some(8.22 * attack_vector * attack_complexity * privileges_required * user_interaction)
+    }
+
+    /// Returns the weights of the confidentiality, integrity and availability impacts
+    fn impacts(&self) -> Option<[f64; 3]> {
+        let impact = |value| weight(value, &[("H", 0.56), ("L", 0.22), ("N", 0.0)]);
+
+        Some([
+            impact(self.confidentiality)?,
+            impact(self.integrity)?,
+            impact(self.availability)?,
+        ])
+    }
+
+    /// Returns whether the scope is changed, `None` for an invalid scope
+    fn scope_changed(&self) -> Option<bool> {
+        match self.scope {
+            "U" => Some(false),
+            "C" => Some(true),
+            _ => None,
+        }
+    }
+
+    /// Returns the base score
+    fn base_score(&self) -> Option<f64> {
+        let [confidentiality, integrity, availability] = self.impacts()?;
+        let impact_sub_score =
+            1.0 - (1.0 - confidentiality) * (1.0 - integrity) * (1.0 - availability);
+        let scope_changed = self.scope_changed()?;
+        let impact = if scope_changed {
+            7.52 * (impact_sub_score - 0.029) - 3.25 * (impact_sub_score - 0.02).powi(15)
+        } else {
+            6.42 * impact_sub_score
+        };
+
+        Some(combined_score(
+            impact,
+            self.exploitability()?,
+            scope_changed,
+        ))
+    }
+
+    /// Returns the environmental score of the modified metrics
+    fn environmental_score(&self, requirements: [f64; 3], temporal_factor: f64) -> Option<f64> {
+        let impacts = self.impacts()?;
+        let mut unimpacted = 1.0;
+        for (impact, requirement) in impacts.iter().zip(requirements) {
+            unimpacted *= 1.0 - requirement * impact;
+        }
+        let impact_sub_score = (1.0 - unimpacted).min(0.915);
+        let scope_changed = self.scope_changed()?;
+        let impact = if scope_changed {
+            7.52 * (impact_sub_score - 0.029) - 3.25 * (impact_sub_score * 0.9731 - 0.02).powi(13)
+        } else {
+            6.42 * impact_sub_score
+        };
+
+        let score = combined_score(impact, self.exploitability()?, scope_changed);
+        Some(roundup(score * temporal_factor))
+    }
+}
+
+/// Combines impact and exploitability into a score between 0 and 10
+fn combined_score(impact: f64, exploitability: f64, scope_changed: bool) -> f64 {
+    if impact <= 0.0 {
+        0.0
+    } else if scope_changed {
+        roundup((1.08 * (impact + exploitability)).min(10.0))
+    } else {
+        roundup((impact + exploitability).min(10.0))
+    }
+}
+
+/// Returns the weight of a metric value, `None` for values not in `weights`
+fn weight(value: &str, weights: &[(&str, f64)]) -> Option<f64> {
+    weights
+        .iter()
+        .find(|(name, _)| *name == value)
+        .map(|(_, weight)| *weight)
+}
+
+/// Returns the weight of an optional metric, 1 if it is missing or not defined (`X`)
+fn optional_weight(
+    metrics: &HashMap<&str, &str>,
+    metric: &str,
+    weights: &[(&str, f64)],
+) -> Option<f64> {
+    match metrics.get(metric) {
+        None | Some(&"X") => Some(1.0),
+        Some(value) => weight(value, weights),
+    }
+}
+
+/// Rounds up to one decimal as defined by CVSS v3.1, avoiding floating point artifacts
+fn roundup(value: f64) -> f64 {
+    let scaled = (value * 100_000.0).round() as i64;
+    if scaled % 10_000 == 0 {
+        scaled as f64 / 100_000.0
+    } else {
+        (scaled / 10_000 + 1) as f64 / 10.0
+    }
+}
+
+/// Computes the scores of a CVSS v3 vector such as `CVSS:3.1/AV:N/AC:L/PR:N/UI:R/S:U/C:H/I:H/A:N`.
+///
+/// Returns `None` if the vector is not a CVSS v3 vector, lacks a base metric or contains
+/// an invalid value. Temporal and environmental scores are only computed if the vector
+/// defines at least one of their metrics.
+///
+/// # Examples
+///
+/// ```
+/// use vex2pdf::lib_utils::cvss::cvss3_scores;
+///
+/// let scores = cvss3_scores("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/E:P/RL:O/RC:C").unwrap();
+/// assert_eq!(scores.base, 9.8);
+/// assert_eq!(scores.temporal, Some(8.8));
+/// assert_eq!(scores.environmental, None);
+/// ```
+pub fn cvss3_scores(vector: &str) -> Option<CvssScores> {
+    let mut parts = vector.trim().split('/');
+    if !parts.next()?.starts_with("CVSS:3.") {
+        return None;
+    }
+
+    let mut metrics: HashMap<&str, &str> = HashMap::new();
+    for part in parts {
+        let (metric, value) = part.split_once(':')?;
+        metrics.insert(metric, value);
+    }
+
+    let base = Metrics {
+        attack_vector: metrics.get("AV").copied()?,
+        attack_complexity: metrics.get("AC").copied()?,
+        privileges_required: metrics.get("PR").copied()?,
+        user_interaction: metrics.get("UI").copied()?,
+        scope: metrics.get("S").copied()?,
+        confidentiality: metrics.get("C").copied()?,
+        integrity: metrics.get("I").copied()?,
+        availability: metrics.get("A").copied()?,
+    };
+    let base_score = base.base_score()?;
+
+    let is_defined = |metric: &&str| metrics.get(metric).is_some_and(|value| *value != "X");
+    let temporal_factor =
+        optional_weight(
+            &metrics,
+            "E",
+            &[("H", 1.0), ("F", 0.97), ("P", 0.94), ("U", 0.91)],
+        )? * optional_weight(
+            &metrics,
+            "RL",
+            &[("U", 1.0), ("W", 0.97), ("T", 0.96), ("O", 0.95)],
+        )? * optional_weight(&metrics, "RC", &[("C", 1.0), ("R", 0.96), ("U", 0.92)])?;
+
+    let temporal = TEMPORAL_METRICS
+        .iter()
+        .any(is_defined)
+        .then(|| roundup(base_score * temporal_factor));
+
+    let environmental = if ENVIRONMENTAL_METRICS.iter().any(is_defined) {
+        // Modified metrics that are not defined take the value of the base metric
+        let modified = |metric: &str, base_value| match metrics.get(metric).copied() {
+            Some(value) if value != "X" => value,
+            _ => base_value,
+        };
+        let modified_metrics = Metrics {
+            attack_vector: modified("MAV", base.attack_vector),
+            attack_complexity: modified("MAC", base.attack_complexity),
+            privileges_required: modified("MPR", base.privileges_required),
+            user_interaction: modified("MUI", base.user_interaction),
+            scope: modified("MS", base.scope),
+            confidentiality: modified("MC", base.confidentiality),
+            integrity: modified("MI", base.integrity),
+            availability: modified("MA", base.availability),
+        };
+        let requirements = [
+            optional_weight(&metrics, "CR", &REQUIREMENT_WEIGHTS)?,
+            optional_weight(&metrics, "IR", &REQUIREMENT_WEIGHTS)?,
+            optional_weight(&metrics, "AR", &REQUIREMENT_WEIGHTS)?,
+        ];
+        Some(modified_metrics.environmental_score(requirements, temporal_factor)?)
+    } else {
+        None
+    };
+
+    Some(CvssScores {
+        base: base_score,
+        temporal,
+        environmental,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_base_scores() {
+        let score = |vector| cvss3_scores(vector).map(|scores| scores.base);
+
+        assert_eq!(
+            score("CVSS:3.1/AV:N/AC:L/PR:N/UI:R/S:U/C:H/I:H/A:N"),
+            Some(8.1)
+        );
+        assert_eq!(
+            score("CVSS:3.1/AV:N/AC:L/PR:N/UI:R/S:C/C:L/I:L/A:N"),
+            Some(6.1)
+        );
+        assert_eq!(
+            score("CVSS:3.0/AV:N/AC:L/PR:N/UI:N/S:U/C:N/I:N/A:N"),
+            Some(0.0)
+        );
+    }
+
+    #[test]
+    fn test_temporal_and_environmental_scores() {
+        let scores = cvss3_scores(
+            "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/E:P/RL:O/RC:C/CR:L/IR:L/AR:L/MAV:L",
+        )
+        .expect("valid vector");
+
+        assert_eq!(
+            scores,
+            CvssScores {
+                base: 9.8,
+                temporal: Some(8.8),
+                environmental: Some(5.9),
+            }
+        );
+    }
+
+    #[test]
+    fn test_invalid_vectors() {
+        assert_eq!(cvss3_scores("AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H"), None);
+        assert_eq!(
+            cvss3_scores("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H"),
+            None
+        );
+        assert_eq!(
+            cvss3_scores("CVSS:3.1/AV:Q/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H"),
+            None
+        );
+        assert_eq!(cvss3_scores("CVSS:2.0/AV:N/AC:L/Au:N/C:P/I:P/A:P"), None);
+    }
+
+    #[test]
+    fn test_roundup() {
+        assert_eq!(roundup(4.0), 4.0);
+        assert_eq!(roundup(4.02), 4.1);
+        assert_eq!(roundup(4.000_000_1), 4.0);
+    }
+}
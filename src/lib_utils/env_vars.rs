@@ -1,12 +1,16 @@
+use super::console::progress;
+use super::warnings::warn;
+use std::str::FromStr;
+
 /// Environment variable names used in the application
 pub enum EnvVarNames {
     /// Standard HOME environment variable
     Home,
-    /// Controls whether to display "No Vulnerabilities reported" message
+    /// Controls whether to display the "No vulnerabilities found" or "No vulnerability data" message
     /// When set to "false", the Vulnerabilities section will be omitted completely
     /// if no vulnerabilities exist.
-    /// When set to "true" or not set (default), the "No Vulnerabilities reported"
-    /// message will be displayed when no vulnerabilities are present.
+    /// When set to "true" or not set (default), the message will be displayed when no
+    /// vulnerabilities are present.
     NoVulnsMsg,
     /// USER CANNOT YET OVERRIDE THIS OPTION
     ProcessJson,
@@ -22,6 +26,158 @@ pub enum EnvVarNames {
     PdfName,
     /// Whether the components section is displayed in the generated result or only vulnerabilities
     ShowComponents,
+    /// When set, only the vulnerability with this ID is rendered in the report
+    OnlyVulnId,
+    /// Draws a thin border around every page if set to true
+    PageBorder,
+    /// Hex color (e.g. `#F5F5F5`) used to fill the background of every page
+    PageBackground,
+    /// Maximum number of pages a report may have before a warning is emitted. 0 disables the check
+    MaxPages,
+    /// Makes the run fail if any warning was emitted during processing
+    WarningsAsErrors,
+    /// Writes a `<stem>.vulns.json` per-vulnerability summary next to each input if set to true
+    EmitVulnJson,
+    /// Overrides the running page header which defaults to the report title
+    HeaderText,
+    /// Path to a text or markdown file rendered as a disclaimer section at the end of the report
+    DisclaimerFile,
+    /// Only ratings with this score method (e.g. `CVSSv31`) are rendered when present
+    PreferredMethod,
+    /// Redacts email addresses and configured patterns in the rendered output if set to true
+    Redact,
+    /// Comma separated list of additional patterns (e.g. internal hostnames) to redact
+    RedactPatterns,
+    /// Vulnerabilities published more than this many days ago are flagged as aging
+    AgingDays,
+    /// Comma separated list of HTTP(S) URLs of documents to fetch and convert (requires the `http` feature)
+    InputUrl,
+    /// Shows the primary tool and its version as a `Generated by` line below the title if set to true
+    ShowGeneratedBy,
+    /// Renders vulnerabilities as a compact table with one row per vulnerability if set to true
+    Compact,
+    /// Placeholder text rendered for missing values, defaults to `N/A`
+    NaText,
+    /// Numbers components with a `bom-ref` and lists the affected components of each vulnerability with those numbers if set to true
+    CrossRefs,
+    /// Writes a minimal SARIF findings file next to each input if set to true
+    EmitSarif,
+    /// Sorts components by name and version before rendering if set to true
+    SortComponents,
+    /// Renders the `bom-ref` of vulnerabilities and components if set to true
+    ShowBomRefs,
+    /// Turns the defaults of the show toggles off for a concise report if set to true. Explicitly set toggles still take precedence
+    Minimal,
+    /// Path to a cover page template with placeholders such as `{title}` that replaces the built-in cover
+    CoverTemplate,
+    /// Language of the report (e.g. `de` or `de_DE`), used for locale specific number formatting
+    Lang,
+    /// Sorts the ratings of each vulnerability by severity, most severe first, if set to true
+    SortRatings,
+    /// Path to a TrueType font file replacing the embedded regular font variant
+    FontRegular,
+    /// Path to a TrueType font file replacing the embedded bold font variant
+    FontBold,
+    /// Path to a TrueType font file replacing the embedded italic font variant
+    FontItalic,
+    /// Path to a TrueType font file replacing the embedded bold-italic font variant
+    FontBoldItalic,
+    /// Lists vulnerabilities grouped under the components they affect if set to true
+    GroupByComponent,
+    /// Path to a previous JSON or XML document to list the changed vulnerabilities against
+    DiffAgainst,
+    /// Renders the file name of the input document in the Document Information section if set to true
+    ShowSourceFile,
+    /// Renders the absolute path of the input document instead of its file name if set to true
+    ShowSourcePath,
+    /// Number of retries for reading input files and writing reports that fail with a transient I/O error
+    IoRetries,
+    /// Whether the "No Vulnerabilities" message is framed and padded, defaults to true
+    NoVulnsFramed,
+    /// Only render the highest version of input documents sharing a serial number
+    KeepLatest,
+    /// Earliest publication date (`YYYY-MM-DD`) of the rendered vulnerabilities
+    Since,
+    /// Latest publication date (`YYYY-MM-DD`) of the rendered vulnerabilities
+    Until,
+    /// Prefix severities with a symbol so they can be told apart without color
+    SeverityIcons,
+    /// Number of front matter pages, such as a cover, that are left unnumbered
+    PageNumberOffset,
+    /// Append the license texts attached to components and services to the report
+    LicenseAppendix,
+    /// Report vulnerability targets and dependencies referencing unknown bom-refs
+    CheckRefs,
+    /// Draw a gauge of the highest score of each vulnerability
+    ScoreGauge,
+    /// Only list components targeted by a vulnerability in the Components section
+    VulnComponentsOnly,
+    /// Also write a plain text rendering of the report next to each input file
+    EmitTxt,
+    /// Show the base, temporal and environmental scores computed from CVSS v3 vectors
+    CvssBreakdown,
+    /// Name of the issuing organization rendered in the running page header
+    OrgName,
+    /// Number of tools above which the tools list is moved to an appendix
+    ToolsMax,
+    /// Render analysis responses as colored badges instead of a comma separated list
+    ResponseBadges,
+    /// Produce byte-identical PDFs for identical input by fixing all time stamps and document IDs
+    Deterministic,
+    /// Seconds since the Unix epoch used as creation date of deterministic PDFs, see <https://reproducible-builds.org/specs/source-date-epoch/>
+    SourceDateEpoch,
+    /// Show the supplier and its contacts under each component
+    ShowContacts,
+    /// Print a data completeness scorecard of the vulnerabilities of each document
+    CompletenessReport,
+    /// Render the data completeness scorecard as a section of the report
+    CompletenessSection,
+    /// Comma separated columns of the components table, which replaces the components list
+    ComponentColumns,
+    /// Link component purls to their package registry pages
+    PurlLinks,
+    /// Name of the tool credited in the PDF metadata, `false` to credit none
+    Attribution,
+    /// Render the warnings noticed during conversion as a Conversion Notes section
+    ShowNotes,
+    /// Date rendered below the report title, `now` for the date the report is generated
+    ReportDate,
+    /// Write the reports to a fresh temporary directory and print their paths to standard output
+    TempOutput,
+    /// Group vulnerabilities under headings of their highest severity
+    GroupBySeverity,
+    /// Print the serial number and spec version of the document in the footer of every page
+    MetaFooter,
+    /// Lowest scores of the Critical, High and Medium severities derived from scores
+    SeverityBands,
+    /// Leave components identical to the metadata component out of the Components section
+    DedupMetadataComponent,
+    /// Compress the streams of generated reports and remove unused objects
+    Optimize,
+    /// Embed only the glyphs drawn in generated reports instead of the complete fonts
+    SubsetFonts,
+    /// What happens when a PDF already exists: `overwrite` (default), `skip` or `version`
+    OutputMode,
+    /// Group the Components section under the ecosystems of the component purls
+    GroupByEcosystem,
+    /// Format processed when a document is present as both JSON and XML file: `json` or `xml`
+    PreferFormat,
+    /// Vertical space in lines between sections and larger blocks of the report
+    SectionSpacing,
+    /// Vertical space in lines between the items of a section
+    ItemSpacing,
+    /// Show a colored badge with the highest severity and score next to each vulnerability ID
+    SeverityBadge,
+    /// Write the parsed document re-serialized as CycloneDX 1.5 JSON next to each input
+    DumpNormalized,
+    /// Show the SHA-256 of the input file in the Document Information section
+    ShowSourceHash,
+    /// PDF version written to the header of the reports, one of 1.3 to 1.7
+    PdfVersion,
+    /// Only process files modified within a duration such as `24h` or since a `YYYY-MM-DD` date or UTC timestamp
+    ModifiedSince,
+    /// Classification marking printed at the top and bottom of every page, e.g. `UNCLASSIFIED//FOUO`
+    Classification,
 }
 
 impl EnvVarNames {
@@ -36,52 +192,132 @@ impl EnvVarNames {
             EnvVarNames::ReportTitle => "VEX2PDF_REPORT_TITLE",
             EnvVarNames::PdfName => "VEX2PDF_PDF_META_NAME",
             EnvVarNames::ShowComponents => "VEX2PDF_SHOW_COMPONENTS",
+            EnvVarNames::OnlyVulnId => "VEX2PDF_ONLY_VULN_ID",
+            EnvVarNames::PageBorder => "VEX2PDF_PAGE_BORDER",
+            EnvVarNames::PageBackground => "VEX2PDF_PAGE_BG",
+            EnvVarNames::MaxPages => "VEX2PDF_MAX_PAGES",
+            EnvVarNames::WarningsAsErrors => "VEX2PDF_WARNINGS_AS_ERRORS",
+            EnvVarNames::EmitVulnJson => "VEX2PDF_EMIT_VULN_JSON",
+            EnvVarNames::HeaderText => "VEX2PDF_HEADER_TEXT",
+            EnvVarNames::DisclaimerFile => "VEX2PDF_DISCLAIMER_FILE",
+            EnvVarNames::PreferredMethod => "VEX2PDF_PREFERRED_METHOD",
+            EnvVarNames::Redact => "VEX2PDF_REDACT",
+            EnvVarNames::RedactPatterns => "VEX2PDF_REDACT_PATTERNS",
+            EnvVarNames::AgingDays => "VEX2PDF_AGING_DAYS",
+            EnvVarNames::InputUrl => "VEX2PDF_INPUT_URL",
+            EnvVarNames::ShowGeneratedBy => "VEX2PDF_SHOW_GENERATED_BY",
+            EnvVarNames::Compact => "VEX2PDF_COMPACT",
+            EnvVarNames::NaText => "VEX2PDF_NA_TEXT",
+            EnvVarNames::CrossRefs => "VEX2PDF_CROSS_REFS",
+            EnvVarNames::EmitSarif => "VEX2PDF_EMIT_SARIF",
+            EnvVarNames::SortComponents => "VEX2PDF_SORT_COMPONENTS",
+            EnvVarNames::ShowBomRefs => "VEX2PDF_SHOW_BOM_REFS",
+            EnvVarNames::Minimal => "VEX2PDF_MINIMAL",
+            EnvVarNames::CoverTemplate => "VEX2PDF_COVER_TEMPLATE",
+            EnvVarNames::Lang => "VEX2PDF_LANG",
+            EnvVarNames::SortRatings => "VEX2PDF_SORT_RATINGS",
+            EnvVarNames::FontRegular => "VEX2PDF_FONT_REGULAR",
+            EnvVarNames::FontBold => "VEX2PDF_FONT_BOLD",
+            EnvVarNames::FontItalic => "VEX2PDF_FONT_ITALIC",
+            EnvVarNames::FontBoldItalic => "VEX2PDF_FONT_BOLD_ITALIC",
+            EnvVarNames::GroupByComponent => "VEX2PDF_GROUP_BY_COMPONENT",
+            EnvVarNames::DiffAgainst => "VEX2PDF_DIFF_AGAINST",
+            EnvVarNames::ShowSourceFile => "VEX2PDF_SHOW_SOURCE_FILE",
+            EnvVarNames::ShowSourcePath => "VEX2PDF_SHOW_SOURCE_PATH",
+            EnvVarNames::IoRetries => "VEX2PDF_IO_RETRIES",
+            EnvVarNames::NoVulnsFramed => "VEX2PDF_NOVULNS_FRAMED",
+            EnvVarNames::KeepLatest => "VEX2PDF_KEEP_LATEST",
+            EnvVarNames::Since => "VEX2PDF_SINCE",
+            EnvVarNames::Until => "VEX2PDF_UNTIL",
+            EnvVarNames::SeverityIcons => "VEX2PDF_SEVERITY_ICONS",
+            EnvVarNames::PageNumberOffset => "VEX2PDF_PAGE_NUMBER_OFFSET",
+            EnvVarNames::LicenseAppendix => "VEX2PDF_LICENSE_APPENDIX",
+            EnvVarNames::CheckRefs => "VEX2PDF_CHECK_REFS",
+            EnvVarNames::ScoreGauge => "VEX2PDF_SCORE_GAUGE",
+            EnvVarNames::VulnComponentsOnly => "VEX2PDF_VULN_COMPONENTS_ONLY",
+            EnvVarNames::EmitTxt => "VEX2PDF_EMIT_TXT",
+            EnvVarNames::CvssBreakdown => "VEX2PDF_CVSS_BREAKDOWN",
+            EnvVarNames::OrgName => "VEX2PDF_ORG_NAME",
+            EnvVarNames::ToolsMax => "VEX2PDF_TOOLS_MAX",
+            EnvVarNames::ResponseBadges => "VEX2PDF_RESPONSE_BADGES",
+            EnvVarNames::Deterministic => "VEX2PDF_DETERMINISTIC",
+            EnvVarNames::SourceDateEpoch => "SOURCE_DATE_EPOCH",
+            EnvVarNames::ShowContacts => "VEX2PDF_SHOW_CONTACTS",
+            EnvVarNames::CompletenessReport => "VEX2PDF_COMPLETENESS_REPORT",
+            EnvVarNames::CompletenessSection => "VEX2PDF_COMPLETENESS_SECTION",
+            EnvVarNames::ComponentColumns => "VEX2PDF_COMPONENT_COLUMNS",
+            EnvVarNames::PurlLinks => "VEX2PDF_PURL_LINKS",
+            EnvVarNames::Attribution => "VEX2PDF_ATTRIBUTION",
+            EnvVarNames::ShowNotes => "VEX2PDF_SHOW_NOTES",
+            EnvVarNames::ReportDate => "VEX2PDF_REPORT_DATE",
+            EnvVarNames::TempOutput => "VEX2PDF_TEMP_OUTPUT",
+            EnvVarNames::GroupBySeverity => "VEX2PDF_GROUP_BY_SEVERITY",
+            EnvVarNames::MetaFooter => "VEX2PDF_META_FOOTER",
+            EnvVarNames::SeverityBands => "VEX2PDF_SEVERITY_BANDS",
+            EnvVarNames::DedupMetadataComponent => "VEX2PDF_DEDUP_METADATA_COMPONENT",
+            EnvVarNames::Optimize => "VEX2PDF_OPTIMIZE",
+            EnvVarNames::SubsetFonts => "VEX2PDF_SUBSET_FONTS",
+            EnvVarNames::OutputMode => "VEX2PDF_OUTPUT_MODE",
+            EnvVarNames::GroupByEcosystem => "VEX2PDF_GROUP_BY_ECOSYSTEM",
+            EnvVarNames::PreferFormat => "VEX2PDF_PREFER_FORMAT",
+            EnvVarNames::SectionSpacing => "VEX2PDF_SECTION_SPACING",
+            EnvVarNames::ItemSpacing => "VEX2PDF_ITEM_SPACING",
+            EnvVarNames::SeverityBadge => "VEX2PDF_SEVERITY_BADGE",
+            EnvVarNames::DumpNormalized => "VEX2PDF_DUMP_NORMALIZED",
+            EnvVarNames::ShowSourceHash => "VEX2PDF_SHOW_SOURCE_HASH",
+            EnvVarNames::PdfVersion => "VEX2PDF_PDF_VERSION",
+            EnvVarNames::ModifiedSince => "VEX2PDF_MODIFIED_SINCE",
+            EnvVarNames::Classification => "VEX2PDF_CLASSIFICATION",
         }
     }
     /// this is useful for environment variables which should be on by default
     pub fn is_on_or_unset(&self) -> bool {
-        match std::env::var(self.as_str()) {
-            Ok(value) => self.is_value_on(&value),
-            Err(_) => true, // Variable isn't set, default to ON
-        }
+        self.is_on_or(true)
     }
 
     pub fn is_on(&self) -> bool {
+        self.is_on_or(false)
+    }
+
+    /// Returns whether the variable is on, or `default` if it is not set.
+    ///
+    /// This is useful for toggles whose default depends on other settings such as `VEX2PDF_MINIMAL`
+    pub fn is_on_or(&self, default: bool) -> bool {
         match std::env::var(self.as_str()) {
             Ok(value) => self.is_value_on(&value),
-            Err(_) => false, // Variable isn't set, so we are off
+            Err(_) => default, // Variable isn't set, use the given default
         }
     }
 
     /// Prints information about currently used pdf titles
     pub fn print_report_titles_info() {
-        println!();
+        progress("");
         match EnvVarNames::ReportTitle.get_value() {
             Some(title) => {
-                println!("Overriding report title to {title}");
+                progress(format!("Overriding report title to {title}"));
             }
             None => {
-                println!("Using default report title");
-                println!(
+                progress("Using default report title");
+                progress(format!(
                     "to override this set the {} environment variable to the desired title",
                     EnvVarNames::ReportTitle.as_str()
-                );
+                ));
             }
         };
-        println!();
+        progress("");
         match EnvVarNames::PdfName.get_value() {
             Some(title) => {
-                println!("Overriding pdf metadata title to {title}");
+                progress(format!("Overriding pdf metadata title to {title}"));
             }
             None => {
-                println!("Using default pdf metadata title");
-                println!(
+                progress("Using default pdf metadata title");
+                progress(format!(
                     "to override this set the {} environment variable to the desired title",
                     EnvVarNames::PdfName.as_str()
-                );
+                ));
             }
         };
-        println!();
+        progress("");
     }
 
     // Helper method to determine if a value represents "on"
@@ -99,6 +335,37 @@ impl EnvVarNames {
             Err(_) => None,
         }
     }
+
+    /// Helper method to get the value of the variable parsed into `T`
+    ///
+    /// Returns `None` if the variable is unset. Values that cannot be parsed are
+    /// reported with a warning and also yield `None`.
+    pub fn get_parsed_value<T: FromStr>(&self) -> Option<T> {
+        let value = self.get_value()?;
+        match value.trim().parse::<T>() {
+            Ok(parsed) => Some(parsed),
+            Err(_) => {
+                warn(format!(
+                    "invalid value '{value}' for {}. ignoring",
+                    self.as_str()
+                ));
+                None
+            }
+        }
+    }
+
+    /// Returns the comma separated entries of the variable, trimmed and without empty entries
+    pub fn get_list_value(&self) -> Vec<String> {
+        self.get_value()
+            .map(|value| {
+                value
+                    .split(',')
+                    .map(|entry| entry.trim().to_string())
+                    .filter(|entry| !entry.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
 }
 
 #[cfg(test)]
@@ -21,7 +21,7 @@
 /// assert_eq!(InputFileType::XML.as_str_uppercase(), "XML");
 /// assert_eq!(InputFileType::JSON.as_str_uppercase(), "JSON");
 /// ```
-#[derive(Eq, Hash, PartialEq)]
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
 pub enum InputFileType {
     /// Represents an XML format VEX document
     XML,
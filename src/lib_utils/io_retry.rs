@@ -0,0 +1,109 @@
+//! Bounded retries for transient file I/O errors.
+//!
+//! Reads and writes on network filesystems occasionally fail and succeed when repeated.
+//! The number of retries is configured with `VEX2PDF_IO_RETRIES` and defaults to none,
+//! so every operation is attempted exactly once unless retries are enabled.
+//!
+
+use super::console::progress;
+use std::io;
+use std::thread;
+use std::time::Duration;
+
+/// Delay before the first retry, doubled for every further retry
+pub const INITIAL_BACKOFF: Duration = Duration::from_millis(100);
+
+/// Upper bound of the delay between two attempts
+pub const MAX_BACKOFF: Duration = Duration::from_secs(5);
+
+/// Runs `operation` and repeats it up to `retries` times while it fails with an error that
+/// may be transient, waiting with exponential backoff between the attempts.
+///
+/// Errors that repeating the operation cannot fix, such as a missing file, are returned
+/// immediately.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::fs;
+/// use vex2pdf::lib_utils::io_retry::retry_io;
+///
+/// let content = retry_io(3, || fs::read("bom.json")).expect("failed to read bom.json");
+/// ```
+pub fn retry_io<T>(retries: u32, mut operation: impl FnMut() -> io::Result<T>) -> io::Result<T> {
+    let mut attempt = 0;
+    loop {
+        match operation() {
+            Err(e) if attempt < retries && is_transient(&e) => {
+                let delay = backoff(attempt);
+                progress(format!(
+                    "I/O error, retrying in {} ms ({}/{retries}): {e}",
+                    delay.as_millis(),
+                    attempt + 1
+                ));
+                thread::sleep(delay);
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Returns false for errors that repeating the operation cannot fix
+fn is_transient(error: &io::Error) -> bool {
+    !matches!(
+        error.kind(),
+        io::ErrorKind::NotFound
+            | io::ErrorKind::PermissionDenied
+            | io::ErrorKind::AlreadyExists
+            | io::ErrorKind::InvalidInput
+            | io::ErrorKind::InvalidData
+            | io::ErrorKind::Unsupported
+    )
+}
+
+/// Returns the delay before the retry following the given zero based attempt
+fn backoff(attempt: u32) -> Duration {
+    INITIAL_BACKOFF
+        .saturating_mul(2u32.saturating_pow(attempt))
+        .min(MAX_BACKOFF)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retry_io_repeats_transient_errors() {
+        let mut attempts = 0;
+        let result = retry_io(2, || {
+            attempts += 1;
+            if attempts < 3 {
+                Err(io::Error::new(io::ErrorKind::TimedOut, "stale handle"))
+            } else {
+                Ok(attempts)
+            }
+        });
+
+        assert_eq!(result.expect("third attempt should succeed"), 3);
+    }
+
+    #[test]
+    fn test_retry_io_fails_fast_on_permanent_errors() {
+        let mut attempts = 0;
+        let result: io::Result<()> = retry_io(3, || {
+            attempts += 1;
+            Err(io::Error::new(io::ErrorKind::NotFound, "missing"))
+        });
+
+        assert!(result.is_err());
+        assert_eq!(attempts, 1);
+    }
+
+    #[test]
+    fn test_backoff_is_exponential_and_capped() {
+        assert_eq!(backoff(0), Duration::from_millis(100));
+        assert_eq!(backoff(2), Duration::from_millis(400));
+        assert_eq!(backoff(20), MAX_BACKOFF);
+    }
+}
@@ -0,0 +1,200 @@
+//! Package registry pages of package URLs (purls).
+//!
+//! A purl such as `pkg:cargo/serde@1.0.219` names the ecosystem, the package and its version,
+//! which is enough to build the URL of the package on its public registry. Used by
+//! `VEX2PDF_PURL_LINKS` and, for the ecosystem, by `VEX2PDF_GROUP_BY_ECOSYSTEM`.
+//!
+
+/// Components of a package URL, percent-decoded
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PackageUrl {
+    ecosystem: String,
+    namespace: Option<String>,
+    name: String,
+    version: Option<String>,
+}
+
+/// Splits a package URL into its components, ignoring qualifiers and subpath.
+///
+/// Returns `None` for strings that are not package URLs.
+fn parse_purl(purl: &str) -> Option<PackageUrl> {
+    let rest = purl.trim().strip_prefix("pkg:")?;
+    // Qualifiers and subpath do not identify the registry page
+    let rest = rest.split(['?', '#']).next().unwrap_or_default();
+    let (ecosystem, path) = rest.split_once('/')?;
+    let path = path.trim_matches('/');
+
+    // The version follows the last `@` of the name, scoped npm namespaces may contain `@`
+    let (path, version) = match path.rsplit_once('@') {
+        Some((path, version)) if !version.contains('/') && !path.is_empty() => {
+            (path, Some(percent_decode(version)))
+        }
+        _ => (path, None),
+    };
+    let (namespace, name) = match path.rsplit_once('/') {
+        Some((namespace, name)) => (Some(percent_decode(namespace)), name),
+        None => (None, path),
+    };
+    if ecosystem.is_empty() || name.is_empty() {
+        return None;
+    }
+
+    Some(PackageUrl {
+        ecosystem: ecosystem.to_lowercase(),
+        namespace,
+        name: percent_decode(name),
+        version: version.filter(|version| !version.is_empty()),
+    })
+}
+
+/// Decodes `%XX` escapes, leaving invalid escapes as they are
+fn percent_decode(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let escaped = (bytes[i] == b'%')
+            .then(|| bytes.get(i + 1..i + 3))
+            .flatten()
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match escaped {
+            Some(byte) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            None => {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// Returns the ecosystem of a purl, i.e. its lowercased type such as `cargo` or `npm`.
+///
+/// Returns `None` for strings that are not package URLs.
+///
+/// # Examples
+///
+/// ```
+/// use vex2pdf::lib_utils::purl::purl_ecosystem;
+///
+/// assert_eq!(purl_ecosystem("pkg:Maven/org.slf4j/slf4j-api@2.0.13").as_deref(), Some("maven"));
+/// assert_eq!(purl_ecosystem("cargo/serde@1.0.219"), None);
+/// ```
+pub fn purl_ecosystem(purl: &str) -> Option<String> {
+    parse_purl(purl).map(|purl| purl.ecosystem)
+}
+
+/// Returns the registry page of the package a purl refers to, the page of its version if
+/// the purl has one.
+///
+/// Supported ecosystems are `cargo`, `npm`, `pypi`, `maven`, `gem`, `nuget`, `golang`,
+/// `composer` and `github`. Returns `None` for other ecosystems and strings that are not
+/// package URLs.
+///
+/// # Examples
+///
+/// ```
+/// use vex2pdf::lib_utils::purl::registry_url;
+///
+/// assert_eq!(
+///     registry_url("pkg:cargo/serde@1.0.219").as_deref(),
+///     Some("https://crates.io/crates/serde/1.0.219")
+/// );
+/// assert_eq!(registry_url("pkg:generic/openssl@3.0.0"), None);
+/// ```
+pub fn registry_url(purl: &str) -> Option<String> {
+    let purl = parse_purl(purl)?;
+    let version = purl.version.as_deref();
+    let qualified_name = match &purl.namespace {
+        Some(namespace) => format!("{namespace}/{}", purl.name),
+        None => purl.name.clone(),
+    };
+
+    let url = match purl.ecosystem.as_str() {
+        "cargo" => match version {
+            Some(version) => format!("https://crates.io/crates/{}/{version}", purl.name),
+            None => format!("https://crates.io/crates/{}", purl.name),
+        },
+        "npm" => match version {
+            Some(version) => format!("https://www.npmjs.com/package/{qualified_name}/v/{version}"),
+            None => format!("https://www.npmjs.com/package/{qualified_name}"),
+        },
+        "pypi" => match version {
+            Some(version) => format!("https://pypi.org/project/{}/{version}/", purl.name),
+            None => format!("https://pypi.org/project/{}/", purl.name),
+        },
+        // Maven artifacts cannot be found without their group
+        "maven" => {
+            let group = purl.namespace.as_deref()?;
+            match version {
+                Some(version) => format!(
+                    "https://central.sonatype.com/artifact/{group}/{}/{version}",
+                    purl.name
+                ),
+                None => format!(
+                    "https://central.sonatype.com/artifact/{group}/{}",
+                    purl.name
+                ),
+            }
+        }
+        "gem" => match version {
+            Some(version) => format!("https://rubygems.org/gems/{}/versions/{version}", purl.name),
+            None => format!("https://rubygems.org/gems/{}", purl.name),
+        },
+        "nuget" => match version {
+            Some(version) => format!("https://www.nuget.org/packages/{}/{version}", purl.name),
+            None => format!("https://www.nuget.org/packages/{}", purl.name),
+        },
+        "golang" => match version {
+            Some(version) => format!("https://pkg.go.dev/{qualified_name}@{version}"),
+            None => format!("https://pkg.go.dev/{qualified_name}"),
+        },
+        "composer" => format!("https://packagist.org/packages/{qualified_name}"),
+        "github" => format!("https://github.com/{qualified_name}"),
+        _ => return None,
+    };
+
+    Some(url)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_urls() {
+        assert_eq!(
+            registry_url("pkg:npm/%40angular/core@16.2.0").as_deref(),
+            Some("https://www.npmjs.com/package/@angular/core/v/16.2.0")
+        );
+        assert_eq!(
+            registry_url("pkg:pypi/requests@2.31.0?extension=whl").as_deref(),
+            Some("https://pypi.org/project/requests/2.31.0/")
+        );
+        assert_eq!(
+            registry_url("pkg:maven/org.apache.commons/commons-text@1.10.0").as_deref(),
+            Some("https://central.sonatype.com/artifact/org.apache.commons/commons-text/1.10.0")
+        );
+        assert_eq!(
+            registry_url("pkg:golang/github.com/gin-gonic/gin@v1.9.1").as_deref(),
+            Some("https://pkg.go.dev/github.com/gin-gonic/gin@v1.9.1")
+        );
+        assert_eq!(
+            registry_url("pkg:cargo/serde").as_deref(),
+            Some("https://crates.io/crates/serde")
+        );
+    }
+
+    #[test]
+    fn test_unsupported_purls_have_no_registry_url() {
+        assert_eq!(registry_url("pkg:deb/debian/curl@7.88.1"), None);
+        assert_eq!(registry_url("pkg:maven/commons-text@1.10.0"), None);
+        assert_eq!(registry_url("cargo/serde@1.0.0"), None);
+        assert_eq!(registry_url("pkg:cargo/"), None);
+    }
+}
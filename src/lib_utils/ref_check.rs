@@ -0,0 +1,181 @@
+//! Validation of the `bom-ref` references within a CycloneDX document.
+//!
+//! Vulnerability targets and dependencies refer to components and services by their
+//! `bom-ref`. A reference that matches none of them is dangling and usually points at
+//! a data integrity problem. Enabled with `VEX2PDF_CHECK_REFS`.
+//!
+
+use cyclonedx_bom::models::component::Component;
+use cyclonedx_bom::models::service::Service;
+use cyclonedx_bom::prelude::Bom;
+use std::collections::HashSet;
+
+/// Prefix of BOM-Link references, which point into other documents and cannot be resolved
+const BOM_LINK_PREFIX: &str = "urn:cdx:";
+
+/// A reference that matches no `bom-ref` of the document
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DanglingRef {
+    /// The unresolved reference
+    pub reference: String,
+    /// Where the reference is used, e.g. `vulnerability CVE-2025-0001`
+    pub referenced_by: String,
+}
+
+/// Returns the vulnerability target, dependency and `dependsOn` references of the document
+/// that match no component or service, in document order.
+///
+/// Components and services are searched recursively, including the metadata component.
+/// BOM-Link references (`urn:cdx:...`) refer to other documents and are not checked.
+///
+/// # Examples
+///
+/// ```
+/// use vex2pdf::lib_utils::ref_check::dangling_refs;
+/// use vex2pdf::lib_utils::run_utils::parse_vex_json_bytes;
+///
+/// let bom = parse_vex_json_bytes(br#"{
+///     "bomFormat": "CycloneDX",
+///     "specVersion": "1.5",
+///     "version": 1,
+///     "dependencies": [{"ref": "pkg:cargo/missing@1.0.0"}]
+/// }"#).unwrap();
+///
+/// assert_eq!(dangling_refs(&bom)[0].reference, "pkg:cargo/missing@1.0.0");
+/// ```
+pub fn dangling_refs(bom: &Bom) -> Vec<DanglingRef> {
+    let mut known = HashSet::new();
+    if let Some(component) = bom
+        .metadata
+        .as_ref()
+        .and_then(|metadata| metadata.component.as_ref())
+    {
+        collect_component_refs(std::slice::from_ref(component), &mut known);
+    }
+    if let Some(components) = &bom.components {
+        collect_component_refs(&components.0, &mut known);
+    }
+    if let Some(services) = &bom.services {
+        collect_service_refs(&services.0, &mut known);
+    }
+
+    let mut dangling = Vec::new();
+    let mut check = |reference: &str, referenced_by: String| {
+        if !known.contains(reference) && !reference.starts_with(BOM_LINK_PREFIX) {
+            dangling.push(DanglingRef {
+                reference: reference.to_string(),
+                referenced_by,
+            });
+        }
+    };
+
+    for vuln in bom.vulnerabilities.iter().flat_map(|vulns| &vulns.0) {
+        let vuln_id = vuln
+            .id
+            .as_ref()
+            .map_or_else(|| "without ID".to_string(), |id| id.to_string());
+        for target in vuln
+            .vulnerability_targets
+            .iter()
+            .flat_map(|targets| &targets.0)
+        {
+            check(&target.bom_ref, format!("vulnerability {vuln_id}"));
+        }
+    }
+
+    for dependency in bom
+        .dependencies
+        .iter()
+        .flat_map(|dependencies| &dependencies.0)
+    {
+        check(&dependency.dependency_ref, "dependencies".to_string());
+        for depends_on in &dependency.dependencies {
+            check(
+                depends_on,
+                format!("dependsOn of {}", dependency.dependency_ref),
+            );
+        }
+    }
+
+    dangling
+}
+
+/// Adds the references of the components and their sub-components
+fn collect_component_refs(components: &[Component], known: &mut HashSet<String>) {
+    for component in components {
+        if let Some(bom_ref) = &component.bom_ref {
+            known.insert(bom_ref.to_string());
+        }
+        if let Some(sub_components) = &component.components {
+            collect_component_refs(&sub_components.0, known);
+        }
+    }
+}
+
+/// Adds the references of the services and their sub-services
+fn collect_service_refs(services: &[Service], known: &mut HashSet<String>) {
+    for service in services {
+        if let Some(bom_ref) = &service.bom_ref {
+            known.insert(bom_ref.to_string());
+        }
+        if let Some(sub_services) = &service.services {
+            collect_service_refs(&sub_services.0, known);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lib_utils::run_utils::parse_vex_json_bytes;
+
+    #[test]
+    fn test_dangling_refs() {
+        let bom = parse_vex_json_bytes(
+            br#"{
+                "bomFormat": "CycloneDX",
+                "specVersion": "1.5",
+                "version": 1,
+                "metadata": {"component": {"type": "application", "name": "app", "bom-ref": "app"}},
+                "components": [
+                    {
+                        "type": "library",
+                        "name": "lib",
+                        "bom-ref": "lib",
+                        "components": [{"type": "library", "name": "nested", "bom-ref": "nested"}]
+                    }
+                ],
+                "services": [{"name": "api", "bom-ref": "api"}],
+                "dependencies": [
+                    {"ref": "app", "dependsOn": ["lib", "api", "gone"]},
+                    {"ref": "nested"}
+                ],
+                "vulnerabilities": [
+                    {
+                        "id": "CVE-2025-0001",
+                        "affects": [
+                            {"ref": "nested"},
+                            {"ref": "missing"},
+                            {"ref": "urn:cdx:3e671687-395b-41f5-a30f-a58921a69b79/1#lib"}
+                        ]
+                    }
+                ]
+            }"#,
+        )
+        .expect("failed to parse test document");
+
+        assert_eq!(
+            dangling_refs(&bom),
+            vec![
+                DanglingRef {
+                    reference: "missing".to_string(),
+                    referenced_by: "vulnerability CVE-2025-0001".to_string(),
+                },
+                DanglingRef {
+                    reference: "gone".to_string(),
+                    referenced_by: "dependsOn of app".to_string(),
+                },
+            ]
+        );
+    }
+}
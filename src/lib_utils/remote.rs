@@ -0,0 +1,120 @@
+//! Fetching of CycloneDX documents over HTTP(S).
+//!
+//! Documents listed in `VEX2PDF_INPUT_URL` are downloaded into memory and parsed without
+//! touching the disk. The HTTP client is only compiled in with the `http` cargo feature;
+//! without it every fetch fails with an explanatory error.
+//!
+
+use std::error::Error;
+#[cfg(feature = "http")]
+use std::io::Read;
+use std::time::Duration;
+
+/// Maximum accepted size of a fetched document in bytes
+pub const MAX_DOCUMENT_BYTES: u64 = 50 * 1024 * 1024;
+
+/// Timeout for a whole request including reading the response body
+pub const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// File name used for reports of URLs without a usable path segment
+const FALLBACK_FILE_NAME: &str = "remote-bom";
+
+/// Returns true if the value is an HTTP or HTTPS URL
+///
+/// # Examples
+///
+/// ```
+/// use vex2pdf::lib_utils::remote::is_url;
+///
+/// assert!(is_url("https://artifacts.example.com/bom.json"));
+/// assert!(!is_url("bom.json"));
+/// ```
+pub fn is_url(value: &str) -> bool {
+    let value = value.to_ascii_lowercase();
+    value.starts_with("http://") || value.starts_with("https://")
+}
+
+/// Derives a local file name from the last path segment of a URL.
+///
+/// Query strings and fragments are ignored. The file name only determines where the
+/// generated report is written; the document is never saved to disk.
+pub fn file_name_from_url(url: &str) -> String {
+    let without_scheme = url.split_once("://").map_or(url, |(_, rest)| rest);
+    let path = without_scheme.split(['?', '#']).next().unwrap_or_default();
+
+    path.split('/')
+        .skip(1) // host
+        .filter(|segment| !segment.is_empty())
+        .last()
+        .unwrap_or(FALLBACK_FILE_NAME)
+        .to_string()
+}
+
+/// Returns true if the content looks like an XML document rather than JSON
+pub fn looks_like_xml(content: &[u8]) -> bool {
+    content
+        .iter()
+        .find(|byte| !byte.is_ascii_whitespace())
+        .is_some_and(|byte| *byte == b'<')
+}
+
+/// Downloads a document into memory.
+///
+/// Fails if the request does not complete within [`REQUEST_TIMEOUT`] or the response
+/// body exceeds [`MAX_DOCUMENT_BYTES`].
+#[cfg(feature = "http")]
+pub fn fetch_url(url: &str) -> Result<Vec<u8>, Box<dyn Error>> {
+    let agent = ureq::AgentBuilder::new().timeout(REQUEST_TIMEOUT).build();
+    let response = agent.get(url).call()?;
+
+    // read one byte more than allowed to detect oversized documents without reading them fully
+    let mut content = Vec::new();
+    response
+        .into_reader()
+        .take(MAX_DOCUMENT_BYTES + 1)
+        .read_to_end(&mut content)?;
+
+    if content.len() as u64 > MAX_DOCUMENT_BYTES {
+        return Err(
+            format!("document exceeds the size limit of {MAX_DOCUMENT_BYTES} bytes").into(),
+        );
+    }
+
+    Ok(content)
+}
+
+/// Downloads a document into memory.
+///
+/// This build does not include the `http` feature, so this always fails.
+#[cfg(not(feature = "http"))]
+pub fn fetch_url(_url: &str) -> Result<Vec<u8>, Box<dyn Error>> {
+    Err("fetching documents from URLs requires vex2pdf to be built with the `http` feature".into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_name_from_url() {
+        assert_eq!(
+            file_name_from_url("https://artifacts.example.com/builds/42/bom.json?token=abc"),
+            "bom.json"
+        );
+        assert_eq!(
+            file_name_from_url("https://artifacts.example.com/vex/"),
+            "vex"
+        );
+        assert_eq!(
+            file_name_from_url("https://artifacts.example.com"),
+            FALLBACK_FILE_NAME
+        );
+    }
+
+    #[test]
+    fn test_looks_like_xml() {
+        assert!(looks_like_xml(b"  \n<?xml version=\"1.0\"?><bom/>"));
+        assert!(!looks_like_xml(b"{\"bomFormat\": \"CycloneDX\"}"));
+        assert!(!looks_like_xml(b""));
+    }
+}
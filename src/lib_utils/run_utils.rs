@@ -1,38 +1,135 @@
+#[cfg(feature = "pdf")]
+use super::completeness::completeness;
+#[cfg(feature = "pdf")]
 use super::config::Config;
+#[cfg(feature = "pdf")]
+use super::console::output_path;
+use super::console::progress;
+#[cfg(feature = "pdf")]
+use super::env_vars::EnvVarNames;
+#[cfg(feature = "pdf")]
 use super::input_file_type::InputFileType;
+use super::io_retry::retry_io;
+#[cfg(feature = "pdf")]
+use super::ref_check::dangling_refs;
+#[cfg(feature = "pdf")]
+use super::remote;
+#[cfg(feature = "pdf")]
 use super::run_utils;
-use crate::pdf::generator::PdfGenerator;
+use super::severity::{rating_severity, severity_rank};
+use super::warnings::{record_note, record_warning};
+#[cfg(feature = "pdf")]
+use super::warnings::{warn, NoteCollection};
+#[cfg(feature = "pdf")]
+use crate::pdf::generator::{timestamp_from_epoch, PdfGenerator, ReportSource};
 use cyclonedx_bom::errors::{BomError, JsonReadError, XmlReadError};
+use cyclonedx_bom::models::vulnerability_analysis::ImpactAnalysisState;
+use cyclonedx_bom::models::vulnerability_rating::Severity;
+use cyclonedx_bom::models::vulnerability_source::VulnerabilitySource;
 use cyclonedx_bom::prelude::Bom;
+use serde_json::json;
+#[cfg(feature = "pdf")]
+use sha2::{Digest, Sha256};
+#[cfg(feature = "pdf")]
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::error::Error;
+use std::fmt;
 use std::fs;
 use std::path::{Path, PathBuf};
+#[cfg(feature = "pdf")]
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Tally of the outcome of processing a set of input files.
+///
+/// Returned by `parse_files()` and accumulated by `run()` to print a final summary line.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ProcessingSummary {
+    /// Number of input files that were picked up for processing
+    pub files_processed: usize,
+    /// Number of input files that were converted without errors
+    pub files_succeeded: usize,
+    /// Number of input files that could not be read or parsed, i.e. malformed input
+    pub parse_failures: usize,
+    /// Number of parsed documents whose PDF could not be rendered or written
+    pub render_failures: usize,
+    /// Number of PDF files written to disk
+    pub pdfs_generated: usize,
+    /// Number of documents skipped because a newer version shares their serial number,
+    /// see `VEX2PDF_KEEP_LATEST`
+    pub duplicates_skipped: usize,
+    /// Number of documents skipped because their PDF already exists, see
+    /// `VEX2PDF_OUTPUT_MODE`
+    pub skipped: usize,
+}
+
+impl ProcessingSummary {
+    /// Adds the counts of another summary to this one
+    pub fn merge(&mut self, other: ProcessingSummary) {
+        self.files_processed += other.files_processed;
+        self.files_succeeded += other.files_succeeded;
+        self.parse_failures += other.parse_failures;
+        self.render_failures += other.render_failures;
+        self.pdfs_generated += other.pdfs_generated;
+        self.duplicates_skipped += other.duplicates_skipped;
+        self.skipped += other.skipped;
+    }
+
+    /// Returns the number of input files that failed for any reason
+    pub fn files_failed(&self) -> usize {
+        self.parse_failures + self.render_failures
+    }
+}
+
+impl fmt::Display for ProcessingSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Processed {} files: {} succeeded, {} failed ({} parse errors, {} render errors), {} PDFs generated",
+            self.files_processed,
+            self.files_succeeded,
+            self.files_failed(),
+            self.parse_failures,
+            self.render_failures,
+            self.pdfs_generated
+        )?;
+        if self.duplicates_skipped > 0 {
+            write!(f, ", {} duplicates skipped", self.duplicates_skipped)?;
+        }
+        if self.skipped > 0 {
+            write!(f, ", {} skipped as already rendered", self.skipped)?;
+        }
+        Ok(())
+    }
+}
 
 /// Finds files of a given type in the configured working directory.
 ///
 /// Checks configuration to see if processing this file type is enabled,
-/// then scans the working directory for matching files.
+/// then scans the working directory for matching files. With `VEX2PDF_MODIFIED_SINCE`
+/// files modified before the configured time are left out.
 /// Returns None if processing is disabled for this file type.
+#[cfg(feature = "pdf")]
 pub(crate) fn find_files(
     config: &Config,
     file_type: InputFileType,
 ) -> Result<Option<Vec<PathBuf>>, Box<dyn Error>> {
     if let Some(init_process) = config.file_types_to_process.get(&file_type) {
         if !init_process {
-            println!(
+            progress(format!(
                 "Skipping {} files : deactivated by user",
                 file_type.as_str_uppercase()
-            );
+            ));
             return Ok(None);
         }
     }
-    println!(
+    progress(format!(
         "Scanning for {} files in: {}",
         file_type.as_str_uppercase(),
         config.working_dir.display()
-    );
+    ));
 
     let mut files: Vec<PathBuf> = Vec::new();
+    let mut unmodified = 0;
 
     for entry in fs::read_dir(&config.working_dir)? {
         let entry = entry?;
@@ -41,69 +138,609 @@ pub(crate) fn find_files(
         if path.is_file() {
             if let Some(extension) = path.extension() {
                 if extension.to_string_lossy().to_lowercase() == file_type.as_str_lowercase() {
-                    files.push(path);
+                    if is_modified_since(&path, config.modified_since) {
+                        files.push(path);
+                    } else {
+                        unmodified += 1;
+                    }
                 }
             }
         }
     }
 
+    if let Some(threshold) = config.modified_since.filter(|_| unmodified > 0) {
+        progress(format!(
+            "Skipping {unmodified} {} files modified before {}",
+            file_type.as_str_uppercase(),
+            system_time_text(threshold)
+        ));
+    }
+
     // inform over search results
     if files.is_empty() {
-        println!(
+        progress(format!(
             "No {} files found in the current directory.",
             file_type.as_str_uppercase()
-        );
+        ));
     } else {
-        println!(
+        progress(format!(
             "Found {} {} files",
             files.len(),
             file_type.as_str_uppercase()
-        );
+        ));
     }
 
     Ok(Some(files))
 }
 
+/// Returns whether a file was modified at or after `threshold`, always `true` without a
+/// threshold. Files whose modification time cannot be read are kept.
+#[cfg(feature = "pdf")]
+fn is_modified_since(path: &Path, threshold: Option<SystemTime>) -> bool {
+    match threshold {
+        Some(threshold) => !fs::metadata(path)
+            .and_then(|metadata| metadata.modified())
+            .is_ok_and(|modified| modified < threshold),
+        None => true,
+    }
+}
+
+/// Formats a time as an RFC 3339 UTC timestamp, see [`timestamp_from_epoch`]
+#[cfg(feature = "pdf")]
+fn system_time_text(time: SystemTime) -> String {
+    let seconds = match time.duration_since(UNIX_EPOCH) {
+        Ok(elapsed) => elapsed.as_secs() as i64,
+        Err(err) => -(err.duration().as_secs() as i64),
+    };
+
+    timestamp_from_epoch(seconds)
+}
+
+/// Handles inputs whose JSON and XML versions share a stem, e.g. `bom.json` and `bom.xml`,
+/// which would otherwise write the same `bom.pdf`.
+///
+/// With `VEX2PDF_PREFER_FORMAT` only the file of the preferred format is kept in the lists.
+/// Otherwise both are kept with a warning and the returned paths are the inputs whose
+/// outputs are named after their format, see [`format_suffixed_path`].
+#[cfg(feature = "pdf")]
+pub(crate) fn resolve_format_collisions(
+    config: &Config,
+    json_files: &mut Option<Vec<PathBuf>>,
+    xml_files: &mut Option<Vec<PathBuf>>,
+) -> HashSet<PathBuf> {
+    let mut suffixed = HashSet::new();
+    if let (Some(json), Some(xml)) = (json_files.as_mut(), xml_files.as_mut()) {
+        for (json_path, xml_path) in format_collisions(json, xml) {
+            match config.prefer_format {
+                Some(preferred) => {
+                    let (kept, dropped, files) = match preferred {
+                        InputFileType::JSON => (&json_path, &xml_path, &mut *xml),
+                        InputFileType::XML => (&xml_path, &json_path, &mut *json),
+                    };
+                    progress(format!(
+                        "Skipping {}: {} is preferred",
+                        dropped.display(),
+                        kept.display()
+                    ));
+                    files.retain(|path| path != dropped);
+                }
+                None => {
+                    warn(format!(
+                        "{} and {} share a name, naming their reports {} and {}. set {} to process only one of them",
+                        json_path.display(),
+                        xml_path.display(),
+                        get_output_pdf_path(&format_suffixed_path(&json_path))
+                            .display(),
+                        get_output_pdf_path(&format_suffixed_path(&xml_path))
+                            .display(),
+                        EnvVarNames::PreferFormat.as_str()
+                    ));
+                    suffixed.insert(json_path);
+                    suffixed.insert(xml_path);
+                }
+            }
+        }
+    }
+
+    suffixed
+}
+
+/// Returns the pairs of JSON and XML files that only differ in their extension
+#[cfg(feature = "pdf")]
+fn format_collisions(json_files: &[PathBuf], xml_files: &[PathBuf]) -> Vec<(PathBuf, PathBuf)> {
+    let xml_by_stem: HashMap<PathBuf, &PathBuf> = xml_files
+        .iter()
+        .map(|path| (path.with_extension(""), path))
+        .collect();
+
+    json_files
+        .iter()
+        .filter_map(|path| {
+            xml_by_stem
+                .get(&path.with_extension(""))
+                .map(|&xml_path| (path.clone(), xml_path.clone()))
+        })
+        .collect()
+}
+
+/// Appends the extension of an input path to its stem, e.g. `bom.xml` turns into
+/// `bom-xml.xml`. Outputs are named after the suffixed path, so that the JSON and XML
+/// versions of a document get separate reports.
+#[cfg(feature = "pdf")]
+fn format_suffixed_path(file_path: &Path) -> PathBuf {
+    match file_path.extension() {
+        Some(extension) => {
+            let extension = extension.to_string_lossy();
+            let stem = file_path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            file_path.with_file_name(format!("{stem}-{}.{extension}", extension.to_lowercase()))
+        }
+        None => file_path.to_path_buf(),
+    }
+}
+
 /// Processes a list of files found by find_files() and generates PDFs.
 ///
 /// Iterates through each file in the provided list, attempts to parse it
 /// according to the specified input file type, and generates a PDF if successful.
 /// Does nothing if the files parameter is None.
+///
+/// JSON files holding an array of documents produce one report per document, named
+/// after the file and numbered from 1, e.g. `bundle-2.pdf`.
+///
+/// Files sharing a serial number are reported as a warning. With `VEX2PDF_KEEP_LATEST`
+/// only the one with the highest `version` is rendered.
+///
+/// The outputs of the files in `format_suffixed` are named after their format, see
+/// [`resolve_format_collisions`].
+///
+/// Returns a `ProcessingSummary` with the outcome counts of the processed files.
+#[cfg(feature = "pdf")]
 pub(crate) fn parse_files(
+    config: &Config,
     pdf_generator: &PdfGenerator,
     files: &Option<Vec<PathBuf>>,
     input_file_type: InputFileType,
-) {
+    format_suffixed: &HashSet<PathBuf>,
+) -> ProcessingSummary {
+    let mut summary = ProcessingSummary::default();
+
     if let Some(files) = &files {
-        // Process each JSON file
+        // Parse all files first, duplicate serial numbers can only be detected across the set
+        let mut parsed = Vec::with_capacity(files.len());
         for file_path in files {
-            println!("Processing: {}", file_path.display());
+            progress(format!("Processing: {}", file_path.display()));
+            summary.files_processed += 1;
 
-            // Try to parse the JSON file as a CycloneDX Bom
-            let parse_res = if input_file_type == InputFileType::JSON {
-                run_utils::parse_vex_json(file_path)
+            // Try to parse the file as CycloneDX, JSON files may hold an array of documents
+            let note_collection = NoteCollection::start();
+            let content = retry_io(config.io_retries, || fs::read(file_path));
+            // The hash covers the file as read, every document of an array shares it
+            let source_hash = content
+                .as_ref()
+                .ok()
+                .filter(|_| config.show_source_hash)
+                .map(|content| sha256_hex(content));
+            let parse_res = content.map_err(Box::<dyn Error>::from).and_then(|content| {
+                if input_file_type == InputFileType::JSON {
+                    parse_vex_json_documents(&content)
+                } else {
+                    parse_vex_xml_bytes(&content).map(|vex| vec![vex])
+                }
+            });
+            // Parsing warnings such as the 1.6 downgrade concern every document of the file
+            let notes = note_collection.finish();
+            // Outputs are named after this path
+            let output_name = if format_suffixed.contains(file_path) {
+                format_suffixed_path(file_path)
             } else {
-                run_utils::parse_vex_xml(file_path)
+                file_path.clone()
             };
 
             match parse_res {
-                Ok(vex) => {
-                    // Generate output PDF path with same base name
-                    let output_path = run_utils::get_output_pdf_path(file_path);
+                Ok(mut boms) if boms.len() == 1 => {
+                    let vex = boms.remove(0);
+                    parsed.push((
+                        output_name,
+                        file_source_label(config, file_path),
+                        vex,
+                        notes,
+                        source_hash,
+                    ));
+                }
+                Ok(boms) => {
+                    // Each document of an array gets its own numbered outputs
+                    let count = boms.len();
+                    progress(format!(
+                        "Found {count} documents in {}",
+                        file_path.display()
+                    ));
+                    let source = file_source_label(config, file_path);
+                    for (index, vex) in boms.into_iter().enumerate() {
+                        let source = source
+                            .as_ref()
+                            .map(|source| format!("{source} (document {} of {count})", index + 1));
+                        parsed.push((
+                            numbered_path(&output_name, index + 1),
+                            source,
+                            vex,
+                            notes.clone(),
+                            source_hash.clone(),
+                        ));
+                    }
+                }
+                Err(e) => {
+                    progress(format!("Failed to parse {}: {}", file_path.display(), e));
+                    summary.parse_failures += 1;
+                }
+            }
+        }
 
-                    println!("Generating PDF: {}", output_path.display());
+        let mut superseded = vec![false; parsed.len()];
+        for (serial, indices) in duplicate_serials(parsed.iter().map(|(_, _, vex, _, _)| vex)) {
+            let names: Vec<String> = indices
+                .iter()
+                .map(|&index| parsed[index].0.display().to_string())
+                .collect();
+            let message = format!("serial number {serial} is shared by {}", names.join(", "));
+            warn(&message);
+            for &index in &indices {
+                parsed[index].3.push(message.clone());
+            }
 
-                    // Generate the PDF
-                    match pdf_generator.generate_pdf(&vex, &output_path) {
-                        Ok(_) => println!("Successfully generated PDF: {}", output_path.display()),
-                        Err(e) => {
-                            println!("Failed to generate PDF for {}: {}", file_path.display(), e)
-                        }
-                    }
+            if config.keep_latest {
+                let latest = latest_version(&indices, |index| parsed[index].2.version);
+                for &index in indices.iter().filter(|&&index| index != latest) {
+                    progress(format!(
+                        "Skipping {}: superseded by {}",
+                        parsed[index].0.display(),
+                        parsed[latest].0.display()
+                    ));
+                    superseded[index] = true;
+                    summary.duplicates_skipped += 1;
                 }
-                Err(e) => println!("Failed to parse {}: {}", file_path.display(), e),
             }
         }
+
+        for ((file_path, source, vex, notes, source_hash), superseded) in
+            parsed.into_iter().zip(superseded)
+        {
+            if superseded {
+                continue;
+            }
+
+            convert_bom(
+                config,
+                pdf_generator,
+                &vex,
+                &file_path,
+                &ReportSource {
+                    name: source.as_deref(),
+                    sha256: source_hash.as_deref(),
+                },
+                notes,
+                &mut summary,
+            );
+        }
+    }
+
+    summary
+}
+
+/// Returns the indices of the documents sharing a serial number, keyed by serial number.
+///
+/// Serial numbers used by a single document and documents without serial number are left
+/// out.
+#[cfg(feature = "pdf")]
+fn duplicate_serials<'a>(boms: impl Iterator<Item = &'a Bom>) -> BTreeMap<String, Vec<usize>> {
+    let mut serials: BTreeMap<String, Vec<usize>> = BTreeMap::new();
+    for (index, bom) in boms.enumerate() {
+        if let Some(serial) = &bom.serial_number {
+            serials.entry(serial.to_string()).or_default().push(index);
+        }
+    }
+
+    serials.retain(|_, indices| indices.len() > 1);
+    serials
+}
+
+/// Returns the index with the highest version, the first one if several share it
+#[cfg(feature = "pdf")]
+fn latest_version(indices: &[usize], version: impl Fn(usize) -> u32) -> usize {
+    let mut latest = indices[0];
+    for &index in &indices[1..] {
+        if version(index) > version(latest) {
+            latest = index;
+        }
+    }
+    latest
+}
+
+/// Fetches the documents listed in `VEX2PDF_INPUT_URL` and generates PDFs.
+///
+/// Documents are parsed in memory as XML or JSON depending on their content. Reports are
+/// written to the working directory and named after the last path segment of the URL.
+///
+/// Returns a `ProcessingSummary` with the outcome counts of the fetched documents.
+#[cfg(feature = "pdf")]
+pub(crate) fn parse_urls(config: &Config, pdf_generator: &PdfGenerator) -> ProcessingSummary {
+    let mut summary = ProcessingSummary::default();
+
+    for url in &config.input_urls {
+        progress(format!("Fetching: {url}"));
+        summary.files_processed += 1;
+
+        let note_collection = NoteCollection::start();
+        let content = remote::fetch_url(url);
+        let source_hash = content
+            .as_ref()
+            .ok()
+            .filter(|_| config.show_source_hash)
+            .map(|content| sha256_hex(content));
+        let parse_res = content.and_then(|content| {
+            if remote::looks_like_xml(&content) {
+                parse_vex_xml_bytes(&content)
+            } else {
+                parse_vex_json_bytes(&content)
+            }
+        });
+        let notes = note_collection.finish();
+
+        match parse_res {
+            Ok(vex) => {
+                let file_path = config.working_dir.join(remote::file_name_from_url(url));
+                let source = url_source_label(config, url);
+                convert_bom(
+                    config,
+                    pdf_generator,
+                    &vex,
+                    &file_path,
+                    &ReportSource {
+                        name: source.as_deref(),
+                        sha256: source_hash.as_deref(),
+                    },
+                    notes,
+                    &mut summary,
+                );
+            }
+            Err(e) => {
+                progress(format!("Failed to fetch or parse {url}: {e}"));
+                summary.parse_failures += 1;
+            }
+        }
+    }
+
+    summary
+}
+
+/// Writes the optional vulnerability JSON and the PDF report for a parsed document.
+///
+/// `file_path` is the input the document was read from and determines the output paths,
+/// see [`output_base_path`].
+/// `source` names the input in the report. `notes` are the warnings noted while
+/// parsing the document, the warnings of the conversion are noted after them. The outcome
+/// is recorded in `summary`.
+/// Dangling `bom-ref` references are reported first if `VEX2PDF_CHECK_REFS` is enabled.
+#[cfg(feature = "pdf")]
+fn convert_bom(
+    config: &Config,
+    pdf_generator: &PdfGenerator,
+    vex: &Bom,
+    file_path: &Path,
+    source: &ReportSource<'_>,
+    notes: Vec<String>,
+    summary: &mut ProcessingSummary,
+) {
+    // Warnings are rendered into the report with VEX2PDF_SHOW_NOTES
+    let _note_collection = NoteCollection::start_with(notes);
+    // Outputs are named after the input, in the output directory if there is one
+    let output_base = output_base_path(config, file_path);
+
+    if config.check_refs {
+        for dangling in dangling_refs(vex) {
+            warn(format!(
+                "{}: {} references unknown bom-ref {}",
+                file_path.display(),
+                dangling.referenced_by,
+                dangling.reference
+            ));
+        }
+    }
+
+    if config.completeness_report {
+        progress(format!(
+            "Data completeness of {}: {}",
+            file_path.display(),
+            completeness(vex)
+        ));
+    }
+
+    // Generate output PDF path with same base name, an existing report skips all outputs
+    let pdf_path = run_utils::get_output_pdf_path_with_mode(&output_base, config.output_mode);
+    if config.output_mode == run_utils::OutputMode::Skip && pdf_path.exists() {
+        progress(format!(
+            "Skipping {}: {} already exists",
+            file_path.display(),
+            pdf_path.display()
+        ));
+        summary.skipped += 1;
+        return;
+    }
+
+    if config.dump_normalized {
+        match emit_normalized_json(vex, &output_base) {
+            Ok(json_path) => {
+                progress(format!(
+                    "Wrote normalized document: {}",
+                    json_path.display()
+                ));
+                output_path(&json_path);
+            }
+            Err(e) => warn(format!(
+                "failed to write normalized document for {}: {}",
+                file_path.display(),
+                e
+            )),
+        }
+    }
+
+    if config.emit_vuln_json {
+        match emit_vuln_json(vex, &output_base) {
+            Ok(json_path) => {
+                progress(format!("Wrote vulnerability JSON: {}", json_path.display()));
+                output_path(&json_path);
+            }
+            Err(e) => warn(format!(
+                "failed to write vulnerability JSON for {}: {}",
+                file_path.display(),
+                e
+            )),
+        }
+    }
+
+    if config.emit_sarif {
+        match emit_sarif(vex, &output_base) {
+            Ok(sarif_path) => {
+                progress(format!("Wrote SARIF findings: {}", sarif_path.display()));
+                output_path(&sarif_path);
+            }
+            Err(e) => warn(format!(
+                "failed to write SARIF findings for {}: {}",
+                file_path.display(),
+                e
+            )),
+        }
+    }
+
+    if config.emit_txt {
+        match emit_text(pdf_generator, vex, &output_base, source) {
+            Ok(text_path) => {
+                progress(format!("Wrote text report: {}", text_path.display()));
+                output_path(&text_path);
+            }
+            Err(e) => warn(format!(
+                "failed to write text report for {}: {}",
+                file_path.display(),
+                e
+            )),
+        }
+    }
+
+    progress(format!("Generating PDF: {}", pdf_path.display()));
+
+    // Generate the PDF
+    match pdf_generator.generate_pdf_with_source(vex, &pdf_path, source) {
+        Ok(_) => {
+            progress(format!(
+                "Successfully generated PDF: {}",
+                pdf_path.display()
+            ));
+            output_path(&pdf_path);
+            summary.files_succeeded += 1;
+            summary.pdfs_generated += 1;
+        }
+        Err(e) => {
+            progress(format!(
+                "Failed to render PDF for {}: {}",
+                file_path.display(),
+                e
+            ));
+            summary.render_failures += 1;
+        }
+    }
+}
+
+/// Returns the path the outputs of an input are named after: the input path itself, or
+/// the file name of the input in the output directory if one is configured
+#[cfg(feature = "pdf")]
+fn output_base_path(config: &Config, file_path: &Path) -> PathBuf {
+    match (&config.output_dir, file_path.file_name()) {
+        (Some(output_dir), Some(file_name)) => output_dir.join(file_name),
+        _ => file_path.to_path_buf(),
+    }
+}
+
+/// Names an input file for the report: its absolute path if `show_source_path` is set,
+/// its file name if `show_source_file` is set, otherwise `None`
+#[cfg(feature = "pdf")]
+fn file_source_label(config: &Config, file_path: &Path) -> Option<String> {
+    if config.show_source_path {
+        let absolute = fs::canonicalize(file_path).unwrap_or_else(|_| file_path.to_path_buf());
+        Some(absolute.display().to_string())
+    } else if config.show_source_file {
+        file_path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+    } else {
+        None
+    }
+}
+
+/// Returns the hex encoded SHA-256 of an input, see `VEX2PDF_SHOW_SOURCE_HASH`
+#[cfg(feature = "pdf")]
+fn sha256_hex(content: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(content))
+}
+
+/// Names a fetched URL for the report like [`file_source_label`]. The query string and
+/// fragment are left out since they may carry access tokens.
+#[cfg(feature = "pdf")]
+fn url_source_label(config: &Config, url: &str) -> Option<String> {
+    if config.show_source_path {
+        url.split(['?', '#']).next().map(str::to_string)
+    } else if config.show_source_file {
+        Some(remote::file_name_from_url(url))
+    } else {
+        None
+    }
+}
+
+/// CycloneDX spec versions parsed as they are
+pub const NATIVE_SPEC_VERSIONS: [&str; 3] = ["1.3", "1.4", "1.5"];
+
+/// CycloneDX spec versions parsed in compatibility mode as the newest native version,
+/// see [`SpecSupport::Downgradable`]
+pub const DOWNGRADABLE_SPEC_VERSIONS: [&str; 1] = ["1.6"];
+
+/// Newest spec version parsed as it is, downgradable documents are parsed as this version
+const NEWEST_NATIVE_SPEC_VERSION: &str = "1.5";
+
+/// How documents of a CycloneDX spec version are parsed, see [`spec_version_support`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpecSupport {
+    /// Documents are parsed as they are. XML documents are only parsed with the 1.5
+    /// schema, older XML documents are not supported.
+    Native,
+    /// Documents are parsed as the newest native version, which only works if they do not
+    /// use fields added in their version
+    Downgradable,
+    /// Documents cannot be parsed
+    Unsupported,
+}
+
+/// Returns how documents of a CycloneDX spec version such as `"1.5"` are parsed.
+///
+/// Allows library users to check documents before attempting a conversion.
+///
+/// # Examples
+///
+/// ```
+/// use vex2pdf::lib_utils::run_utils::{spec_version_support, SpecSupport};
+///
+/// assert_eq!(spec_version_support("1.5"), SpecSupport::Native);
+/// assert_eq!(spec_version_support("1.6"), SpecSupport::Downgradable);
+/// assert_eq!(spec_version_support("2.0"), SpecSupport::Unsupported);
+/// ```
+pub fn spec_version_support(version: &str) -> SpecSupport {
+    let version = version.trim();
+    if NATIVE_SPEC_VERSIONS.contains(&version) {
+        SpecSupport::Native
+    } else if DOWNGRADABLE_SPEC_VERSIONS.contains(&version) {
+        SpecSupport::Downgradable
+    } else {
+        SpecSupport::Unsupported
     }
 }
 
@@ -115,25 +752,53 @@ pub(crate) fn parse_files(
 ///
 /// Note: The downgrade from 1.6 to 1.5 is a compatibility feature and may not work
 /// if the document uses 1.6-specific fields.
-pub(crate) fn parse_vex_xml(path: &Path) -> Result<Bom, Box<dyn Error>> {
+///
+/// The file is read once, use [`parse_vex_xml_with_retries`] to retry failed reads.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::Path;
+/// use vex2pdf::lib_utils::run_utils::parse_vex_xml;
+///
+/// let bom = parse_vex_xml(Path::new("vex.xml")).expect("failed to parse VEX document");
+/// println!("Spec version: {}", bom.spec_version);
+/// ```
+pub fn parse_vex_xml(path: &Path) -> Result<Bom, Box<dyn Error>> {
+    parse_vex_xml_with_retries(path, 0)
+}
+
+/// Parses an XML file like [`parse_vex_xml`], retrying the read up to `retries` times
+/// when it fails with an error that may be transient, e.g. on network file systems. See
+/// [`retry_io`] and `VEX2PDF_IO_RETRIES`.
+pub fn parse_vex_xml_with_retries(path: &Path, retries: u32) -> Result<Bom, Box<dyn Error>> {
     // First, read the entire file content
-    let content = fs::read(path)?;
+    let content = retry_io(retries, || fs::read(path))?;
 
+    parse_vex_xml_bytes(&content)
+}
+
+/// Parses in-memory XML content into a CycloneDX Bom object.
+///
+/// Behaves like [`parse_vex_xml`], including the 1.6 compatibility handling.
+pub fn parse_vex_xml_bytes(content: &[u8]) -> Result<Bom, Box<dyn Error>> {
     // try to parse xml bom
-    match Bom::parse_from_xml_v1_5(&content[..]) {
+    match Bom::parse_from_xml_v1_5(content) {
         Ok(bom) => Ok(bom),
         Err(err) => match &err {
             XmlReadError::InvalidNamespaceError {
                 expected_namespace,
                 actual_namespace,
             } => {
-                // check if we are dealing with a cyclonedx version > 1.5
+                // check if we are dealing with a downgradable cyclonedx version, the
+                // namespace ends with the version
                 if let Some(actual) = actual_namespace {
-                    if actual.contains("1.6") {
+                    let version = actual.rsplit('/').next().unwrap_or_default();
+                    if spec_version_support(version) == SpecSupport::Downgradable {
                         print_downgrade_warning();
 
                         // convert content to string to replace namespace
-                        let xml_str = std::string::String::from_utf8_lossy(&content);
+                        let xml_str = std::string::String::from_utf8_lossy(content);
 
                         // replace the namespace
                         let modified_xml = xml_str.replace(actual, expected_namespace);
@@ -162,31 +827,88 @@ pub(crate) fn parse_vex_xml(path: &Path) -> Result<Bom, Box<dyn Error>> {
 ///
 /// Note: The downgrade from 1.6 to 1.5 is a compatibility feature and may not work
 /// if the document uses 1.6-specific fields.
-pub(crate) fn parse_vex_json(path: &Path) -> Result<Bom, Box<dyn Error>> {
+///
+/// The file is read once, use [`parse_vex_json_with_retries`] to retry failed reads.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::Path;
+/// use vex2pdf::lib_utils::run_utils::parse_vex_json;
+///
+/// let bom = parse_vex_json(Path::new("vex.json")).expect("failed to parse VEX document");
+/// println!("Spec version: {}", bom.spec_version);
+/// ```
+pub fn parse_vex_json(path: &Path) -> Result<Bom, Box<dyn Error>> {
+    parse_vex_json_with_retries(path, 0)
+}
+
+/// Parses a JSON file like [`parse_vex_json`], retrying the read up to `retries` times
+/// when it fails with an error that may be transient, e.g. on network file systems. See
+/// [`retry_io`] and `VEX2PDF_IO_RETRIES`.
+pub fn parse_vex_json_with_retries(path: &Path, retries: u32) -> Result<Bom, Box<dyn Error>> {
     // First, read the entire file content
-    let content = fs::read(path)?;
+    let content = retry_io(retries, || fs::read(path))?;
+
+    parse_vex_json_bytes(&content)
+}
+
+/// Parses in-memory JSON content into a CycloneDX Bom object.
+///
+/// Behaves like [`parse_vex_json`], including the 1.6 compatibility handling. Documents
+/// whose `bomFormat` is not `CycloneDX`, e.g. SPDX documents, fail with an error saying so
+/// instead of the error of the CycloneDX parser.
+pub fn parse_vex_json_bytes(content: &[u8]) -> Result<Bom, Box<dyn Error>> {
     // Try to parse normally first
-    match Bom::parse_from_json(&content[..]) {
+    match Bom::parse_from_json(content) {
         Ok(bom) => Ok(bom),
-        Err(err) => match err {
-            JsonReadError::BomError { error } => {
-                match error {
-                    BomError::UnsupportedSpecVersion(version) if version == "1.6" => {
-                        // Parse to JSON Value
-                        let mut json_value: serde_json::Value = serde_json::from_slice(&content)?;
+        Err(err) => {
+            // The format is only checked once parsing failed, valid documents are read once
+            if let Some(message) = bom_format_error(content) {
+                return Err(message.into());
+            }
+            match err {
+                JsonReadError::BomError { error } => {
+                    match error {
+                        BomError::UnsupportedSpecVersion(version)
+                            if spec_version_support(&version) == SpecSupport::Downgradable =>
+                        {
+                            // Parse to JSON Value
+                            let mut json_value: serde_json::Value =
+                                serde_json::from_slice(content)?;
 
-                        print_downgrade_warning();
+                            print_downgrade_warning();
 
-                        json_value["specVersion"] = serde_json::Value::String("1.5".to_string());
+                            json_value["specVersion"] =
+                                serde_json::Value::String(NEWEST_NATIVE_SPEC_VERSION.to_string());
 
-                        // Try parsing with modified JSON
-                        Ok(Bom::parse_json_value(json_value)?)
+                            // Try parsing with modified JSON
+                            Ok(Bom::parse_json_value(json_value)?)
+                        }
+                        _ => Err(JsonReadError::BomError { error }.into()),
                     }
-                    _ => Err(JsonReadError::BomError { error }.into()),
                 }
+                _ => Err(err.into()),
             }
-            _ => Err(err.into()),
-        },
+        }
+    }
+}
+
+/// Returns an error message for JSON content whose `bomFormat` is not `CycloneDX`, `None`
+/// if it is or the content is not a JSON object
+fn bom_format_error(content: &[u8]) -> Option<String> {
+    let json: serde_json::Value = serde_json::from_slice(content).ok()?;
+    let document = json.as_object()?;
+
+    match document.get("bomFormat") {
+        Some(serde_json::Value::String(format)) if format == "CycloneDX" => None,
+        Some(format) => Some(format!(
+            "not a CycloneDX document: bomFormat is {format}, expected \"CycloneDX\""
+        )),
+        None if document.contains_key("spdxVersion") => {
+            Some("not a CycloneDX document: found an SPDX document".to_string())
+        }
+        None => Some("not a CycloneDX document: bomFormat is missing".to_string()),
     }
 }
 
@@ -195,12 +917,92 @@ pub(crate) fn parse_vex_json(path: &Path) -> Result<Bom, Box<dyn Error>> {
 /// Called when the parser encounters a 1.6 document and attempts to process it
 /// by downgrading to version 1.5.
 fn print_downgrade_warning() {
-    println!();
-    println!("NOTE: Downgrading CycloneDX BOM from spec version 1.6 to 1.5");
-    println!("Reason: Current implementation does not yet fully support spec version 1.6");
-    println!("Warning: This compatibility mode only works for BOMs that don't utilize 1.6-specific fields");
-    println!("         Processing will fail if 1.6-specific fields are encountered");
-    println!();
+    record_warning();
+    record_note(
+        "downgraded from CycloneDX spec version 1.6 to 1.5, fields specific to 1.6 may be missing",
+    );
+    progress("");
+    progress("NOTE: Downgrading CycloneDX BOM from spec version 1.6 to 1.5");
+    progress("Reason: Current implementation does not yet fully support spec version 1.6");
+    progress("Warning: This compatibility mode only works for BOMs that don't utilize 1.6-specific fields");
+    progress("         Processing will fail if 1.6-specific fields are encountered");
+    progress("");
+}
+
+/// Parses in-memory JSON content holding a single CycloneDX document or a top-level array
+/// of documents.
+///
+/// Each document is parsed like [`parse_vex_json_bytes`], including the 1.6 compatibility
+/// handling. Content holding a single document yields one `Bom`. Arrays must contain at
+/// least one document, and fail to parse as a whole if any of their documents does.
+///
+/// # Examples
+///
+/// ```
+/// use vex2pdf::lib_utils::run_utils::parse_vex_json_documents;
+///
+/// let boms = parse_vex_json_documents(br#"[
+///     {"bomFormat": "CycloneDX", "specVersion": "1.5", "version": 1},
+///     {"bomFormat": "CycloneDX", "specVersion": "1.5", "version": 2}
+/// ]"#).unwrap();
+/// assert_eq!(boms.len(), 2);
+/// ```
+pub fn parse_vex_json_documents(content: &[u8]) -> Result<Vec<Bom>, Box<dyn Error>> {
+    let is_array = content
+        .iter()
+        .find(|byte| !byte.is_ascii_whitespace())
+        .is_some_and(|byte| *byte == b'[');
+    if !is_array {
+        return Ok(vec![parse_vex_json_bytes(content)?]);
+    }
+
+    let documents: Vec<serde_json::Value> = serde_json::from_slice(content)?;
+    if documents.is_empty() {
+        return Err("the JSON array contains no document".into());
+    }
+
+    documents
+        .iter()
+        .enumerate()
+        .map(|(index, document)| -> Result<Bom, Box<dyn Error>> {
+            let content = serde_json::to_vec(document)?;
+            parse_vex_json_bytes(&content)
+                .map_err(|e| format!("document {} of the array: {e}", index + 1).into())
+        })
+        .collect()
+}
+
+/// What happens when the PDF of an input already exists, see `VEX2PDF_OUTPUT_MODE`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputMode {
+    /// The existing PDF is replaced
+    #[default]
+    Overwrite,
+    /// The input is not rendered again and the existing PDF is kept
+    Skip,
+    /// The PDF is written to the next free name, `report.v1.pdf`, `report.v2.pdf` and so on
+    Version,
+}
+
+impl OutputMode {
+    /// Parses a mode name as used in `VEX2PDF_OUTPUT_MODE`, ignoring case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vex2pdf::lib_utils::run_utils::OutputMode;
+    ///
+    /// assert_eq!(OutputMode::from_name("Version"), Some(OutputMode::Version));
+    /// assert_eq!(OutputMode::from_name("append"), None);
+    /// ```
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "overwrite" => Some(Self::Overwrite),
+            "skip" => Some(Self::Skip),
+            "version" => Some(Self::Version),
+            _ => None,
+        }
+    }
 }
 
 /// Constructs an output PDF path based on the input file path.
@@ -208,19 +1010,524 @@ fn print_downgrade_warning() {
 /// Creates a new path with the same base name as the input file but with a .pdf extension.
 /// Used internally to determine where to save generated PDF files.
 pub fn get_output_pdf_path(file_path: &Path) -> PathBuf {
+    get_output_path_with_extension(file_path, "pdf")
+}
+
+/// Constructs an output PDF path like [`get_output_pdf_path`] for the given output mode.
+///
+/// With [`OutputMode::Version`] an existing PDF is kept and the first free name of the form
+/// `<name>.v<n>.pdf` is returned instead. Other modes return the path unchanged.
+pub fn get_output_pdf_path_with_mode(file_path: &Path, mode: OutputMode) -> PathBuf {
+    let pdf_path = get_output_pdf_path(file_path);
+    if mode != OutputMode::Version || !pdf_path.exists() {
+        return pdf_path;
+    }
+
+    (1..)
+        .map(|version| versioned_path(&pdf_path, version))
+        .find(|candidate| !candidate.exists())
+        .expect("ran out of version numbers")
+}
+
+/// Constructs an output path next to the input file with the given extension.
+///
+/// The extension may contain dots, e.g. `vulns.json` turns `bom.json` into `bom.vulns.json`.
+pub fn get_output_path_with_extension(file_path: &Path, extension: &str) -> PathBuf {
     if let Some(file_stem) = file_path.file_stem() {
-        file_path.with_file_name(format!("{}.pdf", file_stem.to_string_lossy()))
+        file_path.with_file_name(format!("{}.{}", file_stem.to_string_lossy(), extension))
     } else {
-        file_path.with_extension("pdf")
+        file_path.with_extension(extension)
+    }
+}
+
+/// Numbers an input path for one of several documents read from it, e.g. `bom.json`
+/// turns into `bom-2.json` for the second document. Outputs are named after the numbered
+/// path.
+fn numbered_path(file_path: &Path, number: usize) -> PathBuf {
+    suffixed_path(file_path, &format!("-{number}"))
+}
+
+/// Numbers a version of a PDF, e.g. `bom.pdf` turns into `bom.v2.pdf` for the second
+/// version, see [`OutputMode::Version`]. Versions use a different suffix than
+/// [`numbered_path`] so that the versions of one document never take the name of
+/// another document of the same input.
+fn versioned_path(file_path: &Path, version: usize) -> PathBuf {
+    suffixed_path(file_path, &format!(".v{version}"))
+}
+
+/// Appends `suffix` to the file stem of a path, keeping its extension
+fn suffixed_path(file_path: &Path, suffix: &str) -> PathBuf {
+    let stem = file_path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    match file_path.extension() {
+        Some(extension) => {
+            file_path.with_file_name(format!("{stem}{suffix}.{}", extension.to_string_lossy()))
+        }
+        None => file_path.with_file_name(format!("{stem}{suffix}")),
     }
 }
 
+/// Writes the document as parsed, serialized as CycloneDX 1.5 JSON, as
+/// `<stem>.normalized.json` next to the input file.
+///
+/// Diffing the input against this file shows which fields were dropped while parsing,
+/// e.g. by the downgrade of 1.6 documents. Returns the path of the written file.
+pub fn emit_normalized_json(vex: &Bom, file_path: &Path) -> Result<PathBuf, Box<dyn Error>> {
+    let json_path = get_output_path_with_extension(file_path, "normalized.json");
+    let mut content = Vec::<u8>::new();
+    vex.clone().output_as_json_v1_5(&mut content)?;
+    fs::write(&json_path, content)?;
+
+    Ok(json_path)
+}
+
+/// Builds a normalized per-vulnerability JSON summary of a CycloneDX document.
+///
+/// Each vulnerability is exported with its ID, source, analysis state and justification
+/// and the full list of its ratings (severity, score, method and source), preserving
+/// the nesting of multiple ratings per vulnerability.
+pub fn vuln_summary_json(vex: &Bom) -> serde_json::Value {
+    let mut vulnerabilities: Vec<serde_json::Value> = Vec::new();
+
+    for vuln in vex.vulnerabilities.iter().flat_map(|vulns| vulns.0.iter()) {
+        let mut ratings: Vec<serde_json::Value> = Vec::new();
+        for rating in vuln.vulnerability_ratings.iter().flat_map(|r| r.0.iter()) {
+            // CVSS scores carry one decimal, avoid f32 artifacts in the output
+            let score = rating
+                .score
+                .as_ref()
+                .map(|score| (f64::from(score.to_f32()) * 10.0).round() / 10.0);
+
+            ratings.push(json!({
+                "severity": rating.severity.as_ref().map(|severity| severity.to_string()),
+                "score": score,
+                "method": rating.score_method.as_ref().map(|method| method.to_string()),
+                "source": source_name(rating.vulnerability_source.as_ref()),
+            }));
+        }
+
+        let analysis = vuln.vulnerability_analysis.as_ref();
+        vulnerabilities.push(json!({
+            "id": vuln.id.as_ref().map(|id| id.to_string()),
+            "source": source_name(vuln.vulnerability_source.as_ref()),
+            "state": analysis.and_then(|a| a.state.as_ref()).map(|s| s.to_string()),
+            "justification": analysis.and_then(|a| a.justification.as_ref()).map(|j| j.to_string()),
+            "ratings": ratings,
+        }));
+    }
+
+    json!({
+        "serialNumber": vex.serial_number.as_ref().map(|serial| serial.to_string()),
+        "vulnerabilities": vulnerabilities,
+    })
+}
+
+/// Returns the name of a vulnerability source, if any
+fn source_name(source: Option<&VulnerabilitySource>) -> Option<String> {
+    source
+        .and_then(|source| source.name.as_ref())
+        .map(|name| name.to_string())
+}
+
+/// Writes the vulnerability summary of `vex` as `<stem>.vulns.json` next to the input file.
+///
+/// Returns the path of the written file.
+pub fn emit_vuln_json(vex: &Bom, file_path: &Path) -> Result<PathBuf, Box<dyn Error>> {
+    let json_path = get_output_path_with_extension(file_path, "vulns.json");
+    let content = serde_json::to_string_pretty(&vuln_summary_json(vex))?;
+    fs::write(&json_path, content)?;
+
+    Ok(json_path)
+}
+
+/// Builds a minimal SARIF 2.1.0 log of the vulnerabilities of a CycloneDX document.
+///
+/// Every vulnerability becomes a result whose rule is the vulnerability ID and whose
+/// message is the description. The level is derived from the highest severity of the
+/// ratings: critical and high map to `error`, medium to `warning`, low and info to `note`.
+/// Vulnerabilities analysed as `not_affected` or `false_positive` are marked as suppressed.
+pub fn sarif_json(vex: &Bom) -> serde_json::Value {
+    let mut rules: Vec<serde_json::Value> = Vec::new();
+    let mut results: Vec<serde_json::Value> = Vec::new();
+
+    for vuln in vex.vulnerabilities.iter().flat_map(|vulns| vulns.0.iter()) {
+        let rule_id = vuln
+            .id
+            .as_ref()
+            .map(|id| id.to_string())
+            .or_else(|| vuln.bom_ref.clone())
+            .unwrap_or_else(|| "unidentified".to_string());
+        let message = vuln.description.clone().unwrap_or_else(|| rule_id.clone());
+
+        let level = match vuln
+            .vulnerability_ratings
+            .iter()
+            .flat_map(|ratings| ratings.0.iter())
+            .filter_map(rating_severity)
+            .max_by_key(severity_rank)
+        {
+            Some(Severity::Critical | Severity::High) => "error",
+            Some(Severity::Medium) => "warning",
+            Some(Severity::Low | Severity::Info) => "note",
+            Some(_) => "none",
+            // SARIF's default level, the document gives no indication of the severity
+            None => "warning",
+        };
+
+        if !rules.iter().any(|rule| rule["id"] == rule_id.as_str()) {
+            rules.push(json!({
+                "id": rule_id,
+                "shortDescription": { "text": message },
+            }));
+        }
+
+        let mut result = json!({
+            "ruleId": rule_id,
+            "level": level,
+            "message": { "text": message },
+        });
+
+        let analysis = vuln.vulnerability_analysis.as_ref();
+        if matches!(
+            analysis.and_then(|analysis| analysis.state.as_ref()),
+            Some(ImpactAnalysisState::NotAffected | ImpactAnalysisState::FalsePositive)
+        ) {
+            let justification = analysis
+                .and_then(|analysis| analysis.justification.as_ref())
+                .map(|justification| justification.to_string());
+            result["suppressions"] = json!([{
+                "kind": "external",
+                "justification": justification,
+            }]);
+        }
+
+        results.push(result);
+    }
+
+    json!({
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "vex2pdf",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "informationUri": env!("CARGO_PKG_REPOSITORY"),
+                    "rules": rules,
+                }
+            },
+            "results": results,
+        }],
+    })
+}
+
+/// Writes the SARIF log of `vex` as `<stem>.sarif` next to the input file.
+///
+/// Returns the path of the written file.
+pub fn emit_sarif(vex: &Bom, file_path: &Path) -> Result<PathBuf, Box<dyn Error>> {
+    let sarif_path = get_output_path_with_extension(file_path, "sarif");
+    let content = serde_json::to_string_pretty(&sarif_json(vex))?;
+    fs::write(&sarif_path, content)?;
+
+    Ok(sarif_path)
+}
+
+/// Writes the plain text rendering of the report as `<stem>.txt` next to the input file,
+/// see [`PdfGenerator::generate_text`].
+///
+/// Returns the path of the written file.
+#[cfg(feature = "pdf")]
+pub fn emit_text(
+    pdf_generator: &PdfGenerator,
+    vex: &Bom,
+    file_path: &Path,
+    source: &ReportSource<'_>,
+) -> Result<PathBuf, Box<dyn Error>> {
+    let text_path = get_output_path_with_extension(file_path, "txt");
+    fs::write(&text_path, pdf_generator.generate_text(vex, source))?;
+
+    Ok(text_path)
+}
+
 /// Prints the application version, copyright, and license information.
+///
+/// Printed like progress messages, see [`progress`], so that a reserved standard output
+/// only receives paths.
 pub fn print_copyright() {
-    println!(
+    progress(format!(
         "vex2pdf v{} - CycloneDX (VEX) to PDF Converter",
         env!("CARGO_PKG_VERSION")
-    );
-    println!("Copyright (c) 2025 Salem B. - MIT Or Apache 2.0 License");
-    println!();
+    ));
+    progress("Copyright (c) 2025 Salem B. - MIT Or Apache 2.0 License");
+    progress("");
+}
+
+#[cfg(all(test, feature = "pdf"))]
+mod tests {
+    use super::*;
+
+    fn bom_with_serial(serial: Option<&str>, version: u32) -> Bom {
+        let serial = serial
+            .map(|serial| format!(r#""serialNumber": "{serial}","#))
+            .unwrap_or_default();
+        let json = format!(
+            r#"{{"bomFormat": "CycloneDX", "specVersion": "1.5", {serial} "version": {version}}}"#
+        );
+
+        parse_vex_json_bytes(json.as_bytes()).expect("failed to parse test document")
+    }
+
+    #[test]
+    fn test_parse_vex_json_documents_reads_arrays() {
+        let single = br#"{"bomFormat": "CycloneDX", "specVersion": "1.5", "version": 1}"#;
+        assert_eq!(parse_vex_json_documents(single).unwrap().len(), 1);
+
+        let array = br#" [
+            {"bomFormat": "CycloneDX", "specVersion": "1.5", "version": 1},
+            {"bomFormat": "CycloneDX", "specVersion": "1.6", "version": 2}
+        ]"#;
+        let boms = parse_vex_json_documents(array).unwrap();
+        assert_eq!(
+            boms.iter().map(|bom| bom.version).collect::<Vec<_>>(),
+            [1, 2]
+        );
+
+        assert!(parse_vex_json_documents(b"[]").is_err());
+        let err = parse_vex_json_documents(
+            br#"[{"bomFormat": "CycloneDX", "specVersion": "1.5", "version": 1}, {}]"#,
+        )
+        .unwrap_err();
+        assert!(err.to_string().starts_with("document 2 of the array"));
+    }
+
+    #[test]
+    fn test_parse_vex_json_bytes_rejects_other_bom_formats() {
+        let err =
+            parse_vex_json_bytes(br#"{"bomFormat": "SPDX", "specVersion": "1.5", "version": 1}"#)
+                .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"not a CycloneDX document: bomFormat is "SPDX", expected "CycloneDX""#
+        );
+
+        let err = parse_vex_json_bytes(
+            br#"{"spdxVersion": "SPDX-2.3", "SPDXID": "SPDXRef-DOCUMENT", "packages": []}"#,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "not a CycloneDX document: found an SPDX document"
+        );
+
+        assert_eq!(
+            bom_format_error(br#"{"specVersion": "1.5"}"#).as_deref(),
+            Some("not a CycloneDX document: bomFormat is missing")
+        );
+        assert_eq!(
+            bom_format_error(br#"{"bomFormat": "CycloneDX", "specVersion": "9.9"}"#),
+            None
+        );
+        assert_eq!(bom_format_error(b"not json"), None);
+    }
+
+    #[test]
+    fn test_emit_normalized_json_writes_downgraded_document() {
+        let vex = parse_vex_json_bytes(
+            br#"{
+                "bomFormat": "CycloneDX",
+                "specVersion": "1.6",
+                "version": 1,
+                "vulnerabilities": [{"id": "CVE-2025-0001"}]
+            }"#,
+        )
+        .expect("failed to parse 1.6 document");
+        let input = std::env::temp_dir().join("test_emit_normalized.json");
+
+        let json_path = emit_normalized_json(&vex, &input).expect("failed to write document");
+        let content = fs::read(&json_path).expect("failed to read normalized document");
+        fs::remove_file(&json_path).expect("Failed to remove temp file");
+
+        assert_eq!(
+            json_path,
+            std::env::temp_dir().join("test_emit_normalized.normalized.json")
+        );
+        let normalized: serde_json::Value =
+            serde_json::from_slice(&content).expect("normalized document is not JSON");
+        assert_eq!(normalized["specVersion"], "1.5");
+        assert_eq!(normalized["vulnerabilities"][0]["id"], "CVE-2025-0001");
+    }
+
+    #[test]
+    fn test_sha256_hex() {
+        assert_eq!(
+            sha256_hex(b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn test_find_files_skips_files_modified_before_threshold() {
+        let dir = std::env::temp_dir().join("test_find_files_modified_since");
+        fs::create_dir_all(&dir).expect("failed to create temp dir");
+        fs::write(dir.join("bom.json"), "{}").expect("failed to write test file");
+        let now = SystemTime::now();
+
+        let mut config = Config {
+            working_dir: dir.clone(),
+            modified_since: now.checked_sub(std::time::Duration::from_secs(3600)),
+            ..Config::default()
+        };
+        let recent = find_files(&config, InputFileType::JSON).expect("failed to scan");
+
+        config.modified_since = now.checked_add(std::time::Duration::from_secs(3600));
+        let future = find_files(&config, InputFileType::JSON).expect("failed to scan");
+        fs::remove_dir_all(&dir).expect("failed to remove temp dir");
+
+        assert_eq!(recent, Some(vec![dir.join("bom.json")]));
+        assert_eq!(future, Some(Vec::new()));
+    }
+
+    #[test]
+    fn test_numbered_path() {
+        assert_eq!(
+            numbered_path(Path::new("out/bom.json"), 2),
+            Path::new("out/bom-2.json")
+        );
+        assert_eq!(numbered_path(Path::new("bom"), 1), Path::new("bom-1"));
+        assert_eq!(
+            versioned_path(Path::new("out/bom-2.pdf"), 3),
+            Path::new("out/bom-2.v3.pdf")
+        );
+    }
+
+    #[test]
+    fn test_format_collisions_are_suffixed_or_resolved_by_preference() {
+        let json = vec![PathBuf::from("in/bom.json"), PathBuf::from("in/other.json")];
+        let xml = vec![PathBuf::from("in/bom.xml"), PathBuf::from("in/bom.v2.xml")];
+        assert_eq!(
+            format_collisions(&json, &xml),
+            [(PathBuf::from("in/bom.json"), PathBuf::from("in/bom.xml"))]
+        );
+        assert_eq!(
+            format_suffixed_path(Path::new("in/bom.XML")),
+            Path::new("in/bom-xml.XML")
+        );
+
+        let mut config = Config::default();
+        let (mut json_files, mut xml_files) = (Some(json.clone()), Some(xml.clone()));
+        let suffixed = resolve_format_collisions(&config, &mut json_files, &mut xml_files);
+        assert_eq!(suffixed.len(), 2);
+        assert!(suffixed.contains(Path::new("in/bom.xml")));
+        assert_eq!(xml_files.as_deref(), Some(xml.as_slice()));
+
+        config.prefer_format = Some(InputFileType::JSON);
+        let (mut json_files, mut xml_files) = (Some(json.clone()), Some(xml));
+        let suffixed = resolve_format_collisions(&config, &mut json_files, &mut xml_files);
+        assert!(suffixed.is_empty());
+        assert_eq!(json_files, Some(json));
+        assert_eq!(xml_files, Some(vec![PathBuf::from("in/bom.v2.xml")]));
+    }
+
+    #[test]
+    fn test_output_base_path_moves_outputs_to_output_dir() {
+        let mut config = Config::default();
+        assert_eq!(
+            output_base_path(&config, Path::new("in/bom-2.json")),
+            Path::new("in/bom-2.json")
+        );
+
+        config.output_dir = Some(PathBuf::from("/tmp/vex2pdf-out"));
+        assert_eq!(
+            output_base_path(&config, Path::new("in/bom-2.json")),
+            Path::new("/tmp/vex2pdf-out/bom-2.json")
+        );
+    }
+
+    #[test]
+    fn test_duplicate_serials_and_latest_version() {
+        let serial = "urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79";
+        let boms = [
+            bom_with_serial(Some(serial), 1),
+            bom_with_serial(Some("urn:uuid:8b0bd4f3-6b0c-4a42-9d2e-2b9f3c1f0a11"), 1),
+            bom_with_serial(None, 1),
+            bom_with_serial(Some(serial), 3),
+            bom_with_serial(None, 2),
+        ];
+
+        let duplicates = duplicate_serials(boms.iter());
+
+        assert_eq!(duplicates.len(), 1);
+        let indices = &duplicates[serial];
+        assert_eq!(indices, &vec![0, 3]);
+        assert_eq!(latest_version(indices, |index| boms[index].version), 3);
+    }
+
+    #[test]
+    fn test_get_output_pdf_path_versions_existing_reports() {
+        let dir = std::env::temp_dir().join("vex2pdf_test_output_mode");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).expect("failed to create temp dir");
+        let input = dir.join("report.json");
+
+        assert_eq!(get_output_pdf_path(&input), dir.join("report.pdf"));
+        for mode in [OutputMode::Overwrite, OutputMode::Skip, OutputMode::Version] {
+            assert_eq!(
+                get_output_pdf_path_with_mode(&input, mode),
+                dir.join("report.pdf")
+            );
+        }
+
+        fs::write(dir.join("report.pdf"), b"").unwrap();
+        fs::write(dir.join("report.v1.pdf"), b"").unwrap();
+        assert_eq!(
+            get_output_pdf_path_with_mode(&input, OutputMode::Overwrite),
+            dir.join("report.pdf")
+        );
+        assert_eq!(
+            get_output_pdf_path_with_mode(&input, OutputMode::Version),
+            dir.join("report.v2.pdf")
+        );
+
+        fs::remove_dir_all(&dir).expect("failed to remove temp dir");
+    }
+
+    #[test]
+    fn test_versions_of_array_documents_stay_apart() {
+        let dir = std::env::temp_dir().join("vex2pdf_test_output_mode_array");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).expect("failed to create temp dir");
+        let input = dir.join("bom.json");
+        let (first, second) = (numbered_path(&input, 1), numbered_path(&input, 2));
+
+        // A first run of an input holding two documents
+        for document in [&first, &second] {
+            let pdf_path = get_output_pdf_path_with_mode(document, OutputMode::Version);
+            fs::write(pdf_path, b"").unwrap();
+        }
+        assert!(dir.join("bom-1.pdf").exists() && dir.join("bom-2.pdf").exists());
+
+        // Versions of each document are numbered apart from the other document
+        assert_eq!(
+            get_output_pdf_path_with_mode(&first, OutputMode::Version),
+            dir.join("bom-1.v1.pdf")
+        );
+        assert_eq!(
+            get_output_pdf_path_with_mode(&second, OutputMode::Version),
+            dir.join("bom-2.v1.pdf")
+        );
+
+        fs::remove_dir_all(&dir).expect("failed to remove temp dir");
+    }
+
+    #[test]
+    fn test_spec_version_support() {
+        for version in NATIVE_SPEC_VERSIONS {
+            assert_eq!(spec_version_support(version), SpecSupport::Native);
+        }
+        assert_eq!(spec_version_support(" 1.6 "), SpecSupport::Downgradable);
+        assert_eq!(spec_version_support("1.2"), SpecSupport::Unsupported);
+        assert_eq!(spec_version_support(""), SpecSupport::Unsupported);
+    }
 }
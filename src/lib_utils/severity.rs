@@ -0,0 +1,177 @@
+//! Qualitative severities of vulnerability ratings.
+//!
+//! Shared by the PDF report and the JSON and SARIF summaries, which all need the severity
+//! of ratings that only carry a score.
+//!
+
+use cyclonedx_bom::models::vulnerability_rating::{Severity, VulnerabilityRating};
+use std::fmt;
+use std::str::FromStr;
+
+/// Lowest scores of the Critical, High and Medium severities, used to derive the severity
+/// of ratings that only carry a score.
+///
+/// Positive scores below the Medium band are Low, a score of 0 has no severity. The
+/// default are the standard CVSS v3 bands (0.1-3.9 Low, 4.0-6.9 Medium, 7.0-8.9 High,
+/// 9.0-10.0 Critical).
+///
+/// Parsed from the comma separated lowest scores of the Critical, High and Medium bands,
+/// as in `VEX2PDF_SEVERITY_BANDS`:
+///
+/// ```
+/// use cyclonedx_bom::models::vulnerability_rating::Severity;
+/// use vex2pdf::lib_utils::severity::SeverityBands;
+///
+/// let bands: SeverityBands = "8.0, 6.0, 3.0".parse().unwrap();
+/// assert_eq!(bands.severity(8.1), Severity::Critical);
+/// assert_eq!(bands.severity(3.5), Severity::Medium);
+/// assert!("4.0, 7.0, 9.0".parse::<SeverityBands>().is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SeverityBands {
+    critical: f32,
+    high: f32,
+    medium: f32,
+}
+
+impl SeverityBands {
+    /// Returns the bands with the given lowest scores, `None` unless
+    /// `0 < medium < high < critical <= 10`
+    pub fn new(critical: f32, high: f32, medium: f32) -> Option<Self> {
+        (0.0 < medium && medium < high && high < critical && critical <= 10.0).then_some(Self {
+            critical,
+            high,
+            medium,
+        })
+    }
+
+    /// Derives the qualitative severity of a numeric CVSS score
+    pub fn severity(&self, score: f32) -> Severity {
+        if score >= self.critical {
+            Severity::Critical
+        } else if score >= self.high {
+            Severity::High
+        } else if score >= self.medium {
+            Severity::Medium
+        } else if score > 0.0 {
+            Severity::Low
+        } else {
+            Severity::None
+        }
+    }
+
+    /// Returns the severity of a rating, derived from its score if no severity is given
+    pub(crate) fn rating_severity(&self, rating: &VulnerabilityRating) -> Option<Severity> {
+        match (&rating.severity, &rating.score) {
+            (Some(severity), _) => Some(severity.clone()),
+            (None, Some(score)) => Some(self.severity(score.to_f32())),
+            (None, None) => None,
+        }
+    }
+}
+
+impl Default for SeverityBands {
+    fn default() -> Self {
+        Self {
+            critical: 9.0,
+            high: 7.0,
+            medium: 4.0,
+        }
+    }
+}
+
+impl fmt::Display for SeverityBands {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{},{}", self.critical, self.high, self.medium)
+    }
+}
+
+impl FromStr for SeverityBands {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let scores = value
+            .split(',')
+            .map(|score| score.trim().parse::<f32>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|err| format!("invalid score in severity bands '{value}': {err}"))?;
+        match scores.as_slice() {
+            [critical, high, medium] => Self::new(*critical, *high, *medium).ok_or_else(|| {
+                format!("severity bands '{value}' are not descending scores between 0 and 10")
+            }),
+            _ => Err(format!(
+                "severity bands '{value}' must list the lowest Critical, High and Medium scores"
+            )),
+        }
+    }
+}
+
+/// Derives a qualitative severity from a numeric CVSS score using the standard
+/// CVSS v3 bands (0.1-3.9 Low, 4.0-6.9 Medium, 7.0-8.9 High, 9.0-10.0 Critical).
+pub(crate) fn severity_from_score(score: f32) -> Severity {
+    SeverityBands::default().severity(score)
+}
+
+/// Returns the severity of a rating, derived from its score with the standard bands if no
+/// severity is given
+pub(crate) fn rating_severity(rating: &VulnerabilityRating) -> Option<Severity> {
+    SeverityBands::default().rating_severity(rating)
+}
+
+/// Ranks severities from least to most severe so that the highest can be picked
+pub(crate) fn severity_rank(severity: &Severity) -> u8 {
+    match severity {
+        Severity::Critical => 5,
+        Severity::High => 4,
+        Severity::Medium => 3,
+        Severity::Low => 2,
+        Severity::Info => 1,
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_severity_from_score_bands() {
+        assert_eq!(severity_from_score(0.0), Severity::None);
+        assert_eq!(severity_from_score(0.1), Severity::Low);
+        assert_eq!(severity_from_score(3.9), Severity::Low);
+        assert_eq!(severity_from_score(4.0), Severity::Medium);
+        assert_eq!(severity_from_score(6.9), Severity::Medium);
+        assert_eq!(severity_from_score(7.0), Severity::High);
+        assert_eq!(severity_from_score(8.9), Severity::High);
+        assert_eq!(severity_from_score(9.0), Severity::Critical);
+        assert_eq!(severity_from_score(10.0), Severity::Critical);
+    }
+
+    #[test]
+    fn test_custom_severity_bands() {
+        let bands: SeverityBands = " 8.5, 6.0 ,3.0".parse().unwrap();
+        assert_eq!(bands.severity(8.5), Severity::Critical);
+        assert_eq!(bands.severity(8.4), Severity::High);
+        assert_eq!(bands.severity(6.0), Severity::High);
+        assert_eq!(bands.severity(3.0), Severity::Medium);
+        assert_eq!(bands.severity(2.9), Severity::Low);
+        assert_eq!(bands.severity(0.0), Severity::None);
+        assert_eq!(bands.to_string().parse::<SeverityBands>(), Ok(bands));
+
+        assert!("9.0,7.0".parse::<SeverityBands>().is_err());
+        assert!("9.0,7.0,high".parse::<SeverityBands>().is_err());
+        assert!("9.0,7.0,7.0".parse::<SeverityBands>().is_err());
+        assert!("11.0,7.0,4.0".parse::<SeverityBands>().is_err());
+        assert!("9.0,7.0,0.0".parse::<SeverityBands>().is_err());
+    }
+
+    #[test]
+    fn test_severity_rank_orders_severities() {
+        let mut severities = vec![Severity::Low, Severity::Critical, Severity::Medium];
+        severities.sort_by_key(severity_rank);
+        assert_eq!(
+            severities,
+            vec![Severity::Low, Severity::Medium, Severity::Critical]
+        );
+    }
+}
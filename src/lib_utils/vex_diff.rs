@@ -0,0 +1,169 @@
+//! Comparison of the vulnerabilities of two CycloneDX documents.
+//!
+//! Used to render the changes since a previous report, see `VEX2PDF_DIFF_AGAINST`.
+//! Vulnerabilities are matched by ID; vulnerabilities without an ID cannot be matched
+//! and are ignored.
+//!
+
+use cyclonedx_bom::models::vulnerability::Vulnerability;
+use cyclonedx_bom::prelude::Bom;
+use std::collections::BTreeMap;
+
+/// A vulnerability and its analysis state
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VulnStatus {
+    /// ID of the vulnerability
+    pub id: String,
+    /// Analysis state such as `not_affected`, `None` if the vulnerability was not analysed
+    pub state: Option<String>,
+}
+
+/// A vulnerability whose analysis state differs between two documents
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StateChange {
+    /// ID of the vulnerability
+    pub id: String,
+    /// Analysis state in the previous document
+    pub previous: Option<String>,
+    /// Analysis state in the current document
+    pub current: Option<String>,
+}
+
+/// Differences between the vulnerabilities of two documents, each sorted by ID
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VulnDiff {
+    /// Vulnerabilities only present in the current document
+    pub added: Vec<VulnStatus>,
+    /// Vulnerabilities only present in the previous document
+    pub removed: Vec<VulnStatus>,
+    /// Vulnerabilities present in both documents with a different analysis state
+    pub changed: Vec<StateChange>,
+}
+
+impl VulnDiff {
+    /// Returns true if the vulnerabilities of both documents are the same
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Compares the vulnerabilities of the `current` document with the `previous` one.
+///
+/// If an ID occurs more than once within a document, its first occurrence is used.
+pub fn diff_vulnerabilities(previous: &Bom, current: &Bom) -> VulnDiff {
+    let previous = vuln_states(previous);
+    let current = vuln_states(current);
+    let mut diff = VulnDiff::default();
+
+    for (id, state) in &current {
+        match previous.get(id) {
+            None => diff.added.push(VulnStatus {
+                id: id.clone(),
+                state: state.clone(),
+            }),
+            Some(previous_state) if previous_state != state => diff.changed.push(StateChange {
+                id: id.clone(),
+                previous: previous_state.clone(),
+                current: state.clone(),
+            }),
+            Some(_) => {}
+        }
+    }
+
+    for (id, state) in previous {
+        if !current.contains_key(&id) {
+            diff.removed.push(VulnStatus { id, state });
+        }
+    }
+
+    diff
+}
+
+/// Maps the ID of each vulnerability of the document to its analysis state
+fn vuln_states(bom: &Bom) -> BTreeMap<String, Option<String>> {
+    let mut states = BTreeMap::new();
+
+    for vuln in bom.vulnerabilities.iter().flat_map(|vulns| &vulns.0) {
+        if let Some(id) = &vuln.id {
+            states
+                .entry(id.to_string())
+                .or_insert_with(|| analysis_state(vuln));
+        }
+    }
+
+    states
+}
+
+/// Returns the analysis state of the vulnerability in its CycloneDX spelling
+fn analysis_state(vuln: &Vulnerability) -> Option<String> {
+    vuln.vulnerability_analysis
+        .as_ref()
+        .and_then(|analysis| analysis.state.as_ref())
+        .map(|state| state.to_string().to_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lib_utils::run_utils::parse_vex_json_bytes;
+
+    /// Builds a document from `(id, state)` pairs
+    fn bom_with(vulns: &[(&str, &str)]) -> Bom {
+        let vulns: Vec<String> = vulns
+            .iter()
+            .map(|(id, state)| format!(r#"{{"id": "{id}", "analysis": {{"state": "{state}"}}}}"#))
+            .collect();
+        let json = format!(
+            r#"{{"bomFormat": "CycloneDX", "specVersion": "1.5", "version": 1, "vulnerabilities": [{}]}}"#,
+            vulns.join(",")
+        );
+
+        parse_vex_json_bytes(json.as_bytes()).expect("failed to parse test document")
+    }
+
+    #[test]
+    fn test_diff_vulnerabilities() {
+        let previous = bom_with(&[
+            ("CVE-2025-0001", "in_triage"),
+            ("CVE-2025-0002", "exploitable"),
+            ("CVE-2025-0003", "not_affected"),
+        ]);
+        let current = bom_with(&[
+            ("CVE-2025-0001", "not_affected"),
+            ("CVE-2025-0003", "not_affected"),
+            ("CVE-2025-0004", "in_triage"),
+        ]);
+
+        let diff = diff_vulnerabilities(&previous, &current);
+
+        assert_eq!(
+            diff.added,
+            vec![VulnStatus {
+                id: "CVE-2025-0004".to_string(),
+                state: Some("in_triage".to_string()),
+            }]
+        );
+        assert_eq!(
+            diff.removed,
+            vec![VulnStatus {
+                id: "CVE-2025-0002".to_string(),
+                state: Some("exploitable".to_string()),
+            }]
+        );
+        assert_eq!(
+            diff.changed,
+            vec![StateChange {
+                id: "CVE-2025-0001".to_string(),
+                previous: Some("in_triage".to_string()),
+                current: Some("not_affected".to_string()),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_diff_of_identical_documents_is_empty() {
+        let bom = bom_with(&[("CVE-2025-0001", "resolved")]);
+
+        assert!(diff_vulnerabilities(&bom, &bom).is_empty());
+    }
+}
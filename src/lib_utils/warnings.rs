@@ -0,0 +1,109 @@
+//! Central tracking of warnings emitted while converting documents.
+//!
+//! All warnings are printed through [`warn`] so that they are counted in one place.
+//! This allows `run()` to fail at the end of processing when warnings are treated
+//! as errors (see `VEX2PDF_WARNINGS_AS_ERRORS`).
+//!
+//! While a [`NoteCollection`] is active on the current thread, warnings are also kept as
+//! notes so that they can be rendered into the report (see `VEX2PDF_SHOW_NOTES`).
+//!
+
+use super::console::progress;
+use std::cell::RefCell;
+use std::fmt::Display;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Number of warnings emitted since the process started
+static WARNING_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    /// Notes recorded while a collection is active, in the order they were recorded
+    static NOTES: RefCell<Option<Vec<String>>> = const { RefCell::new(None) };
+}
+
+/// Prints a warning like a progress message, see [`progress`], and records it.
+///
+/// # Examples
+///
+/// ```
+/// use vex2pdf::lib_utils::warnings::{warn, warning_count};
+///
+/// let before = warning_count();
+/// warn("something looks off");
+/// assert!(warning_count() > before);
+/// ```
+pub fn warn(message: impl Display) {
+    progress(format!("**** WARNING: {message}"));
+    record_warning();
+    record_note(message);
+}
+
+/// Records a warning that was printed by other means, e.g. a multi-line notice
+pub(crate) fn record_warning() {
+    WARNING_COUNT.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Returns the number of warnings emitted so far
+pub fn warning_count() -> usize {
+    WARNING_COUNT.load(Ordering::Relaxed)
+}
+
+/// Keeps a note for the report if a [`NoteCollection`] is active on the current thread.
+///
+/// Warnings printed through [`warn`] are noted automatically, use this for warnings
+/// printed by other means.
+pub(crate) fn record_note(message: impl Display) {
+    NOTES.with(|notes| {
+        if let Some(notes) = notes.borrow_mut().as_mut() {
+            notes.push(message.to_string());
+        }
+    });
+}
+
+/// Returns the notes recorded so far by the collection active on the current thread,
+/// empty if no collection is active
+pub fn collected_notes() -> Vec<String> {
+    NOTES.with(|notes| notes.borrow().clone().unwrap_or_default())
+}
+
+/// Collects the warnings emitted on the current thread as notes while it is alive.
+///
+/// # Examples
+///
+/// ```
+/// use vex2pdf::lib_utils::warnings::{warn, NoteCollection};
+///
+/// let collection = NoteCollection::start();
+/// warn("something looks off");
+/// assert_eq!(collection.finish(), ["something looks off"]);
+/// ```
+pub struct NoteCollection {
+    _private: (),
+}
+
+impl NoteCollection {
+    /// Starts collecting notes, replacing any collection already active on this thread
+    pub fn start() -> Self {
+        Self::start_with(Vec::new())
+    }
+
+    /// Starts collecting notes after the given earlier notes, e.g. those collected while
+    /// parsing the document
+    pub fn start_with(notes: Vec<String>) -> Self {
+        NOTES.with(|active| *active.borrow_mut() = Some(notes));
+        Self { _private: () }
+    }
+
+    /// Stops collecting and returns the collected notes
+    pub fn finish(self) -> Vec<String> {
+        NOTES
+            .with(|notes| notes.borrow_mut().take())
+            .unwrap_or_default()
+    }
+}
+
+impl Drop for NoteCollection {
+    fn drop(&mut self) {
+        NOTES.with(|notes| notes.borrow_mut().take());
+    }
+}
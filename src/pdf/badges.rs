@@ -0,0 +1,97 @@
+//! Row of colored badges, used for the responses of a vulnerability analysis.
+//!
+//! genpdf has no element for labels on a colored background, so each badge is filled with
+//! [`fill_rect`] and its label printed on top, the same way the score gauge is drawn.
+//!
+
+use crate::pdf::page_decorator::fill_rect;
+use genpdf::error::Error;
+use genpdf::render::Area;
+use genpdf::style::{Color, Style};
+use genpdf::{Context, Element, Mm, Position, RenderResult, Size};
+
+/// Horizontal space in mm between a badge's edge and its label
+const PADDING: f64 = 1.5;
+
+/// Horizontal space in mm between two badges
+const GAP: f64 = 2.0;
+
+/// Color of the badge labels
+const LABEL_COLOR: Color = Color::Rgb(255, 255, 255);
+
+/// A row of badges, each a label on a colored background. Badges that do not fit into the
+/// remaining width wrap onto a new row.
+pub struct Badges {
+    /// Label and background color of each badge
+    badges: Vec<(String, Color)>,
+}
+
+impl Badges {
+    /// Creates a row of badges
+    ///
+    /// # Arguments
+    ///
+    /// * `badges` - Label and background color of each badge, in render order
+    pub fn new(badges: Vec<(String, Color)>) -> Self {
+        Self { badges }
+    }
+}
+
+impl Element for Badges {
+    fn render(
+        &mut self,
+        context: &Context,
+        area: Area<'_>,
+        style: Style,
+    ) -> Result<RenderResult, Error> {
+        let label_style = style.bold().with_color(LABEL_COLOR);
+        let row_height = label_style.line_height(&context.font_cache);
+        let available_width = area.size().width;
+
+        // Lay out all badges first so that the whole row moves to the next page if needed
+        let mut placements = Vec::with_capacity(self.badges.len());
+        let (mut x, mut y, mut width) = (Mm::from(0), Mm::from(0), Mm::from(0));
+        for (label, _) in &self.badges {
+            let badge_width =
+                label_style.str_width(&context.font_cache, label) + Mm::from(PADDING * 2.0);
+            if x > Mm::from(0) && x + badge_width > available_width {
+                x = Mm::from(0);
+                y = y + row_height + Mm::from(GAP / 2.0);
+            }
+            placements.push((x, y, badge_width));
+            if x + badge_width > width {
+                width = x + badge_width;
+            }
+            x = x + badge_width + Mm::from(GAP);
+        }
+        let size = Size::new(width, y + row_height);
+
+        if area.size().height < size.height {
+            // Not enough space left on this page, render the badges on the next one
+            return Ok(RenderResult {
+                size: Size::new(0, 0),
+                has_more: true,
+            });
+        }
+
+        for ((label, color), (x, y, badge_width)) in self.badges.iter().zip(placements) {
+            fill_rect(
+                &area,
+                Position::new(x, y),
+                Size::new(badge_width, row_height),
+                *color,
+            );
+            area.print_str(
+                &context.font_cache,
+                Position::new(x + Mm::from(PADDING), y),
+                label_style,
+                label,
+            )?;
+        }
+
+        Ok(RenderResult {
+            size,
+            has_more: false,
+        })
+    }
+}
@@ -1,9 +1,15 @@
+use crate::lib_utils::console::progress;
+use crate::lib_utils::env_vars::EnvVarNames;
+use crate::lib_utils::warnings::warn;
 use genpdf::fonts::{FontData, FontFamily};
 use std::error::Error;
+use std::fs;
 
 /// `FontsDir` manages font directory paths across different locations in the system.
 ///  Used to manage different external font path directories before.
 ///  As of v0.6.1 the logic is simplified to only load font files embedded in the library.
+///  Individual font variants can be replaced with font files through environment variables,
+///  see [`FontsDir::build`].
 pub struct FontsDir {
     pub font_family: FontFamily<FontData>,
 }
@@ -15,6 +21,11 @@ impl FontsDir {
     /// (regular, bold, italic, and bold-italic) that are embedded directly in the binary.
     /// This approach eliminates the need for external font files on the host system.
     ///
+    /// Each variant can be replaced with a TrueType font file by setting `VEX2PDF_FONT_REGULAR`,
+    /// `VEX2PDF_FONT_BOLD`, `VEX2PDF_FONT_ITALIC` or `VEX2PDF_FONT_BOLD_ITALIC` to its path.
+    /// Variants without a custom font keep the embedded font, and a custom font that cannot be
+    /// loaded is reported with a warning and replaced by the embedded variant as well.
+    ///
     /// This method is private since v0.7.0 and is automatically called by the `FontsDir::build()` builder
     ///
     /// # Returns
@@ -59,13 +70,31 @@ impl FontsDir {
     /// The embedded LiberationSans fonts are licensed under the SIL Open Font License.
     /// Set the environment variable `VEX2PDF_SHOW_OSS_LICENSES=true` to display full license details.
     pub fn build() -> Self {
-        FontsDir {
-            font_family : FontsDir::load_embedded_font_family().expect("Embedded fonts failed to load. \
+        let mut font_family = FontsDir::load_embedded_font_family().expect("Embedded fonts failed to load. \
              this is a fatal failure that is caused by a corrupt build. Please contact the DEV or submit and Issue \
-             under https://github.com/jurassicLizard/vex2pdf/issues")
+             under https://github.com/jurassicLizard/vex2pdf/issues");
+
+        let variants = [
+            (EnvVarNames::FontRegular, &mut font_family.regular),
+            (EnvVarNames::FontBold, &mut font_family.bold),
+            (EnvVarNames::FontItalic, &mut font_family.italic),
+            (EnvVarNames::FontBoldItalic, &mut font_family.bold_italic),
+        ];
+        for (env_var, font) in variants {
+            if let Some(path) = env_var.get_value().filter(|path| !path.trim().is_empty()) {
+                match FontsDir::load_font_file(&path) {
+                    Ok(custom_font) => *font = custom_font,
+                    Err(err) => warn(format!(
+                        "failed to load font {path} from {}, using the embedded font instead: {err}",
+                        env_var.as_str()
+                    )),
+                }
+            }
         }
+
+        FontsDir { font_family }
     }
-    /// Prints information about the active font source to standard output.
+    /// Prints information about the active font source as progress messages.
     ///
     ///
     /// The output will include either:
@@ -76,7 +105,7 @@ impl FontsDir {
     /// ```
     /// use vex2pdf::pdf::font_config::FontsDir;
     ///
-    /// FontsDir::print_fonts_info(); // Prints the active font source to stdout
+    /// FontsDir::print_fonts_info(); // Prints the active font source
     /// ```
     ///
     /// # Output Example
@@ -86,8 +115,25 @@ impl FontsDir {
     ///
     /// ```
     pub fn print_fonts_info() {
-        println!("Active font path: <embedded liberationSans fonts> -- the env variable VEX2PDF_SHOW_OSS_LICENSES=true shows Font license details");
-        println!();
+        progress("Active font path: <embedded liberationSans fonts> -- the env variable VEX2PDF_SHOW_OSS_LICENSES=true shows Font license details");
+        for env_var in [
+            EnvVarNames::FontRegular,
+            EnvVarNames::FontBold,
+            EnvVarNames::FontItalic,
+            EnvVarNames::FontBoldItalic,
+        ] {
+            if let Some(path) = env_var.get_value().filter(|path| !path.trim().is_empty()) {
+                progress(format!("Custom font ({}): {path}", env_var.as_str()));
+            }
+        }
+        progress("");
+    }
+
+    /// Loads a single font variant from a TrueType font file
+    fn load_font_file(path: &str) -> Result<FontData, Box<dyn Error>> {
+        let font_data = fs::read(path)?;
+
+        Ok(FontData::new(font_data, None)?)
     }
 
     /// private builder method that loads the embedded font files
@@ -116,3 +162,19 @@ impl FontsDir {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_font_file() {
+        let font_path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/external/fonts/liberation-fonts/LiberationSans-Italic.ttf"
+        );
+
+        assert!(FontsDir::load_font_file(font_path).is_ok());
+        assert!(FontsDir::load_font_file("does/not/exist.ttf").is_err());
+    }
+}
@@ -0,0 +1,590 @@
+//! Font subsetting of generated reports.
+//!
+//! genpdf embeds every font of the font family completely, although a report only draws a
+//! small part of their glyphs. [`subset_fonts`] rewrites a rendered file so that each
+//! embedded TrueType font only contains the outlines of the glyphs drawn on its pages.
+//! Used by `VEX2PDF_SUBSET_FONTS`.
+//!
+//! The fonts are embedded with an identity mapping from character codes to glyph IDs, so
+//! the glyph IDs are kept: the outlines of unused glyphs are emptied, tables that are not
+//! needed to render glyphs are dropped and the glyph widths are limited to the used glyphs.
+//!
+
+use lopdf::content::{Content, Operation};
+use lopdf::{Dictionary, Document, Object, ObjectId};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+/// Tables kept in subset fonts, sorted by tag as the table directory requires
+const KEPT_TABLES: [&[u8; 4]; 13] = [
+    b"OS/2", b"cmap", b"cvt ", b"fpgm", b"glyf", b"head", b"hhea", b"hmtx", b"loca", b"maxp",
+    b"name", b"post", b"prep",
+];
+
+/// Flags of composite glyph components, see the `glyf` table of the OpenType specification
+const ARG_1_AND_2_ARE_WORDS: u16 = 0x0001;
+const WE_HAVE_A_SCALE: u16 = 0x0008;
+const MORE_COMPONENTS: u16 = 0x0020;
+const WE_HAVE_AN_X_AND_Y_SCALE: u16 = 0x0040;
+const WE_HAVE_A_TWO_BY_TWO: u16 = 0x0080;
+
+/// Checksums of complete fonts must add up to this value
+const FONT_CHECKSUM: u32 = 0xB1B0_AFBA;
+
+fn read_u16(data: &[u8], at: usize) -> Option<u16> {
+    data.get(at..at + 2)
+        .map(|bytes| u16::from_be_bytes([bytes[0], bytes[1]]))
+}
+
+fn read_u32(data: &[u8], at: usize) -> Option<u32> {
+    data.get(at..at + 4)
+        .map(|bytes| u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+/// Returns the sum of the big-endian 32-bit words of `data`, padded with zeros
+fn table_checksum(data: &[u8]) -> u32 {
+    data.chunks(4).fold(0u32, |sum, word| {
+        let mut padded = [0u8; 4];
+        padded[..word.len()].copy_from_slice(word);
+        sum.wrapping_add(u32::from_be_bytes(padded))
+    })
+}
+
+/// Returns the tag and data of each table of a TrueType font, `None` for other fonts and
+/// malformed table directories
+fn font_tables(font: &[u8]) -> Option<Vec<([u8; 4], &[u8])>> {
+    let version = read_u32(font, 0)?;
+    if version != 0x0001_0000 && version != u32::from_be_bytes(*b"true") {
+        return None;
+    }
+
+    let table_count = usize::from(read_u16(font, 4)?);
+    (0..table_count)
+        .map(|table| {
+            let record = 12 + 16 * table;
+            let tag: [u8; 4] = font.get(record..record + 4)?.try_into().ok()?;
+            let offset = read_u32(font, record + 8)? as usize;
+            let length = read_u32(font, record + 12)? as usize;
+            Some((tag, font.get(offset..offset.checked_add(length)?)?))
+        })
+        .collect()
+}
+
+/// Returns the glyph IDs of the components of a composite glyph, nothing for simple glyphs
+fn glyph_components(glyph: &[u8]) -> Option<Vec<u16>> {
+    let mut components = Vec::new();
+    // Simple glyphs have a non-negative number of contours
+    if glyph.is_empty() || read_u16(glyph, 0)? & 0x8000 == 0 {
+        return Some(components);
+    }
+
+    let mut at = 10;
+    loop {
+        let flags = read_u16(glyph, at)?;
+        components.push(read_u16(glyph, at + 2)?);
+        at += if flags & ARG_1_AND_2_ARE_WORDS != 0 {
+            8
+        } else {
+            6
+        };
+        if flags & WE_HAVE_A_SCALE != 0 {
+            at += 2;
+        } else if flags & WE_HAVE_AN_X_AND_Y_SCALE != 0 {
+            at += 4;
+        } else if flags & WE_HAVE_A_TWO_BY_TWO != 0 {
+            at += 8;
+        }
+        if flags & MORE_COMPONENTS == 0 {
+            return Some(components);
+        }
+    }
+}
+
+/// Returns a copy of a TrueType font that only keeps the outlines of `glyphs`, the
+/// components of composite glyphs among them and the `.notdef` glyph.
+///
+/// Glyph IDs are unchanged, so text drawn with the font needs no changes. Returns `None`
+/// for fonts that are not TrueType fonts or cannot be read.
+fn subset_truetype(font: &[u8], glyphs: &BTreeSet<u16>) -> Option<Vec<u8>> {
+    let tables = font_tables(font)?;
+    let table = |tag: &[u8; 4]| {
+        tables
+            .iter()
+            .find(|(table_tag, _)| table_tag == tag)
+            .map(|(_, data)| *data)
+    };
+    let head = table(b"head")?;
+    let maxp = table(b"maxp")?;
+    let loca = table(b"loca")?;
+    let glyf = table(b"glyf")?;
+
+    let glyph_count = usize::from(read_u16(maxp, 4)?);
+    let long_offsets = read_u16(head, 50)? == 1;
+    let offsets: Vec<usize> = (0..=glyph_count)
+        .map(|glyph| {
+            if long_offsets {
+                read_u32(loca, 4 * glyph).map(|offset| offset as usize)
+            } else {
+                read_u16(loca, 2 * glyph).map(|offset| usize::from(offset) * 2)
+            }
+        })
+        .collect::<Option<_>>()?;
+    let glyph_data =
+        |glyph: u16| glyf.get(offsets[usize::from(glyph)]..offsets[usize::from(glyph) + 1]);
+
+    // Composite glyphs are drawn from their components, keep those as well
+    let mut kept: BTreeSet<u16> = glyphs
+        .iter()
+        .copied()
+        .filter(|glyph| usize::from(*glyph) < glyph_count)
+        .collect();
+    kept.insert(0);
+    let mut pending: Vec<u16> = kept.iter().copied().collect();
+    while let Some(glyph) = pending.pop() {
+        for component in glyph_components(glyph_data(glyph)?)? {
+            if usize::from(component) < glyph_count && kept.insert(component) {
+                pending.push(component);
+            }
+        }
+    }
+
+    // The new offsets are always written in the long format
+    let mut new_glyf = Vec::new();
+    let mut new_loca = Vec::with_capacity((glyph_count + 1) * 4);
+    for glyph in 0..glyph_count {
+        new_loca.extend_from_slice(&(new_glyf.len() as u32).to_be_bytes());
+        if kept.contains(&(glyph as u16)) {
+            new_glyf.extend_from_slice(glyph_data(glyph as u16)?);
+            new_glyf.resize(new_glyf.len().next_multiple_of(4), 0);
+        }
+    }
+    new_loca.extend_from_slice(&(new_glyf.len() as u32).to_be_bytes());
+
+    let mut new_head = head.to_vec();
+    // Reset the checksum adjustment, it is computed over the finished font
+    new_head.get_mut(8..12)?.copy_from_slice(&[0; 4]);
+    new_head
+        .get_mut(50..52)?
+        .copy_from_slice(&1u16.to_be_bytes());
+
+    let new_tables: Vec<(&[u8; 4], Vec<u8>)> = KEPT_TABLES
+        .iter()
+        .filter_map(|tag| {
+            let data = match *tag {
+                b"glyf" => new_glyf.clone(),
+                b"loca" => new_loca.clone(),
+                b"head" => new_head.clone(),
+                // Version 3 drops the glyph names, which PDF viewers do not need
+                b"post" => match table(b"post")? {
+                    post if post.len() >= 32 => {
+                        let mut post = post[..32].to_vec();
+                        post[..4].copy_from_slice(&0x0003_0000u32.to_be_bytes());
+                        post
+                    }
+                    post => post.to_vec(),
+                },
+                tag => table(tag)?.to_vec(),
+            };
+            Some((*tag, data))
+        })
+        .collect();
+
+    let table_count = new_tables.len() as u16;
+    let entry_selector = 15 - table_count.leading_zeros() as u16;
+    let search_range = (1u16 << entry_selector) * 16;
+    let mut subset = Vec::new();
+    subset.extend_from_slice(&font[..4]);
+    for value in [
+        table_count,
+        search_range,
+        entry_selector,
+        table_count * 16 - search_range,
+    ] {
+        subset.extend_from_slice(&value.to_be_bytes());
+    }
+
+    let mut offset = 12 + 16 * new_tables.len();
+    let mut head_offset = None;
+    for (tag, data) in &new_tables {
+        subset.extend_from_slice(*tag);
+        subset.extend_from_slice(&table_checksum(data).to_be_bytes());
+        subset.extend_from_slice(&(offset as u32).to_be_bytes());
+        subset.extend_from_slice(&(data.len() as u32).to_be_bytes());
+        if *tag == b"head" {
+            head_offset = Some(offset);
+        }
+        offset += data.len().next_multiple_of(4);
+    }
+    for (_, data) in &new_tables {
+        subset.extend_from_slice(data);
+        subset.resize(subset.len().next_multiple_of(4), 0);
+    }
+
+    let adjustment = FONT_CHECKSUM.wrapping_sub(table_checksum(&subset));
+    let head_offset = head_offset?;
+    subset[head_offset + 8..head_offset + 12].copy_from_slice(&adjustment.to_be_bytes());
+
+    Some(subset)
+}
+
+/// Returns the ID of the embedded TrueType program of a Type0 font whose character codes
+/// are glyph IDs, `None` for other fonts
+fn font_file_id(pdf: &Document, font: &Dictionary) -> Option<ObjectId> {
+    fn name<'d>(dict: &'d Dictionary, key: &[u8]) -> Option<&'d [u8]> {
+        dict.get(key).and_then(Object::as_name).ok()
+    }
+    if name(font, b"Subtype")? != b"Type0" || name(font, b"Encoding")? != b"Identity-H" {
+        return None;
+    }
+
+    let (_, descendants) = pdf.dereference(font.get(b"DescendantFonts").ok()?).ok()?;
+    let (_, descendant) = pdf
+        .dereference(descendants.as_array().ok()?.first()?)
+        .ok()?;
+    let descendant = descendant.as_dict().ok()?;
+    if name(descendant, b"Subtype")? != b"CIDFontType2"
+        || descendant
+            .get(b"CIDToGIDMap")
+            .is_ok_and(|map| map.as_name().ok() != Some(b"Identity".as_slice()))
+    {
+        return None;
+    }
+
+    let (_, descriptor) = pdf
+        .dereference(descendant.get(b"FontDescriptor").ok()?)
+        .ok()?;
+    descriptor
+        .as_dict()
+        .ok()?
+        .get(b"FontFile2")
+        .and_then(Object::as_reference)
+        .ok()
+}
+
+/// Returns the embedded TrueType programs of the fonts available to a page by resource name
+fn page_font_files(pdf: &Document, page_id: ObjectId) -> BTreeMap<Vec<u8>, ObjectId> {
+    let (page_resources, resource_ids) = pdf.get_page_resources(page_id);
+    let mut font_files = BTreeMap::new();
+
+    // Resources of the page come before the ones inherited from the page tree
+    let resources = page_resources.into_iter().chain(
+        resource_ids
+            .iter()
+            .filter_map(|resources_id| pdf.get_dictionary(*resources_id).ok()),
+    );
+    for resources in resources {
+        let fonts = resources
+            .get(b"Font")
+            .and_then(|fonts| pdf.dereference(fonts))
+            .and_then(|(_, fonts)| fonts.as_dict());
+        for (name, font) in fonts.iter().flat_map(|fonts| fonts.iter()) {
+            let font_file = pdf
+                .dereference(font)
+                .and_then(|(_, font)| font.as_dict())
+                .ok()
+                .and_then(|font| font_file_id(pdf, font));
+            if let Some(font_file) = font_file {
+                font_files.entry(name.clone()).or_insert(font_file);
+            }
+        }
+    }
+
+    font_files
+}
+
+/// Returns the strings shown by a text showing operation
+fn shown_strings(operation: &Operation) -> Vec<&[u8]> {
+    let operand = match operation.operator.as_str() {
+        "Tj" | "'" | "TJ" => operation.operands.first(),
+        "\"" => operation.operands.get(2),
+        _ => None,
+    };
+
+    match operand {
+        Some(Object::String(bytes, _)) => vec![bytes.as_slice()],
+        Some(Object::Array(items)) => items
+            .iter()
+            .filter_map(|item| match item {
+                Object::String(bytes, _) => Some(bytes.as_slice()),
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Returns the glyphs drawn with each embedded TrueType program on the pages of `pdf`.
+/// Programs of fonts available to a page but not used have an empty set.
+fn used_glyphs(pdf: &Document) -> Result<BTreeMap<ObjectId, BTreeSet<u16>>, lopdf::Error> {
+    let mut glyphs: BTreeMap<ObjectId, BTreeSet<u16>> = BTreeMap::new();
+
+    for page_id in pdf.get_pages().into_values() {
+        let font_files = page_font_files(pdf, page_id);
+        for font_file in font_files.values() {
+            glyphs.entry(*font_file).or_default();
+        }
+
+        let content = Content::decode(&pdf.get_page_content(page_id)?)?;
+        // The font is part of the graphics state saved and restored by q and Q
+        let mut font_file: Option<ObjectId> = None;
+        let mut saved_font_files = Vec::new();
+        for operation in &content.operations {
+            match operation.operator.as_str() {
+                "q" => saved_font_files.push(font_file),
+                "Q" => font_file = saved_font_files.pop().flatten(),
+                "Tf" => {
+                    font_file = operation
+                        .operands
+                        .first()
+                        .and_then(|name| name.as_name().ok())
+                        .and_then(|name| font_files.get(name))
+                        .copied();
+                }
+                _ => {
+                    if let Some(font_file) = font_file {
+                        let used = glyphs.entry(font_file).or_default();
+                        for bytes in shown_strings(operation) {
+                            used.extend(
+                                bytes
+                                    .chunks_exact(2)
+                                    .map(|glyph| u16::from_be_bytes([glyph[0], glyph[1]])),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(glyphs)
+}
+
+/// Limits the glyph widths of a CIDFont `W` array to `glyphs`. Returns `None` if the array
+/// cannot be read.
+fn trimmed_widths(widths: &[Object], glyphs: &BTreeSet<u16>) -> Option<Vec<Object>> {
+    let mut kept: BTreeMap<u16, Object> = BTreeMap::new();
+    let mut at = 0;
+    while at < widths.len() {
+        let first = widths[at].as_i64().ok()?;
+        match widths.get(at + 1)? {
+            // `first [w1 w2 ...]` gives the widths of consecutive glyphs
+            Object::Array(list) => {
+                for (glyph, width) in (first..).zip(list) {
+                    if let Ok(glyph) = u16::try_from(glyph) {
+                        if glyphs.contains(&glyph) {
+                            kept.insert(glyph, width.clone());
+                        }
+                    }
+                }
+                at += 2;
+            }
+            // `first last w` gives all glyphs of the range the same width
+            last => {
+                let first = u16::try_from(first).ok()?;
+                let last = u16::try_from(last.as_i64().ok()?).ok()?;
+                let width = widths.get(at + 2)?;
+                for glyph in glyphs.range(first..=last.max(first)) {
+                    kept.insert(*glyph, width.clone());
+                }
+                at += 3;
+            }
+        }
+    }
+
+    // Write runs of consecutive glyphs in the `first [w1 w2 ...]` form
+    let mut trimmed = Vec::new();
+    let mut run: Option<(u16, Vec<Object>)> = None;
+    for (glyph, width) in kept {
+        match &mut run {
+            Some((first, run_widths))
+                if usize::from(*first) + run_widths.len() == usize::from(glyph) =>
+            {
+                run_widths.push(width);
+            }
+            _ => {
+                if let Some((first, run_widths)) = run.take() {
+                    trimmed.push(Object::Integer(i64::from(first)));
+                    trimmed.push(Object::Array(run_widths));
+                }
+                run = Some((glyph, vec![width]));
+            }
+        }
+    }
+    if let Some((first, run_widths)) = run {
+        trimmed.push(Object::Integer(i64::from(first)));
+        trimmed.push(Object::Array(run_widths));
+    }
+
+    Some(trimmed)
+}
+
+/// Limits the widths of the descendant font of the Type0 font `font_id` to `glyphs`
+fn trim_font_widths(pdf: &mut Document, font_id: ObjectId, glyphs: &BTreeSet<u16>) {
+    let descendant_id = pdf
+        .get_dictionary(font_id)
+        .and_then(|font| font.get(b"DescendantFonts"))
+        .and_then(Object::as_array)
+        .ok()
+        .and_then(|descendants| descendants.first())
+        .and_then(|descendant| descendant.as_reference().ok());
+    let descendant = match descendant_id {
+        Some(descendant_id) => pdf
+            .get_object_mut(descendant_id)
+            .and_then(Object::as_dict_mut)
+            .ok(),
+        // printpdf writes the descendant font inline
+        None => pdf
+            .get_object_mut(font_id)
+            .and_then(Object::as_dict_mut)
+            .and_then(|font| font.get_mut(b"DescendantFonts"))
+            .and_then(Object::as_array_mut)
+            .ok()
+            .and_then(|descendants| descendants.first_mut())
+            .and_then(|descendant| descendant.as_dict_mut().ok()),
+    };
+
+    if let Some(descendant) = descendant {
+        let trimmed = descendant
+            .get(b"W")
+            .and_then(Object::as_array)
+            .ok()
+            .and_then(|widths| trimmed_widths(widths, glyphs));
+        if let Some(trimmed) = trimmed {
+            descendant.set("W", trimmed);
+        }
+    }
+}
+
+/// Replaces the embedded TrueType fonts of the PDF at `path` with subsets of the glyphs
+/// drawn on its pages, see the module documentation. The file is rewritten in place.
+///
+/// Fonts that are not embedded as TrueType programs with glyph ID character codes, as
+/// genpdf embeds them, are left as they are.
+///
+/// # Examples
+///
+/// ```no_run
+/// use vex2pdf::pdf::font_subset::subset_fonts;
+///
+/// subset_fonts("report.pdf").expect("failed to subset fonts");
+/// ```
+pub fn subset_fonts<P: AsRef<Path>>(path: P) -> Result<(), lopdf::Error> {
+    let path = path.as_ref();
+    let mut pdf = Document::load(path)?;
+
+    let glyphs = used_glyphs(&pdf)?;
+    if glyphs.is_empty() {
+        return Ok(());
+    }
+
+    let fonts: Vec<(ObjectId, ObjectId)> = pdf
+        .objects
+        .iter()
+        .filter_map(|(font_id, font)| {
+            let font_file = font_file_id(&pdf, font.as_dict().ok()?)?;
+            Some((*font_id, font_file))
+        })
+        .collect();
+    for (font_id, font_file) in fonts {
+        if let Some(used) = glyphs.get(&font_file) {
+            trim_font_widths(&mut pdf, font_id, used);
+        }
+    }
+
+    for (font_file, used) in &glyphs {
+        let stream = pdf.get_object_mut(*font_file)?.as_stream_mut()?;
+        stream.decompress();
+        if let Some(subset) = subset_truetype(&stream.content, used) {
+            stream.dict.set("Length1", subset.len() as i64);
+            stream.set_plain_content(subset);
+        }
+        stream.compress()?;
+    }
+
+    pdf.save(path)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FONT: &[u8] =
+        include_bytes!("../../external/fonts/liberation-fonts/LiberationSans-Regular.ttf");
+
+    /// Returns the outline of a glyph of a font
+    fn outline(font: &[u8], glyph: u16) -> Vec<u8> {
+        let tables = font_tables(font).unwrap();
+        let table = |tag: &[u8; 4]| tables.iter().find(|(t, _)| t == tag).unwrap().1;
+        let long_offsets = read_u16(table(b"head"), 50).unwrap() == 1;
+        let offset = |glyph: usize| {
+            if long_offsets {
+                read_u32(table(b"loca"), 4 * glyph).unwrap() as usize
+            } else {
+                usize::from(read_u16(table(b"loca"), 2 * glyph).unwrap()) * 2
+            }
+        };
+        table(b"glyf")[offset(usize::from(glyph))..offset(usize::from(glyph) + 1)].to_vec()
+    }
+
+    #[test]
+    fn test_subset_truetype_keeps_used_glyphs_only() {
+        let subset = subset_truetype(FONT, &BTreeSet::from([36, 37])).unwrap();
+
+        assert!(subset.len() * 10 < FONT.len());
+        assert_eq!(table_checksum(&subset), FONT_CHECKSUM);
+        let tags: Vec<[u8; 4]> = font_tables(&subset)
+            .unwrap()
+            .iter()
+            .map(|(tag, _)| *tag)
+            .collect();
+        assert_eq!(tags, KEPT_TABLES.map(|tag| *tag));
+
+        assert_eq!(outline(&subset, 36), outline(FONT, 36));
+        assert_eq!(outline(&subset, 37), outline(FONT, 37));
+        assert_eq!(outline(&subset, 0), outline(FONT, 0));
+        assert!(!outline(FONT, 38).is_empty());
+        assert!(outline(&subset, 38).is_empty());
+    }
+
+    #[test]
+    fn test_subset_truetype_keeps_components_of_composite_glyphs() {
+        let composite = (0..2000)
+            .find(|glyph| !glyph_components(&outline(FONT, *glyph)).unwrap().is_empty())
+            .expect("font has composite glyphs");
+        let components = glyph_components(&outline(FONT, composite)).unwrap();
+
+        let subset = subset_truetype(FONT, &BTreeSet::from([composite])).unwrap();
+
+        for component in components {
+            assert_eq!(outline(&subset, component), outline(FONT, component));
+        }
+    }
+
+    #[test]
+    fn test_trimmed_widths_keep_used_glyphs() {
+        let widths = vec![
+            Object::Integer(3),
+            Object::Array(vec![
+                Object::Integer(278),
+                Object::Integer(355),
+                Object::Integer(556),
+            ]),
+            Object::Integer(10),
+            Object::Integer(20),
+            Object::Integer(500),
+        ];
+
+        let trimmed = trimmed_widths(&widths, &BTreeSet::from([3, 4, 12, 30])).unwrap();
+
+        // lopdf objects cannot be compared, compare their numbers instead
+        let numbers: Vec<Vec<i64>> = trimmed
+            .iter()
+            .map(|object| match object {
+                Object::Array(items) => items.iter().map(|item| item.as_i64().unwrap()).collect(),
+                object => vec![object.as_i64().unwrap()],
+            })
+            .collect();
+        assert_eq!(numbers, vec![vec![3], vec![278, 355], vec![12], vec![500]]);
+    }
+}
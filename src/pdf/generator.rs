@@ -7,15 +7,50 @@
 //! components, and document metadata.
 //!
 
+use crate::lib_utils::completeness::completeness;
+use crate::lib_utils::config::Config;
+use crate::lib_utils::cvss::cvss3_scores;
+use crate::lib_utils::io_retry::retry_io;
+use crate::lib_utils::purl::{purl_ecosystem, registry_url};
+use crate::lib_utils::severity::{severity_rank, SeverityBands};
+use crate::lib_utils::vex_diff::{diff_vulnerabilities, VulnDiff};
+use crate::lib_utils::warnings::{collected_notes, warn};
+use crate::pdf::badges::Badges;
 use crate::pdf::font_config::FontsDir;
+use crate::pdf::font_subset::subset_fonts;
+use crate::pdf::links::{add_links, Anchored, LinkCollection, LinkedText, LINK_COLOR};
+use crate::pdf::optimize::optimize_pdf;
+use crate::pdf::page_decorator::{displayed_page_number, ReportPageDecorator};
+use crate::pdf::pdf_version::set_pdf_version;
+use crate::pdf::redaction::{matches_pattern, redact_text, REDACTED};
+use crate::pdf::score_gauge::{ScoreGauge, MAX_SCORE};
+use crate::pdf::vulnerability_renderer::{
+    DefaultVulnerabilityRenderer, VulnerabilityContext, VulnerabilityRenderer,
+};
+use crate::pdf::xmp::{embed_metadata, ReportMetadata};
+use cyclonedx_bom::models::component::Component;
+use cyclonedx_bom::models::license::{LicenseChoice, LicenseIdentifier};
+use cyclonedx_bom::models::lifecycle::Lifecycle;
+use cyclonedx_bom::models::organization::OrganizationalContact;
+use cyclonedx_bom::models::property::Properties;
 use cyclonedx_bom::models::tool::Tools;
-use cyclonedx_bom::prelude::Bom;
-use cyclonedx_bom::models::vulnerability_analysis::ImpactAnalysisState;
-use genpdf::elements::Paragraph;
+use cyclonedx_bom::models::vulnerability::Vulnerability;
+use cyclonedx_bom::models::vulnerability_analysis::{
+    ImpactAnalysisJustification, ImpactAnalysisResponse, ImpactAnalysisState,
+};
+use cyclonedx_bom::models::vulnerability_rating::{Severity, VulnerabilityRating};
+use cyclonedx_bom::prelude::{Bom, DateTime};
+use genpdf::elements::{FrameCellDecorator, LinearLayout, Paragraph, TableLayout, UnorderedList};
+use genpdf::fonts::{FontData, FontFamily};
 use genpdf::style::{Color, Style};
 use genpdf::{Alignment, Document, Element};
+use std::borrow::Cow;
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
 use std::io;
 use std::path::Path;
+use std::sync::OnceLock;
 
 fn fmt_analysis_state(state: &ImpactAnalysisState) -> String {
     // Das Enum implementiert Display – ergibt z.B. "NotAffected".
@@ -23,6 +58,1034 @@ fn fmt_analysis_state(state: &ImpactAnalysisState) -> String {
     state.to_string().to_lowercase()
 }
 
+/// Renders an analysis justification as a phrase, e.g. `code_not_reachable` as
+/// "Code not reachable". The CycloneDX values are worded as phrases already, so the
+/// underscores are replaced and the first letter is capitalized. This also covers values
+/// the model does not know.
+fn fmt_justification(justification: &ImpactAnalysisJustification) -> String {
+    let value = justification.to_string().replace('_', " ");
+    let mut chars = value.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => value,
+    }
+}
+
+/// Joins the responses of an analysis as rendered without badges, e.g. `update, rollback`
+fn responses_text(responses: &[ImpactAnalysisResponse]) -> String {
+    responses
+        .iter()
+        .map(|response| response.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Returns the badge color of an analysis response: green for fixes, blue for
+/// mitigations and red if the vulnerability will not be fixed
+fn response_color(response: &ImpactAnalysisResponse) -> Color {
+    match response {
+        ImpactAnalysisResponse::Update | ImpactAnalysisResponse::Rollback => Color::Rgb(0, 130, 60),
+        ImpactAnalysisResponse::WorkaroundAvailable => Color::Rgb(0, 90, 170),
+        ImpactAnalysisResponse::WillNotFix | ImpactAnalysisResponse::CanNotFix => {
+            Color::Rgb(180, 0, 0)
+        }
+        _ => Color::Rgb(110, 110, 110),
+    }
+}
+
+/// Returns the symbol distinguishing a severity without relying on color, `None` for
+/// severities without a level. The symbols are part of the bundled Liberation fonts.
+fn severity_icon(severity: &Severity) -> Option<&'static str> {
+    match severity {
+        Severity::Critical => Some("■"),
+        Severity::High => Some("▲"),
+        Severity::Medium => Some("●"),
+        Severity::Low => Some("○"),
+        Severity::Info => Some("◦"),
+        _ => None,
+    }
+}
+
+/// Returns the color representing a severity, grey for severities without a level
+fn severity_color(severity: &Severity) -> Color {
+    match severity {
+        Severity::Critical => Color::Rgb(120, 0, 0),
+        Severity::High => Color::Rgb(200, 0, 0),
+        Severity::Medium => Color::Rgb(230, 120, 0),
+        Severity::Low => Color::Rgb(210, 180, 0),
+        _ => Color::Rgb(90, 90, 90),
+    }
+}
+
+/// Removes ratings repeating the severity, score method, source and score of an earlier
+/// rating, as emitted by some scanners. The first of identical ratings is kept.
+fn dedup_ratings(ratings: &mut Vec<&VulnerabilityRating>) {
+    let mut kept: Vec<&VulnerabilityRating> = Vec::with_capacity(ratings.len());
+    for rating in ratings.drain(..) {
+        let is_repeated = kept.iter().any(|earlier| {
+            earlier.severity == rating.severity
+                && earlier.score_method == rating.score_method
+                && earlier.vulnerability_source == rating.vulnerability_source
+                && earlier.score == rating.score
+        });
+        if !is_repeated {
+            kept.push(rating);
+        }
+    }
+
+    *ratings = kept;
+}
+
+/// Sorts ratings by severity, most severe first.
+///
+/// The sort is stable, so ratings of equal severity keep their BOM order. Ratings without
+/// severity and score are placed last.
+fn sort_ratings_by_severity(ratings: &mut [&VulnerabilityRating], bands: &SeverityBands) {
+    ratings.sort_by_key(|rating| {
+        let rank = bands
+            .rating_severity(rating)
+            .as_ref()
+            .map_or(0, severity_rank);
+        Reverse(rank)
+    });
+}
+
+/// Input a report is generated from, named in the Document Information section
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ReportSource<'s> {
+    /// File name, path or URL of the input document, not rendered if `None`
+    pub name: Option<&'s str>,
+    /// Hex encoded SHA-256 of the input bytes, see `VEX2PDF_SHOW_SOURCE_HASH`. Not rendered
+    /// if `None`
+    pub sha256: Option<&'s str>,
+}
+
+/// Joins the name, email and phone of a contact, e.g. `Jane Doe, jane@example.com`.
+/// Returns `None` if the contact has none of them.
+fn contact_text(contact: &OrganizationalContact) -> Option<String> {
+    let parts: Vec<String> = [&contact.name, &contact.email, &contact.phone]
+        .into_iter()
+        .flatten()
+        .map(|part| part.to_string())
+        .filter(|part| !part.trim().is_empty())
+        .collect();
+
+    (!parts.is_empty()).then(|| parts.join(", "))
+}
+
+/// Values substituted into a cover page template
+pub struct CoverValues<'v> {
+    /// Replaces `{title}`
+    pub title: &'v str,
+    /// Replaces `{date}`
+    pub date: &'v str,
+    /// Replaces `{report_date}`
+    pub report_date: &'v str,
+    /// Replaces `{serial}`
+    pub serial: &'v str,
+    /// Replaces `{component_count}`
+    pub component_count: usize,
+}
+
+/// Expands the placeholders `{title}`, `{date}`, `{report_date}`, `{serial}` and
+/// `{component_count}` in a cover page template. Unknown placeholders are left as they are.
+///
+/// # Examples
+///
+/// ```
+/// use vex2pdf::pdf::generator::{expand_cover_template, CoverValues};
+///
+/// let values = CoverValues {
+///     title: "Product X",
+///     date: "2025-06-01",
+///     report_date: "2025-09-15",
+///     serial: "urn:uuid:1234",
+///     component_count: 3,
+/// };
+/// assert_eq!(
+///     expand_cover_template("{title} ({component_count} components) {unknown}", &values),
+///     "Product X (3 components) {unknown}"
+/// );
+/// ```
+pub fn expand_cover_template(template: &str, values: &CoverValues) -> String {
+    template
+        .replace("{title}", values.title)
+        .replace("{date}", values.date)
+        .replace("{report_date}", values.report_date)
+        .replace("{serial}", values.serial)
+        .replace("{component_count}", &values.component_count.to_string())
+}
+
+/// Languages that use a decimal comma instead of a decimal point
+const DECIMAL_COMMA_LANGUAGES: [&str; 29] = [
+    "bg", "cs", "da", "de", "el", "es", "et", "fi", "fr", "hr", "hu", "id", "it", "lt", "lv", "nb",
+    "nl", "nn", "no", "pl", "pt", "ro", "ru", "sk", "sl", "sr", "sv", "tr", "uk",
+];
+
+/// Formats a score with one decimal using the decimal separator of the given language.
+///
+/// The language may carry a region and encoding (`de_DE.UTF-8`); only the language part
+/// is considered. Without a language `.` is used.
+fn format_score(score: f32, lang: Option<&str>) -> String {
+    let formatted = format!("{score:.1}");
+    let language = lang
+        .and_then(|lang| lang.split(['_', '-', '.']).next())
+        .map(str::to_lowercase);
+
+    match language {
+        Some(language) if DECIMAL_COMMA_LANGUAGES.contains(&language.as_str()) => {
+            formatted.replace('.', ",")
+        }
+        _ => formatted,
+    }
+}
+
+/// A column of the components table, selected with `VEX2PDF_COMPONENT_COLUMNS`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComponentColumn {
+    Name,
+    Version,
+    Type,
+    Purl,
+    Cpe,
+    License,
+    Supplier,
+}
+
+impl ComponentColumn {
+    /// Columns of the components table if none of the configured names is valid
+    pub const DEFAULT: [ComponentColumn; 2] = [ComponentColumn::Name, ComponentColumn::Version];
+
+    /// Parses a column name as used in `VEX2PDF_COMPONENT_COLUMNS`, ignoring case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vex2pdf::pdf::generator::ComponentColumn;
+    ///
+    /// assert_eq!(ComponentColumn::from_name("PURL"), Some(ComponentColumn::Purl));
+    /// assert_eq!(ComponentColumn::from_name("hashes"), None);
+    /// ```
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "name" => Some(Self::Name),
+            "version" => Some(Self::Version),
+            "type" => Some(Self::Type),
+            "purl" => Some(Self::Purl),
+            "cpe" => Some(Self::Cpe),
+            "license" => Some(Self::License),
+            "supplier" => Some(Self::Supplier),
+            _ => None,
+        }
+    }
+
+    /// Returns the header of the column
+    pub fn title(&self) -> &'static str {
+        match self {
+            Self::Name => "Name",
+            Self::Version => "Version",
+            Self::Type => "Type",
+            Self::Purl => "Purl",
+            Self::Cpe => "CPE",
+            Self::License => "License",
+            Self::Supplier => "Supplier",
+        }
+    }
+
+    /// Returns the relative width of the column, identifiers get more room than short values
+    fn weight(&self) -> usize {
+        match self {
+            Self::Name | Self::Purl | Self::Cpe => 3,
+            Self::License | Self::Supplier => 2,
+            Self::Version | Self::Type => 1,
+        }
+    }
+}
+
+/// Returns the footer line identifying the document, e.g.
+/// `Serial: urn:uuid:... | CycloneDX 1.5`. Documents without a serial number show `na_text`.
+fn meta_footer_text(vex: &Bom, na_text: &str) -> String {
+    let serial = vex
+        .serial_number
+        .as_ref()
+        .map_or_else(|| na_text.to_string(), |serial| serial.to_string());
+
+    format!("Serial: {serial} | CycloneDX {}", vex.spec_version)
+}
+
+/// Placeholder rendered for components whose name is empty
+const UNNAMED_COMPONENT: &str = "(unnamed)";
+
+/// Returns whether the name of a component is empty or whitespace only
+fn is_unnamed(component: &Component) -> bool {
+    component.name.trim().is_empty()
+}
+
+/// Returns the name of a component, or a placeholder if the name is empty.
+///
+/// CycloneDX requires a name, but malformed documents may still leave it empty, which
+/// would render as a blank entry.
+fn component_name(component: &Component) -> String {
+    if is_unnamed(component) {
+        UNNAMED_COMPONENT.to_string()
+    } else {
+        component.name.to_string()
+    }
+}
+
+/// Returns the licenses of a component as SPDX IDs, names or expressions
+fn component_licenses(component: &Component) -> Vec<String> {
+    component
+        .licenses
+        .iter()
+        .flat_map(|licenses| &licenses.0)
+        .map(|choice| match choice {
+            LicenseChoice::License(license) => match &license.license_identifier {
+                LicenseIdentifier::SpdxId(id) => id.to_string(),
+                LicenseIdentifier::Name(name) => name.to_string(),
+            },
+            LicenseChoice::Expression(expression) => expression.to_string(),
+        })
+        .collect()
+}
+
+/// Describes a component by its type and version, e.g. `application v2.3.1`.
+///
+/// Versions that already start with `v` are not prefixed again.
+fn component_descriptor(component_type: &str, version: Option<&str>) -> String {
+    match version.map(str::trim).filter(|version| !version.is_empty()) {
+        Some(version) if version.starts_with(['v', 'V']) => format!("{component_type} {version}"),
+        Some(version) => format!("{component_type} v{version}"),
+        None => component_type.to_string(),
+    }
+}
+
+/// Splits disclaimer text, or any other multi-line text, into paragraphs separated by
+/// blank lines.
+///
+/// Lines within a paragraph are joined with spaces since genpdf wraps text itself.
+fn disclaimer_paragraphs(text: &str) -> Vec<String> {
+    let mut paragraphs = Vec::new();
+    let mut current: Vec<&str> = Vec::new();
+
+    for line in text.lines().map(str::trim) {
+        if line.is_empty() {
+            if !current.is_empty() {
+                paragraphs.push(current.join(" "));
+                current.clear();
+            }
+        } else {
+            current.push(line);
+        }
+    }
+    if !current.is_empty() {
+        paragraphs.push(current.join(" "));
+    }
+
+    paragraphs
+}
+
+/// Returns true if the document lists vulnerabilities and every one of them has been
+/// analysed as `not_affected` or `resolved`, i.e. the product as a whole is unaffected.
+fn is_product_unaffected(vulnerabilities: &[Vulnerability]) -> bool {
+    !vulnerabilities.is_empty()
+        && vulnerabilities.iter().all(|vuln| {
+            matches!(
+                vuln.vulnerability_analysis
+                    .as_ref()
+                    .and_then(|analysis| analysis.state.as_ref()),
+                Some(
+                    ImpactAnalysisState::NotAffected
+                        | ImpactAnalysisState::Resolved
+                        | ImpactAnalysisState::ResolvedWithPedigree
+                )
+            )
+        })
+}
+
+/// Returns the distinct names of the sources referenced by the vulnerabilities and their
+/// ratings, in order of first appearance
+fn vulnerability_sources(vulnerabilities: &[Vulnerability]) -> Vec<String> {
+    let mut sources: Vec<String> = Vec::new();
+
+    for vuln in vulnerabilities {
+        let rating_sources = vuln
+            .vulnerability_ratings
+            .iter()
+            .flat_map(|ratings| &ratings.0)
+            .filter_map(|rating| rating.vulnerability_source.as_ref());
+
+        for source in vuln.vulnerability_source.iter().chain(rating_sources) {
+            if let Some(name) = &source.name {
+                let name = name.to_string();
+                if !name.trim().is_empty() && !sources.contains(&name) {
+                    sources.push(name);
+                }
+            }
+        }
+    }
+
+    sources
+}
+
+/// Returns the name and version of the primary tool that generated the BOM.
+///
+/// The primary tool is the first named tool, or for the object form the first tool
+/// component, falling back to the first tool service.
+fn primary_tool(tools: &Tools) -> Option<String> {
+    let (name, version) = match tools {
+        Tools::List(tools_list) => tools_list
+            .iter()
+            .find_map(|tool| Some((tool.name.as_ref()?, tool.version.as_ref()))),
+        Tools::Object {
+            services,
+            components,
+        } => components
+            .iter()
+            .flat_map(|components| components.0.iter())
+            .map(|component| (&component.name, component.version.as_ref()))
+            .chain(
+                services
+                    .iter()
+                    .flat_map(|services| services.0.iter())
+                    .map(|service| (&service.name, service.version.as_ref())),
+            )
+            .next(),
+    }?;
+
+    Some(match version {
+        Some(version) => format!("{name} v{}", version.to_string().trim_start_matches('v')),
+        None => name.to_string(),
+    })
+}
+
+/// Returns the display names of all tools, with their version if known.
+///
+/// Tools of the legacy list form are listed by name only, tool components come before
+/// tool services in the object form.
+fn tool_names(tools: &Tools) -> Vec<String> {
+    let with_version = |name: String, version: Option<String>| match version {
+        Some(version) => format!("{name} (v{version})"),
+        None => name,
+    };
+
+    match tools {
+        Tools::List(tools_list) => tools_list
+            .iter()
+            .filter_map(|tool| tool.name.as_ref().map(|name| name.to_string()))
+            .collect(),
+        Tools::Object {
+            services,
+            components,
+        } => components
+            .iter()
+            .flat_map(|components| components.0.iter())
+            .map(|component| {
+                with_version(
+                    component.name.to_string(),
+                    component
+                        .version
+                        .as_ref()
+                        .map(|version| version.to_string()),
+                )
+            })
+            .chain(
+                services
+                    .iter()
+                    .flat_map(|services| services.0.iter())
+                    .map(|service| {
+                        with_version(
+                            service.name.to_string(),
+                            service.version.as_ref().map(|version| version.to_string()),
+                        )
+                    }),
+            )
+            .collect(),
+    }
+}
+
+/// Returns the components in render order: sorted by name (case-insensitive) and then
+/// version if `sort` is set, otherwise in BOM order
+fn ordered_components(components: &[Component], sort: bool) -> Vec<&Component> {
+    let mut ordered: Vec<&Component> = components.iter().collect();
+    if sort {
+        ordered.sort_by_cached_key(|component| {
+            (
+                component.name.to_string().to_lowercase(),
+                component
+                    .version
+                    .as_ref()
+                    .map(|version| version.to_string()),
+            )
+        });
+    }
+    ordered
+}
+
+/// Groups components under the ecosystem of their purl, e.g. `cargo` or `npm`, in
+/// alphabetical order of the ecosystems. Components keep their order within a group.
+/// Components without a parseable purl are collected in a trailing `unknown` group.
+fn group_components_by_ecosystem<'c>(
+    components: &[&'c Component],
+) -> Vec<(String, Vec<&'c Component>)> {
+    let mut groups: BTreeMap<String, Vec<&'c Component>> = BTreeMap::new();
+    let mut unknown = Vec::new();
+
+    for &component in components {
+        match component
+            .purl
+            .as_ref()
+            .and_then(|purl| purl_ecosystem(&purl.to_string()))
+        {
+            Some(ecosystem) => groups.entry(ecosystem).or_default().push(component),
+            None => unknown.push(component),
+        }
+    }
+
+    let mut grouped: Vec<(String, Vec<&Component>)> = groups.into_iter().collect();
+    if !unknown.is_empty() {
+        grouped.push(("unknown".to_string(), unknown));
+    }
+    grouped
+}
+
+/// Returns the name of the anchor at the entry of the component with the given `bom-ref`
+/// in the Components section, the target of its cross-reference links
+fn component_anchor(bom_ref: &str) -> String {
+    format!("component:{bom_ref}")
+}
+
+/// Numbers all components that have a `bom-ref`, including nested sub-components, in the
+/// order they are rendered in the Components section. `components` are the top-level
+/// components in that order, nested sub-components are ordered by [`ordered_components`].
+///
+/// Returns a map from `bom-ref` to the component number and display name.
+fn component_ref_index(components: &[&Component], sort: bool) -> HashMap<String, (usize, String)> {
+    fn visit(components: &[&Component], sort: bool, index: &mut HashMap<String, (usize, String)>) {
+        for &component in components {
+            if let Some(bom_ref) = &component.bom_ref {
+                let name = match &component.version {
+                    Some(version) => format!("{} {}", component_name(component), version),
+                    None => component_name(component),
+                };
+                let number = index.len() + 1;
+                index.entry(bom_ref.to_string()).or_insert((number, name));
+            }
+            if let Some(sub_components) = &component.components {
+                visit(&ordered_components(&sub_components.0, sort), sort, index);
+            }
+        }
+    }
+
+    let mut index = HashMap::new();
+    visit(components, sort, &mut index);
+    index
+}
+
+/// Returns the references of the components targeted by at least one vulnerability
+fn vulnerability_target_refs(vex: &Bom) -> HashSet<String> {
+    vex.vulnerabilities
+        .iter()
+        .flat_map(|vulns| &vulns.0)
+        .filter_map(|vuln| vuln.vulnerability_targets.as_ref())
+        .flat_map(|targets| &targets.0)
+        .map(|target| target.bom_ref.clone())
+        .collect()
+}
+
+/// Returns whether the component or one of its nested sub-components is in `targets`
+fn has_targeted_component(component: &Component, targets: &HashSet<String>) -> bool {
+    component
+        .bom_ref
+        .as_ref()
+        .is_some_and(|bom_ref| targets.contains(bom_ref))
+        || component.components.as_ref().is_some_and(|sub_components| {
+            sub_components
+                .0
+                .iter()
+                .any(|sub_component| has_targeted_component(sub_component, targets))
+        })
+}
+
+/// Counts the components including their nested sub-components that satisfy `include`
+fn count_components(components: &[Component], include: &dyn Fn(&Component) -> bool) -> usize {
+    components
+        .iter()
+        .map(|component| {
+            let nested = component.components.as_ref().map_or(0, |sub_components| {
+                count_components(&sub_components.0, include)
+            });
+            usize::from(include(component)) + nested
+        })
+        .sum()
+}
+
+/// Headings of the severity groups, indexed by [`severity_rank`]
+const SEVERITY_GROUP_TITLES: [&str; 6] = [
+    "None or Unknown",
+    "Info",
+    "Low",
+    "Medium",
+    "High",
+    "Critical",
+];
+
+/// Groups vulnerabilities under their highest severity as returned by `severity`, most
+/// severe first.
+///
+/// Vulnerabilities without a severity are collected in a trailing `Unrated` group. Empty
+/// groups are left out.
+fn group_vulns_by_severity<'v>(
+    vulns: &[&'v Vulnerability],
+    severity: impl Fn(&Vulnerability) -> Option<Severity>,
+) -> Vec<(String, Vec<&'v Vulnerability>)> {
+    let mut groups: BTreeMap<Reverse<u8>, Vec<&'v Vulnerability>> = BTreeMap::new();
+    let mut unrated = Vec::new();
+
+    for &vuln in vulns {
+        match severity(vuln) {
+            Some(severity) => groups
+                .entry(Reverse(severity_rank(&severity)))
+                .or_default()
+                .push(vuln),
+            None => unrated.push(vuln),
+        }
+    }
+
+    let mut grouped: Vec<(String, Vec<&Vulnerability>)> = groups
+        .into_iter()
+        .map(|(Reverse(rank), vulns)| (SEVERITY_GROUP_TITLES[usize::from(rank)].to_string(), vulns))
+        .collect();
+    if !unrated.is_empty() {
+        grouped.push(("Unrated".to_string(), unrated));
+    }
+    grouped
+}
+
+/// Groups vulnerabilities under the components their targets refer to, in the order the
+/// components are numbered by [`component_ref_index`].
+///
+/// A vulnerability affecting several components is listed under each of them. Vulnerabilities
+/// without a target that resolves to a component are collected in a trailing `Unassigned` group.
+fn group_vulns_by_component<'v>(
+    vulns: &[&'v Vulnerability],
+    component_refs: &HashMap<String, (usize, String)>,
+) -> Vec<(String, Vec<&'v Vulnerability>)> {
+    let mut groups: BTreeMap<usize, (String, Vec<&'v Vulnerability>)> = BTreeMap::new();
+    let mut unassigned = Vec::new();
+
+    for &vuln in vulns {
+        let mut assigned = false;
+        for target in vuln
+            .vulnerability_targets
+            .iter()
+            .flat_map(|targets| &targets.0)
+        {
+            if let Some((number, name)) = component_refs.get(&target.bom_ref) {
+                let (_, group) = groups
+                    .entry(*number)
+                    .or_insert_with(|| (name.clone(), Vec::new()));
+                // targets may list the same component more than once, e.g. for several versions
+                if !group.iter().any(|listed| std::ptr::eq(*listed, vuln)) {
+                    group.push(vuln);
+                }
+                assigned = true;
+            }
+        }
+        if !assigned {
+            unassigned.push(vuln);
+        }
+    }
+
+    let mut grouped: Vec<(String, Vec<&Vulnerability>)> = groups.into_values().collect();
+    if !unassigned.is_empty() {
+        grouped.push(("Unassigned".to_string(), unassigned));
+    }
+    grouped
+}
+
+/// An annotation of the document as rendered in the Annotations section
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct AnnotationEntry {
+    text: String,
+    subjects: Vec<String>,
+    annotator: Option<String>,
+    timestamp: Option<String>,
+}
+
+/// Returns the CycloneDX 1.5 JSON form of the document, `None` if it cannot be serialized
+fn document_json(vex: &Bom) -> Option<serde_json::Value> {
+    let mut output = Vec::<u8>::new();
+    vex.clone().output_as_json_v1_5(&mut output).ok()?;
+
+    serde_json::from_slice(&output).ok()
+}
+
+/// Returns the notice rendered when the compositions of the document declare that its
+/// vulnerability data is not complete, e.g. "Note: vulnerability data is marked incomplete".
+///
+/// Compositions are read from the JSON form of the document. Documents without a
+/// composition listing vulnerabilities get no notice.
+fn vulnerability_completeness_notice(vex: &Bom) -> Option<String> {
+    document_json(vex)
+        .and_then(|json| vulnerability_aggregate_from_json(&json))
+        .map(|aggregate| {
+            format!(
+                "Note: vulnerability data is marked {}",
+                aggregate.replace('_', " ")
+            )
+        })
+}
+
+/// Returns the first aggregate other than `complete` of the compositions of a CycloneDX
+/// JSON document that list vulnerabilities
+fn vulnerability_aggregate_from_json(json: &serde_json::Value) -> Option<String> {
+    json_array(&json["compositions"])
+        .iter()
+        .filter(|composition| !json_array(&composition["vulnerabilities"]).is_empty())
+        .filter_map(|composition| composition["aggregate"].as_str())
+        .find(|aggregate| *aggregate != "complete")
+        .map(str::to_string)
+}
+
+/// Reads the annotations of the document.
+///
+/// The annotations are read from the JSON form of the document since the model keeps
+/// the `bom-ref` values of annotation subjects private.
+fn document_annotations(vex: &Bom) -> Vec<AnnotationEntry> {
+    document_json(vex)
+        .map(|json| annotations_from_json(&json))
+        .unwrap_or_default()
+}
+
+/// Extracts the annotations from a CycloneDX JSON document
+fn annotations_from_json(json: &serde_json::Value) -> Vec<AnnotationEntry> {
+    json["annotations"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(|annotation| {
+            let annotator = &annotation["annotator"];
+            let annotator_name = ["organization", "individual", "component", "service"]
+                .iter()
+                .find_map(|kind| annotator[kind]["name"].as_str())
+                .map(str::to_string);
+
+            AnnotationEntry {
+                text: annotation["text"].as_str().unwrap_or_default().to_string(),
+                subjects: annotation["subjects"]
+                    .as_array()
+                    .map(|subjects| {
+                        subjects
+                            .iter()
+                            .filter_map(|subject| subject.as_str().map(str::to_string))
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+                annotator: annotator_name,
+                timestamp: annotation["timestamp"].as_str().map(str::to_string),
+            }
+        })
+        .collect()
+}
+
+/// A service of the document as rendered in the Services section
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ServiceEntry {
+    name: String,
+    version: Option<String>,
+    /// Nesting level, 0 for top-level services
+    depth: usize,
+    data_flows: Vec<DataFlow>,
+}
+
+/// Data exchanged by a service
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct DataFlow {
+    /// Direction of the flow, e.g. `inbound` or `bi-directional`
+    flow: String,
+    /// Classification of the data, e.g. `PII`
+    classification: Option<String>,
+}
+
+/// Reads the services of the document, see [`services_from_json`]
+fn document_services(vex: &Bom) -> Vec<ServiceEntry> {
+    document_json(vex)
+        .map(|json| services_from_json(&json))
+        .unwrap_or_default()
+}
+
+/// Extracts the services of a CycloneDX JSON document with the direction and
+/// classification of the data they exchange. Nested services follow their parent.
+///
+/// Services are read from the JSON form like license texts, which serializes the data
+/// classifications of all spec versions the same way.
+fn services_from_json(json: &serde_json::Value) -> Vec<ServiceEntry> {
+    let mut services = Vec::new();
+    collect_services(json_array(&json["services"]), 0, &mut services);
+
+    services
+}
+
+/// Adds the given services and, one level deeper, their nested services
+fn collect_services(items: &[serde_json::Value], depth: usize, services: &mut Vec<ServiceEntry>) {
+    for item in items {
+        let name = item["name"]
+            .as_str()
+            .filter(|name| !name.trim().is_empty())
+            .unwrap_or(UNNAMED_COMPONENT);
+        let data_flows = json_array(&item["data"])
+            .iter()
+            .map(|data| DataFlow {
+                flow: data["flow"].as_str().unwrap_or("unknown").to_string(),
+                classification: data["classification"]
+                    .as_str()
+                    .filter(|classification| !classification.trim().is_empty())
+                    .map(str::to_string),
+            })
+            .collect();
+
+        services.push(ServiceEntry {
+            name: name.to_string(),
+            version: item["version"].as_str().map(str::to_string),
+            depth,
+            data_flows,
+        });
+        collect_services(json_array(&item["services"]), depth + 1, services);
+    }
+}
+
+/// A license text attached to components or services, as rendered in the License Texts
+/// appendix
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct LicenseText {
+    /// SPDX ID or name of the license
+    license: String,
+    /// Decoded license text
+    text: String,
+    /// Names of the components and services the text is attached to
+    used_by: Vec<String>,
+}
+
+/// Reads the license texts attached to the components and services of the document, see
+/// [`license_texts_from_json`].
+fn document_license_texts(vex: &Bom) -> Vec<LicenseText> {
+    document_json(vex)
+        .map(|json| license_texts_from_json(&json))
+        .unwrap_or_default()
+}
+
+/// Extracts the distinct license texts attached to the metadata component, the components
+/// and the services of a CycloneDX JSON document, including nested ones.
+///
+/// Base64 encoded texts are decoded. Identical texts are listed once with all the
+/// components and services using them.
+fn license_texts_from_json(json: &serde_json::Value) -> Vec<LicenseText> {
+    let mut texts = Vec::new();
+    collect_license_texts(
+        std::slice::from_ref(&json["metadata"]["component"]),
+        &mut texts,
+    );
+    for key in ["components", "services"] {
+        collect_license_texts(json_array(&json[key]), &mut texts);
+    }
+
+    texts
+}
+
+/// Returns the elements of a JSON array, an empty slice for any other value
+fn json_array(value: &serde_json::Value) -> &[serde_json::Value] {
+    value.as_array().map(Vec::as_slice).unwrap_or_default()
+}
+
+/// Adds the license texts of the given components or services and their children
+fn collect_license_texts(items: &[serde_json::Value], texts: &mut Vec<LicenseText>) {
+    for item in items {
+        let owner = item["name"].as_str().unwrap_or_default();
+
+        for choice in json_array(&item["licenses"]) {
+            let license = &choice["license"];
+            if let Some(content) = license["text"]["content"].as_str() {
+                let text = if license["text"]["encoding"].as_str() == Some("base64") {
+                    match decode_base64(content) {
+                        Some(bytes) => String::from_utf8_lossy(&bytes).into_owned(),
+                        None => {
+                            warn(format!(
+                                "license text of {owner} is not valid base64, rendering it as is"
+                            ));
+                            content.to_string()
+                        }
+                    }
+                } else {
+                    content.to_string()
+                };
+                let name = license["id"]
+                    .as_str()
+                    .or(license["name"].as_str())
+                    .unwrap_or("Unnamed license");
+
+                let index = match texts.iter().position(|existing| existing.text == text) {
+                    Some(index) => index,
+                    None => {
+                        texts.push(LicenseText {
+                            license: name.to_string(),
+                            text,
+                            used_by: Vec::new(),
+                        });
+                        texts.len() - 1
+                    }
+                };
+                let used_by = &mut texts[index].used_by;
+                if !owner.is_empty() && !used_by.iter().any(|used| used == owner) {
+                    used_by.push(owner.to_string());
+                }
+            }
+        }
+
+        collect_license_texts(json_array(&item["components"]), texts);
+        collect_license_texts(json_array(&item["services"]), texts);
+    }
+}
+
+/// Decodes standard base64, ignoring whitespace and padding. Returns `None` if the input
+/// contains other characters.
+fn decode_base64(encoded: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(encoded.len() * 3 / 4);
+    let mut buffer: u32 = 0;
+    let mut bits = 0;
+
+    for c in encoded
+        .bytes()
+        .filter(|c| !c.is_ascii_whitespace() && *c != b'=')
+    {
+        let value = match c {
+            b'A'..=b'Z' => c - b'A',
+            b'a'..=b'z' => c - b'a' + 26,
+            b'0'..=b'9' => c - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            _ => return None,
+        };
+        buffer = (buffer << 6) | u32::from(value);
+        bits += 6;
+
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+
+    Some(bytes)
+}
+
+/// Converts the date part of an RFC 3339 timestamp (`YYYY-MM-DD...`) into days since
+/// the Unix epoch. Returns `None` if the value does not start with a valid date.
+pub(crate) fn days_since_epoch(timestamp: &str) -> Option<i64> {
+    let mut parts = timestamp.get(..10)?.split('-');
+    let year: i64 = parts.next()?.parse().ok()?;
+    let month: i64 = parts.next()?.parse().ok()?;
+    let day: i64 = parts.next()?.parse().ok()?;
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+
+    // Civil date to day number, years are shifted to start in March so leap days come last
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    Some(era * 146_097 + day_of_era - 719_468)
+}
+
+/// Converts a `YYYY-MM-DD` date or a `YYYY-MM-DDTHH:MM:SSZ` UTC timestamp into seconds
+/// since the Unix epoch. Dates count from midnight UTC. Returns `None` for other values,
+/// including timestamps with an offset other than `Z`.
+pub(crate) fn seconds_since_epoch(timestamp: &str) -> Option<i64> {
+    let days = days_since_epoch(timestamp)?;
+    let second_of_day = match timestamp.get(10..)? {
+        "" => 0,
+        time => {
+            let mut parts = time.strip_prefix('T')?.strip_suffix('Z')?.split(':');
+            let hour: i64 = parts.next()?.parse().ok()?;
+            let minute: i64 = parts.next()?.parse().ok()?;
+            let second: i64 = parts.next()?.parse().ok()?;
+            if parts.next().is_some() || hour > 23 || minute > 59 || second > 59 {
+                return None;
+            }
+            hour * 3600 + minute * 60 + second
+        }
+    };
+
+    Some(days * 86_400 + second_of_day)
+}
+
+/// Formats seconds since the Unix epoch as an RFC 3339 UTC timestamp, e.g. for
+/// `SOURCE_DATE_EPOCH`. This is the inverse of [`days_since_epoch`] plus the time of day.
+pub(crate) fn timestamp_from_epoch(seconds: i64) -> String {
+    let days = seconds.div_euclid(86_400);
+    let second_of_day = seconds.rem_euclid(86_400);
+
+    // Day number to civil date, with years starting in March as in `days_since_epoch`
+    let shifted = days + 719_468;
+    let era = shifted.div_euclid(146_097);
+    let day_of_era = shifted - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        second_of_day / 3600,
+        second_of_day % 3600 / 60,
+        second_of_day % 60
+    )
+}
+
+/// Returns the number of days since a vulnerability was published, falling back to its
+/// creation date when no publication date is given
+fn vuln_age_days(vuln: &Vulnerability, today: i64) -> Option<i64> {
+    vuln.published
+        .as_ref()
+        .or(vuln.created.as_ref())
+        .and_then(|date| days_since_epoch(&date.to_string()))
+        .map(|day| today - day)
+}
+
+/// Returns whether a vulnerability falls into the date range given as days since the
+/// epoch. The publication date is used, falling back to the last update. Vulnerabilities
+/// without either date are always included.
+fn is_in_date_range(vuln: &Vulnerability, since: Option<i64>, until: Option<i64>) -> bool {
+    let day = vuln
+        .published
+        .as_ref()
+        .or(vuln.updated.as_ref())
+        .and_then(|date| days_since_epoch(&date.to_string()));
+
+    match day {
+        Some(day) => {
+            !since.is_some_and(|since| day < since) && !until.is_some_and(|until| day > until)
+        }
+        None => true,
+    }
+}
+
+/// Renders CycloneDX documents as PDF and text reports.
+///
+/// Rendering only reads the generator, so one generator can be shared across threads,
+/// e.g. in an `Arc`, and render reports concurrently. The fonts are read and parsed by
+/// the first report only, later reports still copy the font data, see
+/// [`Self::font_family`].
 pub struct PdfGenerator<'a> {
     title_style: Style,
     header_style: Style,
@@ -39,6 +1102,120 @@ pub struct PdfGenerator<'a> {
     show_novulns_msg: bool,
     /// Controls whether the components section is shown
     show_components: bool,
+    /// When set, only the vulnerability with this ID is rendered
+    only_vuln_id: Option<&'a str>,
+    /// Controls whether a thin border is drawn around every page
+    page_border: bool,
+    /// Background color filling every page, if any
+    page_background: Option<Color>,
+    /// Page count above which a warning is printed after rendering. 0 disables the check
+    max_pages: usize,
+    /// Text of the running header on every page but the first. Defaults to the report title
+    header_text: Option<&'a str>,
+    /// Disclaimer text rendered as the final section. Blank lines separate paragraphs
+    disclaimer: Option<&'a str>,
+    /// When set, only ratings with this score method are rendered if a vulnerability has any
+    preferred_method: Option<&'a str>,
+    /// Controls whether email addresses and `redact_patterns` are redacted in free text
+    redact: bool,
+    /// Additional patterns redacted in free text when `redact` is on
+    redact_patterns: &'a [String],
+    /// Vulnerabilities older than this many days are marked as aging
+    aging_days: Option<u64>,
+    /// Controls whether the primary tool and its version are shown below the title
+    show_generated_by: bool,
+    /// Controls whether vulnerabilities are rendered as a compact table instead of the detailed list
+    compact: bool,
+    /// Placeholder rendered for missing values
+    na_text: &'a str,
+    /// Controls whether components are numbered and referenced from the vulnerabilities affecting them
+    cross_refs: bool,
+    /// Controls whether components are sorted by name and version
+    sort_components: bool,
+    /// Controls whether `bom-ref` values of vulnerabilities and components are rendered
+    show_bom_refs: bool,
+    /// Cover page template rendered instead of the built-in title, see [`expand_cover_template`]
+    cover_template: Option<&'a str>,
+    /// Language code selecting locale specific formatting, e.g. the decimal separator
+    lang: Option<&'a str>,
+    /// Controls whether ratings are sorted by severity, most severe first
+    sort_ratings: bool,
+    /// Controls whether vulnerabilities are grouped by the components they affect
+    group_by_component: bool,
+    /// Previous document whose differences to the rendered one are listed
+    previous_bom: Option<&'a Bom>,
+    /// Number of retries for writing the report when it fails with a transient I/O error
+    io_retries: u32,
+    /// Whether the "No Vulnerabilities" message is framed and padded
+    novulns_framed: bool,
+    /// Earliest publication date (`YYYY-MM-DD`) of the rendered vulnerabilities
+    since: Option<&'a str>,
+    /// Latest publication date (`YYYY-MM-DD`) of the rendered vulnerabilities
+    until: Option<&'a str>,
+    /// Whether severities are prefixed with a symbol, see [`severity_icon`]
+    severity_icons: bool,
+    /// Number of front matter pages left unnumbered
+    page_number_offset: usize,
+    /// Whether the License Texts appendix is rendered
+    license_appendix: bool,
+    /// Whether the highest score of each vulnerability is drawn as a gauge
+    score_gauge: bool,
+    /// Whether the Components section only lists components targeted by a vulnerability
+    vuln_components_only: bool,
+    /// Whether the sub-scores of CVSS v3 vectors are shown below each rating
+    cvss_breakdown: bool,
+    /// Name of the issuing organization, shown right aligned above the running header text
+    org_name: Option<&'a str>,
+    /// Number of tools above which the tools list is moved to the Tools appendix. 0 disables the limit
+    tools_max: usize,
+    /// Whether analysis responses are rendered as colored badges, see [`response_color`]
+    response_badges: bool,
+    /// Whether time stamps and document IDs are fixed for reproducible output, see [`Self::report_time`]
+    deterministic: bool,
+    /// Creation date of deterministic output in seconds since the Unix epoch
+    source_date_epoch: Option<i64>,
+    /// Whether the supplier name and contacts are shown under each component
+    show_contacts: bool,
+    /// Whether the Data Completeness section is rendered
+    completeness_section: bool,
+    /// Columns of the components table, the components are listed if `None`
+    component_columns: Option<&'a [ComponentColumn]>,
+    /// Whether component purls are shown and linked to their registry pages
+    purl_links: bool,
+    /// Name of the tool credited in the PDF metadata, none if `None`
+    attribution: Option<&'a str>,
+    /// Controls whether the warnings noticed during conversion are rendered as a Conversion Notes section
+    show_notes: bool,
+    /// Date rendered below the report title, `now` for the date the report is stamped with
+    report_date: Option<&'a str>,
+    /// Controls whether vulnerabilities are grouped under headings of their highest severity
+    group_by_severity: bool,
+    /// Controls whether the serial number and spec version of the document are printed in the footer of every page
+    meta_footer: bool,
+    /// Score bands used to derive the severity of ratings that only carry a score
+    severity_bands: SeverityBands,
+    /// Whether components identical to the metadata component are left out of the Components section
+    dedup_metadata_component: bool,
+    /// Whether generated reports are compressed and stripped of unused objects after rendering
+    optimize: bool,
+    /// Whether the embedded fonts of generated reports only keep the glyphs drawn in them
+    subset_fonts: bool,
+    /// Controls whether components are grouped under headings of the ecosystems of their purls
+    group_by_ecosystem: bool,
+    /// Builds the entries of the detailed vulnerability list
+    vulnerability_renderer: &'a dyn VulnerabilityRenderer,
+    /// Vertical space in lines between sections and larger blocks of the report
+    section_spacing: f64,
+    /// Vertical space in lines between the items of a section
+    item_spacing: f64,
+    /// Controls whether a badge such as `High (8.1)` is shown next to each vulnerability ID
+    show_severity_badge: bool,
+    /// PDF version written to the header of the report, see `VEX2PDF_PDF_VERSION`
+    pdf_version: Option<&'a str>,
+    /// Classification marking printed at the top and bottom of every page, see `VEX2PDF_CLASSIFICATION`
+    classification: Option<&'a str>,
+    /// Fonts of the reports, loaded by the first report and copied for later ones
+    fonts: OnceLock<FontFamily<FontData>>,
 }
 
 impl Default for PdfGenerator<'_> {
@@ -83,7 +1260,7 @@ impl<'a> PdfGenerator<'a> {
     ///
     /// * `report_title` - The title displayed as the main heading on the first page of the report
     /// * `pdf_title` - The title displayed in the PDF reader window/tab when the document is opened
-    /// * `show_novulns_msg` - Whether the `No Vulnerabilities reported` message is shown when no vulnerabilities are available
+    /// * `show_novulns_msg` - Whether the `No vulnerabilities found` or `No vulnerability data` message is shown when no vulnerabilities are available
     /// * `show_components` - Whether the `components section` is shown
     ///
     /// # Returns
@@ -129,51 +1306,2242 @@ impl<'a> PdfGenerator<'a> {
             pdf_meta_name,
             show_novulns_msg,
             show_components,
+            only_vuln_id: None,
+            page_border: false,
+            page_background: None,
+            max_pages: Config::get_default_max_pages(),
+            header_text: None,
+            disclaimer: None,
+            preferred_method: None,
+            redact: false,
+            redact_patterns: &[],
+            aging_days: None,
+            show_generated_by: false,
+            compact: false,
+            na_text: Config::get_default_na_text(),
+            cross_refs: false,
+            sort_components: false,
+            show_bom_refs: false,
+            cover_template: None,
+            lang: None,
+            sort_ratings: false,
+            group_by_component: false,
+            previous_bom: None,
+            io_retries: 0,
+            novulns_framed: true,
+            since: None,
+            until: None,
+            severity_icons: false,
+            page_number_offset: 0,
+            license_appendix: false,
+            score_gauge: false,
+            vuln_components_only: false,
+            cvss_breakdown: false,
+            org_name: None,
+            tools_max: 0,
+            response_badges: false,
+            deterministic: false,
+            source_date_epoch: None,
+            show_contacts: false,
+            completeness_section: false,
+            component_columns: None,
+            purl_links: false,
+            attribution: Some(Config::get_default_attribution()),
+            show_notes: false,
+            report_date: None,
+            group_by_severity: false,
+            meta_footer: false,
+            severity_bands: SeverityBands::default(),
+            dedup_metadata_component: false,
+            optimize: false,
+            subset_fonts: true,
+            group_by_ecosystem: false,
+            vulnerability_renderer: &DefaultVulnerabilityRenderer,
+            section_spacing: Config::get_default_section_spacing(),
+            item_spacing: Config::get_default_item_spacing(),
+            show_severity_badge: false,
+            pdf_version: None,
+            classification: None,
+            fonts: OnceLock::new(),
         }
     }
 
-    /// Gets the default title for the pdf metadata
-    fn get_default_pdf_meta_name() -> &'static str {
-        "VEX Vulnerability Report"
-    }
-
-    /// Gets the default title of the report which shows on the first page
-    fn get_default_report_title() -> &'static str {
-        "Vulnerability Report Document"
-    }
-
-    /// Generates a PDF report from a CycloneDX VEX document.
+    /// Creates a new PDF generator from a [`Config`].
     ///
-    /// # Arguments
+    /// In addition to the options accepted by [`PdfGenerator::new`] this picks up
+    /// all further rendering options carried by the configuration.
     ///
-    /// * `vex` - The CycloneDX VEX document to convert
-    /// * `output_path` - Path where the PDF report will be saved
+    /// # Examples
     ///
-    /// # Returns
+    /// ```rust
+    /// use vex2pdf::lib_utils::config::Config;
+    /// use vex2pdf::pdf::generator::PdfGenerator;
     ///
-    /// Result indicating success or an error with details
-    pub fn generate_pdf<P: AsRef<Path>>(&self, vex: &Bom, output_path: P) -> Result<(), io::Error> {
-        // Set up the document with default fonts
-
-        let document_title = self
-            .report_title
-            .unwrap_or(Self::get_default_report_title());
-        let pdf_title = self
-            .pdf_meta_name
-            .unwrap_or(Self::get_default_pdf_meta_name());
+    /// let config = Config::default();
+    /// let generator = PdfGenerator::from_config(&config);
+    /// ```
+    pub fn from_config(config: &'a Config) -> Self {
+        let mut generator = Self::new(
+            config.report_title.as_deref(),
+            config.pdf_meta_name.as_deref(),
+            config.show_novulns_msg,
+            config.show_components,
+        );
+        generator.only_vuln_id = config.only_vuln_id.as_deref();
+        generator.page_border = config.page_border;
+        generator.page_background = config.page_background;
+        generator.max_pages = config.max_pages;
+        generator.header_text = config.header_text.as_deref();
+        generator.disclaimer = config.disclaimer.as_deref();
+        generator.preferred_method = config.preferred_method.as_deref();
+        generator.redact = config.redact;
+        generator.redact_patterns = &config.redact_patterns;
+        generator.aging_days = config.aging_days;
+        generator.show_generated_by = config.show_generated_by;
+        generator.compact = config.compact;
+        generator.na_text = &config.na_text;
+        generator.cross_refs = config.cross_refs;
+        generator.sort_components = config.sort_components;
+        generator.show_bom_refs = config.show_bom_refs;
+        generator.cover_template = config.cover_template.as_deref();
+        generator.lang = config.lang.as_deref();
+        generator.sort_ratings = config.sort_ratings;
+        generator.group_by_component = config.group_by_component;
+        generator.previous_bom = config.previous_bom.as_ref();
+        generator.io_retries = config.io_retries;
+        generator.novulns_framed = config.novulns_framed;
+        generator.since = config.since.as_deref();
+        generator.until = config.until.as_deref();
+        generator.severity_icons = config.severity_icons;
+        generator.page_number_offset = config.page_number_offset;
+        generator.license_appendix = config.license_appendix;
+        generator.score_gauge = config.score_gauge;
+        generator.vuln_components_only = config.vuln_components_only;
+        generator.cvss_breakdown = config.cvss_breakdown;
+        generator.org_name = config.org_name.as_deref();
+        generator.tools_max = config.tools_max;
+        generator.response_badges = config.response_badges;
+        generator.deterministic = config.deterministic;
+        generator.source_date_epoch = config.source_date_epoch;
+        generator.show_contacts = config.show_contacts;
+        generator.completeness_section = config.completeness_section;
+        generator.component_columns = config.component_columns.as_deref();
+        generator.purl_links = config.purl_links;
+        generator.attribution = config.attribution.as_deref();
+        generator.show_notes = config.show_notes;
+        generator.report_date = config.report_date.as_deref();
+        generator.group_by_severity = config.group_by_severity;
+        generator.meta_footer = config.meta_footer;
+        generator.severity_bands = config.severity_bands;
+        generator.dedup_metadata_component = config.dedup_metadata_component;
+        generator.optimize = config.optimize;
+        generator.subset_fonts = config.subset_fonts;
+        generator.group_by_ecosystem = config.group_by_ecosystem;
+        generator.section_spacing = config.section_spacing;
+        generator.item_spacing = config.item_spacing;
+        generator.show_severity_badge = config.show_severity_badge;
+        generator.pdf_version = config.pdf_version.as_deref();
+        generator.classification = config.classification.as_deref();
 
-        let mut doc = Document::new(FontsDir::build().font_family);
+        generator
+    }
+
+    /// Renders the entries of the detailed vulnerability list with `renderer` instead of
+    /// the built-in layout, see [`VulnerabilityRenderer`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vex2pdf::pdf::generator::PdfGenerator;
+    /// use vex2pdf::pdf::vulnerability_renderer::DefaultVulnerabilityRenderer;
+    ///
+    /// let generator = PdfGenerator::default().with_vulnerability_renderer(&DefaultVulnerabilityRenderer);
+    /// ```
+    pub fn with_vulnerability_renderer(mut self, renderer: &'a dyn VulnerabilityRenderer) -> Self {
+        self.vulnerability_renderer = renderer;
+        self
+    }
+
+    /// Vertical space between sections and larger blocks, see `VEX2PDF_SECTION_SPACING`
+    fn section_break(&self) -> genpdf::elements::Break {
+        genpdf::elements::Break::new(self.section_spacing)
+    }
+
+    /// Vertical space between the items of a section, see `VEX2PDF_ITEM_SPACING`
+    fn item_break(&self) -> genpdf::elements::Break {
+        genpdf::elements::Break::new(self.item_spacing)
+    }
+
+    /// Style of regular text in the report
+    pub(crate) fn normal_style(&self) -> Style {
+        self.normal_style
+    }
+
+    /// Style of secondary, indented text in the report
+    pub(crate) fn indent_style(&self) -> Style {
+        self.indent_style
+    }
+
+    /// Gets the default title for the pdf metadata
+    fn get_default_pdf_meta_name() -> &'static str {
+        "VEX Vulnerability Report"
+    }
+
+    /// Gets the default title of the report which shows on the first page
+    fn get_default_report_title() -> &'static str {
+        "Vulnerability Report Document"
+    }
+
+    /// Returns whether a vulnerability passes the `only_vuln_id` filter
+    fn is_vuln_selected(&self, vuln: &Vulnerability) -> bool {
+        match self.only_vuln_id {
+            Some(wanted_id) => vuln
+                .id
+                .as_ref()
+                .is_some_and(|id| id.to_string() == wanted_id),
+            None => true,
+        }
+    }
+
+    /// Returns whether a vulnerability passes the `since` and `until` date filters
+    fn is_vuln_in_date_range(&self, vuln: &Vulnerability) -> bool {
+        is_in_date_range(
+            vuln,
+            self.since.and_then(days_since_epoch),
+            self.until.and_then(days_since_epoch),
+        )
+    }
+
+    /// Describes the `since` and `until` date filters, e.g. `from 2025-06-01 to 2025-06-30`,
+    /// `None` if no filter is set
+    fn date_range_text(&self) -> Option<String> {
+        match (self.since, self.until) {
+            (Some(since), Some(until)) => Some(format!("from {since} to {until}")),
+            (Some(since), None) => Some(format!("since {since}")),
+            (None, Some(until)) => Some(format!("until {until}")),
+            (None, None) => None,
+        }
+    }
+
+    /// Returns the text with sensitive values redacted if redaction is enabled
+    fn redacted(&self, text: &str) -> String {
+        if self.redact {
+            redact_text(text, self.redact_patterns)
+        } else {
+            text.to_string()
+        }
+    }
+
+    /// Prefixes the severity text with the symbol of the severity if severity icons are
+    /// enabled
+    fn with_severity_icon(&self, severity: Option<&Severity>, text: String) -> String {
+        match severity.and_then(severity_icon) {
+            Some(icon) if self.severity_icons => format!("{icon} {text}"),
+            _ => text,
+        }
+    }
+
+    /// Builds the compact vulnerability table with one row per vulnerability.
+    ///
+    /// Each row shows the ID, the highest severity of the rendered ratings and the
+    /// analysis state.
+    fn compact_vuln_table(&self, vulns: &[&Vulnerability]) -> TableLayout {
+        let mut table = TableLayout::new(vec![3, 2, 2]);
+        table.set_cell_decorator(FrameCellDecorator::new(true, true, false));
+
+        let cell =
+            |text: &str, style: Style| Paragraph::default().styled_string(text, style).padded(1);
+        let header_style = self.indent_style.bold();
+        table
+            .row()
+            .element(cell("ID", header_style))
+            .element(cell("Max Severity", header_style))
+            .element(cell("State", header_style))
+            .push()
+            .expect("compact table rows have three columns");
+
+        for vuln in vulns {
+            let id = vuln
+                .id
+                .as_ref()
+                .map_or_else(|| self.na_text.to_string(), |id| id.to_string());
+            let severity = self.max_severity(vuln).map_or_else(
+                || self.na_text.to_string(),
+                |severity| self.with_severity_icon(Some(&severity), severity.to_string()),
+            );
+            let state = vuln
+                .vulnerability_analysis
+                .as_ref()
+                .and_then(|analysis| analysis.state.as_ref())
+                .map_or_else(|| self.na_text.to_string(), fmt_analysis_state);
+
+            table
+                .row()
+                .element(cell(&id, self.indent_style))
+                .element(cell(&severity, self.indent_style))
+                .element(cell(&state, self.indent_style))
+                .push()
+                .expect("compact table rows have three columns");
+        }
+
+        table
+    }
+
+    /// Describes a rating as rendered after "Severity: ", e.g. `high (CVSSv31 — Source: NVD)`.
+    ///
+    /// Returns `None` for ratings carrying neither a severity, a score, a method nor a
+    /// source. Ratings that only name their source are rendered with placeholders, so the
+    /// source stays attributed to its own rating.
+    fn rating_text(&self, rating: &VulnerabilityRating) -> Option<String> {
+        let rating_method = if let Some(method) = &rating.score_method {
+            method.to_string()
+        } else {
+            self.na_text.to_string()
+        };
+
+        let source_str: Option<String> = rating.vulnerability_source.as_ref().and_then(|source| {
+            source
+                .name
+                .as_ref()
+                .map(|source_name| source_name.to_string())
+        });
+
+        // Scanners may emit a score without a qualitative severity;
+        // derive the severity band from the score in that case
+        let severity = match (&rating.severity, &rating.score) {
+            (Some(severity), _) => severity.to_string(),
+            (None, Some(score)) => format!(
+                "{}, score {}",
+                self.severity_bands.severity(score.to_f32()),
+                format_score(score.to_f32(), self.lang)
+            ),
+            (None, None) if rating.score_method.is_some() || source_str.is_some() => {
+                self.na_text.to_string()
+            }
+            (None, None) => return None,
+        };
+        let severity = self.with_severity_icon(
+            self.severity_bands.rating_severity(rating).as_ref(),
+            severity,
+        );
+
+        Some(match source_str {
+            Some(source_name) => format!("{severity} ({rating_method} — Source: {source_name})"),
+            None => format!("{severity} ({rating_method})"),
+        })
+    }
+
+    /// Describes the scores computed from the CVSS v3 vector of a rating, e.g.
+    /// `Base 9.8, Temporal 8.8 (CVSS:3.1/AV:N/...)`.
+    ///
+    /// Returns `None` unless `VEX2PDF_CVSS_BREAKDOWN` is enabled and the rating has a
+    /// valid CVSS v3 vector.
+    fn cvss_breakdown_text(&self, rating: &VulnerabilityRating) -> Option<String> {
+        if !self.cvss_breakdown {
+            return None;
+        }
+        let vector = rating.vector.as_ref()?.to_string();
+        let scores = cvss3_scores(&vector)?;
+
+        let mut parts = vec![format!(
+            "Base {}",
+            format_score(scores.base as f32, self.lang)
+        )];
+        if let Some(temporal) = scores.temporal {
+            parts.push(format!(
+                "Temporal {}",
+                format_score(temporal as f32, self.lang)
+            ));
+        }
+        if let Some(environmental) = scores.environmental {
+            parts.push(format!(
+                "Environmental {}",
+                format_score(environmental as f32, self.lang)
+            ));
+        }
+
+        Some(format!("{} ({vector})", parts.join(", ")))
+    }
+
+    /// Returns the time the report is stamped with as RFC 3339 timestamp.
+    ///
+    /// This is the current time unless deterministic output is requested, in which case
+    /// `SOURCE_DATE_EPOCH` is used, falling back to the BOM timestamp and then the epoch.
+    fn report_time(&self, vex: &Bom) -> Option<String> {
+        if !self.deterministic {
+            return DateTime::now().ok().map(|now| now.to_string());
+        }
+
+        Some(match self.source_date_epoch {
+            Some(seconds) => timestamp_from_epoch(seconds),
+            None => vex
+                .metadata
+                .as_ref()
+                .and_then(|metadata| metadata.timestamp.as_ref())
+                .map_or_else(
+                    || timestamp_from_epoch(0),
+                    |timestamp| timestamp.to_string(),
+                ),
+        })
+    }
+
+    /// Returns the date rendered below the report title, `None` unless a report date is
+    /// configured. `now` resolves to the date the report is stamped with, see
+    /// [`Self::report_time`].
+    fn report_date_text(&self, vex: &Bom) -> Option<String> {
+        match self.report_date? {
+            "now" => self
+                .report_time(vex)
+                .map(|time| time.chars().take(10).collect()),
+            date => Some(date.to_string()),
+        }
+    }
+
+    /// Returns the highest severity of the rendered ratings of a vulnerability, see
+    /// [`Self::select_ratings`]
+    fn max_severity(&self, vuln: &Vulnerability) -> Option<Severity> {
+        vuln.vulnerability_ratings.as_ref().and_then(|ratings| {
+            self.select_ratings(&ratings.0)
+                .into_iter()
+                .filter_map(|rating| self.severity_bands.rating_severity(rating))
+                .max_by_key(severity_rank)
+        })
+    }
+
+    /// Returns the fonts of a report. They are read and parsed once per generator, see
+    /// [`FontsDir::build`].
+    ///
+    /// genpdf takes the fonts of a document by value and keeps the bytes of each font for
+    /// embedding, so every report gets its own copy of the four font files. Only the parsed
+    /// glyph data is shared between reports.
+    fn font_family(&self) -> FontFamily<FontData> {
+        self.fonts
+            .get_or_init(|| FontsDir::build().font_family)
+            .clone()
+    }
+
+    /// Returns the highest score of the rendered ratings of a vulnerability, see
+    /// [`Self::select_ratings`]
+    fn max_score(&self, vuln: &Vulnerability) -> Option<f32> {
+        vuln.vulnerability_ratings
+            .as_ref()
+            .into_iter()
+            .flat_map(|ratings| self.select_ratings(&ratings.0))
+            .filter_map(|rating| rating.score.as_ref().map(|score| score.to_f32()))
+            .reduce(f32::max)
+    }
+
+    /// Returns the label and color of the severity badge of a vulnerability, e.g.
+    /// `High (8.1)` in the color of high severities. The score is left out if no rendered
+    /// rating has one. Returns `None` if no rendered rating has a severity or a score.
+    fn severity_badge(&self, vuln: &Vulnerability) -> Option<(String, Color)> {
+        let severity = self.max_severity(vuln)?;
+        let title = SEVERITY_GROUP_TITLES[usize::from(severity_rank(&severity))];
+        let label = match self.max_score(vuln) {
+            Some(score) => format!("{title} ({})", format_score(score, self.lang)),
+            None => title.to_string(),
+        };
+
+        Some((label, severity_color(&severity)))
+    }
+
+    /// Returns the vulnerabilities passing the `only_vuln_id` and date range filters
+    fn selected_vulns<'v>(&self, vulnerabilities: &'v [Vulnerability]) -> Vec<&'v Vulnerability> {
+        vulnerabilities
+            .iter()
+            .filter(|vuln| self.is_vuln_selected(vuln) && self.is_vuln_in_date_range(vuln))
+            .collect()
+    }
+
+    /// Returns the ratings of a vulnerability that should be rendered.
+    ///
+    /// If a preferred score method is configured and the vulnerability has ratings with
+    /// that method, only those are returned. Otherwise all ratings are returned. Repeated
+    /// ratings are returned once, see [`dedup_ratings`].
+    fn select_ratings<'r>(
+        &self,
+        ratings: &'r [VulnerabilityRating],
+    ) -> Vec<&'r VulnerabilityRating> {
+        if let Some(preferred) = self.preferred_method {
+            let preferred_ratings: Vec<&VulnerabilityRating> = ratings
+                .iter()
+                .filter(|rating| {
+                    rating
+                        .score_method
+                        .as_ref()
+                        .is_some_and(|method| method.to_string().eq_ignore_ascii_case(preferred))
+                })
+                .collect();
+
+            if !preferred_ratings.is_empty() {
+                return self.ordered_ratings(preferred_ratings);
+            }
+        }
+
+        self.ordered_ratings(ratings.iter().collect())
+    }
+
+    /// Drops repeated ratings and sorts the rest by severity, most severe first, if sorting
+    /// is enabled
+    fn ordered_ratings<'r>(
+        &self,
+        mut ratings: Vec<&'r VulnerabilityRating>,
+    ) -> Vec<&'r VulnerabilityRating> {
+        dedup_ratings(&mut ratings);
+        if self.sort_ratings {
+            sort_ratings_by_severity(&mut ratings, &self.severity_bands);
+        }
+
+        ratings
+    }
+
+    /// Builds the element rendering a single vulnerability in the detailed list.
+    ///
+    /// `today` is the current date as days since the epoch, used to flag aging
+    /// vulnerabilities when `aging_days` is set.
+    pub(crate) fn vulnerability_element(
+        &self,
+        vuln: &Vulnerability,
+        component_refs: &HashMap<String, (usize, String)>,
+        today: Option<i64>,
+    ) -> LinearLayout {
+        let aging_style = self.normal_style.bold().with_color(Color::Rgb(200, 80, 0));
+
+        let mut vuln_layout = genpdf::elements::LinearLayout::vertical();
+
+        let id_paragraph = if let Some(vuln_id) = &vuln.id {
+            Paragraph::default()
+                .styled_string("ID: ", self.normal_style)
+                .styled_string(format!("{}", vuln_id), self.normal_style)
+        } else {
+            Paragraph::default()
+                .styled_string("ID: ", self.normal_style)
+                .styled_string(self.na_text, self.normal_style)
+        };
+
+        // The badge sits in a borderless second column next to the ID
+        let badge = if self.show_severity_badge {
+            self.severity_badge(vuln)
+        } else {
+            None
+        };
+        match badge {
+            Some(badge) => {
+                let mut id_row = TableLayout::new(vec![3, 1]);
+                id_row
+                    .row()
+                    .element(id_paragraph)
+                    .element(Badges::new(vec![badge]).styled(self.normal_style))
+                    .push()
+                    .expect("vulnerability ID rows have two columns");
+                vuln_layout.push(id_row);
+            }
+            None => vuln_layout.push(id_paragraph),
+        }
+
+        if let Some(bom_ref) = vuln.bom_ref.as_ref().filter(|_| self.show_bom_refs) {
+            vuln_layout.push(
+                Paragraph::default().styled_string(format!("Ref: {bom_ref}"), self.indent_style),
+            );
+        }
+
+        if let (Some(threshold), Some(today)) = (self.aging_days, today) {
+            if let Some(age) = vuln_age_days(vuln, today).filter(|age| *age > threshold as i64) {
+                vuln_layout.push(Paragraph::default().styled_string(
+                    format!("AGING: published {age} days ago, over the {threshold} day threshold"),
+                    aging_style,
+                ));
+            }
+        }
+
+        if self.cross_refs {
+            if let Some(targets) = &vuln.vulnerability_targets {
+                if !targets.0.is_empty() {
+                    vuln_layout.push(
+                        Paragraph::default().styled_string("Affects:", self.indent_style.bold()),
+                    );
+                }
+                // Numbered components link to their entry in the Components section
+                for target in &targets.0 {
+                    match component_refs.get(&target.bom_ref) {
+                        Some((number, name)) => vuln_layout.push(LinkedText::internal(
+                            format!("[C{number}] {name}"),
+                            component_anchor(&target.bom_ref),
+                            self.indent_style.with_color(LINK_COLOR),
+                        )),
+                        None => vuln_layout.push(
+                            Paragraph::default()
+                                .styled_string(target.bom_ref.to_string(), self.indent_style),
+                        ),
+                    }
+                }
+            }
+        }
+
+        let desc_paragraph = if let Some(desc) = &vuln.description {
+            Paragraph::default()
+                .styled_string("Description: ", self.indent_style.bold())
+                .styled_string(self.redacted(desc), self.indent_style)
+        } else {
+            Paragraph::default()
+                .styled_string("Description: ", self.indent_style.bold())
+                .styled_string(self.na_text, self.indent_style)
+        };
+
+        vuln_layout.push(desc_paragraph);
+        vuln_layout.push(self.item_break());
+
+        // --- Analysis (CycloneDX 'analysis' / Rust: vulnerability_analysis) ---
+        if let Some(analysis) = &vuln.vulnerability_analysis {
+            // Überschrift
+            vuln_layout
+                .push(Paragraph::default().styled_string("Analysis:", self.indent_style.bold()));
+
+            // state
+            if let Some(state) = analysis.state.as_ref() {
+                vuln_layout.push(
+                    Paragraph::default()
+                        .styled_string("  state: ", self.indent_style.bold())
+                        .styled_string(fmt_analysis_state(state), self.indent_style),
+                );
+            }
+
+            // detail
+            if let Some(detail) = analysis.detail.as_deref() {
+                if !detail.is_empty() {
+                    vuln_layout.push(
+                        Paragraph::default()
+                            .styled_string("  detail: ", self.indent_style.bold())
+                            .styled_string(self.redacted(detail), self.indent_style),
+                    );
+                }
+            }
+
+            if let Some(just) = analysis.justification.as_ref() {
+                let txt = fmt_justification(just);
+                vuln_layout.push(
+                    Paragraph::default()
+                        .styled_string("  justification: ", self.indent_style.bold())
+                        .styled_string(txt, self.indent_style),
+                );
+            }
+            if let Some(responses) = analysis.responses.as_ref().filter(|rs| !rs.is_empty()) {
+                if self.response_badges {
+                    vuln_layout.push(
+                        Paragraph::default()
+                            .styled_string("  responses:", self.indent_style.bold()),
+                    );
+                    vuln_layout.push(
+                        Badges::new(
+                            responses
+                                .iter()
+                                .map(|response| (response.to_string(), response_color(response)))
+                                .collect(),
+                        )
+                        .styled(self.indent_style)
+                        .padded(genpdf::Margins::trbl(0.5, 0, 0.5, 3)),
+                    );
+                } else {
+                    vuln_layout.push(
+                        Paragraph::default()
+                            .styled_string("  responses: ", self.indent_style.bold())
+                            .styled_string(responses_text(responses), self.indent_style),
+                    );
+                }
+            }
+
+            vuln_layout.push(self.item_break());
+        }
+
+        let mut ratings_list = genpdf::elements::UnorderedList::new();
+
+        if let Some(ratings) = &vuln.vulnerability_ratings {
+            for rating in self.select_ratings(&ratings.0) {
+                if let Some(text) = self.rating_text(rating) {
+                    let mut rating_layout = LinearLayout::vertical();
+                    rating_layout.push(
+                        Paragraph::default()
+                            .styled_string("Severity: ", self.indent_style.bold())
+                            .styled_string(text, self.indent_style),
+                    );
+                    if let Some(breakdown) = self.cvss_breakdown_text(rating) {
+                        rating_layout.push(
+                            Paragraph::default()
+                                .styled_string("Scores: ", self.indent_style.bold())
+                                .styled_string(breakdown, self.indent_style),
+                        );
+                    }
+                    ratings_list.push(rating_layout);
+                }
+            }
+        }
+        vuln_layout.push(ratings_list);
+
+        if self.score_gauge {
+            if let Some(max_score) = self.max_score(vuln) {
+                vuln_layout.push(self.item_break());
+                vuln_layout.push(Paragraph::default().styled_string(
+                    format!(
+                        "Max score: {} / {}",
+                        format_score(max_score, self.lang),
+                        MAX_SCORE
+                    ),
+                    self.indent_style.bold(),
+                ));
+                vuln_layout.push(
+                    ScoreGauge::new(
+                        f64::from(max_score),
+                        severity_color(&self.severity_bands.severity(max_score)),
+                    )
+                    .padded(genpdf::Margins::trbl(1, 0, 0, 5)),
+                );
+            }
+        }
+        vuln_layout.push(self.section_break());
+
+        vuln_layout
+    }
+
+    /// Builds the element rendering a component and, recursively, its sub-components
+    /// as a nested list to preserve the hierarchy of the BOM.
+    ///
+    /// If `targets` is set, only sub-components that are or contain one of the targeted
+    /// components are included.
+    fn component_element(
+        &self,
+        component: &Component,
+        component_refs: &HashMap<String, (usize, String)>,
+        targets: Option<&HashSet<String>>,
+    ) -> LinearLayout {
+        let mut layout = LinearLayout::vertical();
+
+        // Prefix the number vulnerabilities use to refer to this component, if any
+        let numbered = component
+            .bom_ref
+            .as_ref()
+            .and_then(|bom_ref| Some((bom_ref, component_refs.get(bom_ref)?)));
+        let label = numbered
+            .map(|(_, (number, _))| format!("[C{number}] "))
+            .unwrap_or_default();
+
+        let name = Paragraph::default().styled_string(
+            format!("{label}Name: {}", component_name(component)),
+            self.normal_style,
+        );
+        match numbered {
+            // The Affects lines of vulnerabilities link here
+            Some((bom_ref, _)) => layout.push(Anchored::new(name, component_anchor(bom_ref))),
+            None => layout.push(name),
+        }
+
+        if let Some(version) = &component.version {
+            layout.push(
+                Paragraph::default()
+                    .styled_string(format!("Version: {}", version), self.indent_style),
+            );
+        }
+
+        // Components altered from their upstream original warrant extra scrutiny
+        if component.modified == Some(true) {
+            layout.push(
+                Paragraph::default().styled_string("Modified: yes", self.indent_style.bold()),
+            );
+        }
+
+        // Pedigree notes explain how the component was derived from its ancestors
+        if let Some(notes) = component
+            .pedigree
+            .as_ref()
+            .and_then(|pedigree| pedigree.notes.as_ref())
+        {
+            layout.push(
+                Paragraph::default()
+                    .styled_string("Notes: ", self.indent_style.bold())
+                    .styled_string(self.redacted(notes), self.indent_style),
+            );
+        }
+
+        if let Some(purl) = component.purl.as_ref().filter(|_| self.purl_links) {
+            let text = format!("Purl: {purl}");
+            match self.purl_link(component) {
+                Some(url) => layout.push(LinkedText::new(
+                    text,
+                    url,
+                    self.indent_style.with_color(LINK_COLOR),
+                )),
+                None => layout.push(Paragraph::default().styled_string(text, self.indent_style)),
+            }
+        }
+
+        for (label, text) in self.supplier_details(component) {
+            layout.push(
+                Paragraph::default()
+                    .styled_string(format!("{label}: "), self.indent_style.bold())
+                    .styled_string(text, self.indent_style),
+            );
+        }
+
+        for property in self.property_entries(component.properties.as_ref()) {
+            layout.push(
+                Paragraph::default()
+                    .styled_string("Property: ", self.indent_style.bold())
+                    .styled_string(property, self.indent_style),
+            );
+        }
+
+        if let Some(bom_ref) = component.bom_ref.as_ref().filter(|_| self.show_bom_refs) {
+            layout.push(
+                Paragraph::default().styled_string(format!("Ref: {bom_ref}"), self.indent_style),
+            );
+        }
+
+        if let Some(sub_components) = &component.components {
+            if !sub_components.0.is_empty() {
+                let mut sub_list = UnorderedList::new();
+                let mut has_entries = false;
+                for sub_component in ordered_components(&sub_components.0, self.sort_components) {
+                    let included = match targets {
+                        Some(targets) => has_targeted_component(sub_component, targets),
+                        None => true,
+                    };
+                    if included {
+                        sub_list.push(self.component_element(
+                            sub_component,
+                            component_refs,
+                            targets,
+                        ));
+                        has_entries = true;
+                    }
+                }
+                if has_entries {
+                    layout.push(sub_list);
+                }
+            }
+        }
+
+        layout
+    }
+
+    /// Adds one row per component to the components table, followed by the rows of its
+    /// sub-components. Names of sub-components are indented by their nesting `depth`.
+    fn push_component_rows(
+        &self,
+        table: &mut TableLayout,
+        components: &[&Component],
+        columns: &[ComponentColumn],
+        component_refs: &HashMap<String, (usize, String)>,
+        targets: Option<&HashSet<String>>,
+        depth: usize,
+    ) {
+        for &component in components {
+            if let Some(targets) = targets {
+                if !has_targeted_component(component, targets) {
+                    continue;
+                }
+            }
+
+            let mut row = table.row();
+            for column in columns {
+                let indent = match column {
+                    ComponentColumn::Name => 4.0 * depth as f64,
+                    _ => 0.0,
+                };
+                let text = self.component_cell(component, *column, component_refs);
+                let padding = genpdf::Margins::trbl(1.0, 1.0, 1.0, 1.0 + indent);
+                let anchor = component
+                    .bom_ref
+                    .as_ref()
+                    .filter(|bom_ref| {
+                        *column == ComponentColumn::Name && component_refs.contains_key(*bom_ref)
+                    })
+                    .map(|bom_ref| component_anchor(bom_ref));
+                match self
+                    .purl_link(component)
+                    .filter(|_| *column == ComponentColumn::Purl)
+                {
+                    Some(url) => row.push_element(
+                        LinkedText::new(text, url, self.indent_style.with_color(LINK_COLOR))
+                            .padded(padding),
+                    ),
+                    None => {
+                        let cell = Paragraph::default()
+                            .styled_string(text, self.indent_style)
+                            .padded(padding);
+                        match anchor {
+                            // The Affects lines of vulnerabilities link here
+                            Some(anchor) => row.push_element(Anchored::new(cell, anchor)),
+                            None => row.push_element(cell),
+                        }
+                    }
+                }
+            }
+            row.push()
+                .expect("components table rows have one cell per column");
+
+            if let Some(sub_components) = &component.components {
+                self.push_component_rows(
+                    table,
+                    &ordered_components(&sub_components.0, self.sort_components),
+                    columns,
+                    component_refs,
+                    targets,
+                    depth + 1,
+                );
+            }
+        }
+    }
+
+    /// Returns the registry page of the component's purl if `VEX2PDF_PURL_LINKS` is enabled
+    /// and the ecosystem of the purl is known
+    fn purl_link(&self, component: &Component) -> Option<String> {
+        component
+            .purl
+            .as_ref()
+            .filter(|_| self.purl_links)
+            .and_then(|purl| registry_url(&purl.to_string()))
+    }
+
+    /// Returns the text of a components table cell, the N/A placeholder for missing values
+    fn component_cell(
+        &self,
+        component: &Component,
+        column: ComponentColumn,
+        component_refs: &HashMap<String, (usize, String)>,
+    ) -> String {
+        let value = match column {
+            ComponentColumn::Name => {
+                // Keep the number vulnerabilities use to refer to this component
+                let label = component
+                    .bom_ref
+                    .as_ref()
+                    .and_then(|bom_ref| component_refs.get(bom_ref))
+                    .map(|(number, _)| format!("[C{number}] "))
+                    .unwrap_or_default();
+                Some(format!("{label}{}", component_name(component)))
+            }
+            ComponentColumn::Version => component.version.as_ref().map(|v| v.to_string()),
+            ComponentColumn::Type => Some(component.component_type.to_string()),
+            ComponentColumn::Purl => component.purl.as_ref().map(|purl| purl.to_string()),
+            ComponentColumn::Cpe => component.cpe.as_ref().map(|cpe| cpe.to_string()),
+            ComponentColumn::License => {
+                Some(component_licenses(component).join(", ")).filter(|text| !text.is_empty())
+            }
+            ComponentColumn::Supplier => component
+                .supplier
+                .as_ref()
+                .and_then(|supplier| supplier.name.as_ref())
+                .map(|name| self.redacted(&name.to_string())),
+        };
+
+        value.unwrap_or_else(|| self.na_text.to_string())
+    }
+
+    /// Returns the supplier name and one entry per supplier contact of a component as
+    /// label and text, e.g. `("Contact", "Jane Doe, jane@example.com")`. Empty unless
+    /// `VEX2PDF_SHOW_CONTACTS` is enabled.
+    fn supplier_details(&self, component: &Component) -> Vec<(&'static str, String)> {
+        let mut details = Vec::new();
+        let supplier = match component.supplier.as_ref().filter(|_| self.show_contacts) {
+            Some(supplier) => supplier,
+            None => return details,
+        };
+
+        if let Some(name) = &supplier.name {
+            details.push(("Supplier", self.redacted(&name.to_string())));
+        }
+        for text in supplier.contact.iter().flatten().filter_map(contact_text) {
+            details.push(("Contact", self.redacted(&text)));
+        }
+
+        details
+    }
+
+    /// Returns one entry per property as name and value, e.g. `build: nightly`. With redaction
+    /// enabled, the value of a property whose name contains a redaction pattern is redacted
+    /// completely and other names and values are redacted like free text.
+    fn property_entries(&self, properties: Option<&Properties>) -> Vec<String> {
+        properties
+            .into_iter()
+            .flat_map(|properties| &properties.0)
+            .map(|property| {
+                let value = if self.redact && matches_pattern(&property.name, self.redact_patterns)
+                {
+                    REDACTED.to_string()
+                } else {
+                    self.redacted(&property.value.to_string())
+                };
+                format!("{}: {value}", self.redacted(&property.name))
+            })
+            .collect()
+    }
+
+    /// Returns one entry per author of the document with the name, email and phone of the
+    /// author, e.g. `Jane Doe, jane@example.com`. Authors without any of them are skipped.
+    fn author_entries(&self, vex: &Bom) -> Vec<String> {
+        vex.metadata
+            .as_ref()
+            .and_then(|metadata| metadata.authors.as_ref())
+            .into_iter()
+            .flatten()
+            .filter_map(contact_text)
+            .map(|text| self.redacted(&text))
+            .collect()
+    }
+
+    /// Returns the entries of the added, removed and state changed groups of the changes
+    /// section, each with its title
+    fn change_groups(&self, diff: &VulnDiff) -> [(&'static str, Vec<String>); 3] {
+        let state_text =
+            |state: &Option<String>| state.clone().unwrap_or_else(|| self.na_text.to_string());
+
+        let added = diff
+            .added
+            .iter()
+            .map(|vuln| format!("{} ({})", vuln.id, state_text(&vuln.state)));
+        let removed = diff
+            .removed
+            .iter()
+            .map(|vuln| format!("{} ({})", vuln.id, state_text(&vuln.state)));
+        let changed = diff.changed.iter().map(|change| {
+            format!(
+                "{}: {} → {}",
+                change.id,
+                state_text(&change.previous),
+                state_text(&change.current)
+            )
+        });
+
+        [
+            ("Added", added.collect()),
+            ("Removed", removed.collect()),
+            ("State changed", changed.collect()),
+        ]
+    }
+
+    /// Pushes the section listing the vulnerabilities added, removed and changed in `vex`
+    /// compared to the `previous` document
+    fn push_changes(&self, doc: &mut Document, previous: &Bom, vex: &Bom) {
+        let diff = diff_vulnerabilities(previous, vex);
+
+        doc.push(
+            Paragraph::default().styled_string("Changes Since Previous Report", self.header_style),
+        );
+        doc.push(self.section_break());
+
+        if diff.is_empty() {
+            doc.push(Paragraph::default().styled_string(
+                "No vulnerabilities were added, removed or changed",
+                self.indent_style,
+            ));
+            doc.push(self.section_break());
+            return;
+        }
+
+        for (title, entries) in self.change_groups(&diff) {
+            if entries.is_empty() {
+                continue;
+            }
+
+            doc.push(Paragraph::default().styled_string(
+                format!("{title} ({})", entries.len()),
+                self.normal_style.bold(),
+            ));
+            let mut list = UnorderedList::new();
+            for entry in entries {
+                list.push(Paragraph::default().styled_string(entry, self.indent_style));
+            }
+            doc.push(list);
+            doc.push(self.item_break());
+        }
+        doc.push(self.item_break());
+    }
+
+    /// Expands the cover template and pushes it to the document followed by a page break.
+    ///
+    /// Lines starting with `# ` are rendered as the title and lines starting with `## ` as
+    /// headings. Blank lines add vertical space.
+    fn push_cover(&self, doc: &mut Document, template: &str, document_title: &str, vex: &Bom) {
+        let date = vex
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.timestamp.as_ref())
+            .map(|timestamp| timestamp.to_string())
+            .or_else(|| self.report_time(vex))
+            .unwrap_or_else(|| self.na_text.to_string());
+        // Unlike the document timestamp, the report date is never taken from the BOM
+        let report_date = self
+            .report_date_text(vex)
+            .or_else(|| {
+                self.report_time(vex)
+                    .map(|time| time.chars().take(10).collect())
+            })
+            .unwrap_or_else(|| self.na_text.to_string());
+        let serial = vex
+            .serial_number
+            .as_ref()
+            .map_or_else(|| self.na_text.to_string(), |serial| serial.to_string());
+        let values = CoverValues {
+            title: document_title,
+            date: &date,
+            report_date: &report_date,
+            serial: &serial,
+            component_count: vex
+                .components
+                .as_ref()
+                .map_or(0, |components| components.0.len()),
+        };
+
+        for line in expand_cover_template(template, &values).lines() {
+            let line = line.trim_end();
+            if let Some(title) = line.strip_prefix("# ") {
+                doc.push(Paragraph::default().styled_string(title, self.title_style));
+            } else if let Some(heading) = line.strip_prefix("## ") {
+                doc.push(Paragraph::default().styled_string(heading, self.header_style));
+            } else if line.is_empty() {
+                doc.push(genpdf::elements::Break::new(1));
+            } else {
+                doc.push(Paragraph::default().styled_string(line, self.normal_style));
+            }
+        }
+
+        doc.push(genpdf::elements::PageBreak::new());
+    }
+
+    /// Numbers the referenceable components when cross-references are enabled, see
+    /// [`component_ref_index`]. Returns an empty index otherwise.
+    fn cross_ref_index(&self, vex: &Bom) -> HashMap<String, (usize, String)> {
+        match self.listed_components(vex) {
+            Some(components) if self.cross_refs => component_ref_index(
+                &self.top_level_components(&components),
+                self.sort_components,
+            ),
+            _ => HashMap::new(),
+        }
+    }
+
+    /// Returns the top-level components in the order they are rendered in the Components
+    /// section: ordered by [`ordered_components`] and, if `VEX2PDF_GROUP_BY_ECOSYSTEM` is
+    /// enabled, grouped by ecosystem (see [`group_components_by_ecosystem`])
+    fn top_level_components<'c>(&self, components: &'c [Component]) -> Vec<&'c Component> {
+        let ordered = ordered_components(components, self.sort_components);
+        if self.group_by_ecosystem {
+            group_components_by_ecosystem(&ordered)
+                .into_iter()
+                .flat_map(|(_, group)| group)
+                .collect()
+        } else {
+            ordered
+        }
+    }
+
+    /// Returns the components listed in the Components section, `None` if there are none.
+    ///
+    /// If `VEX2PDF_DEDUP_METADATA_COMPONENT` is enabled, top-level components identical to
+    /// the metadata component are left out, since the Document Information section already
+    /// shows it.
+    fn listed_components<'v>(&self, vex: &'v Bom) -> Option<Cow<'v, [Component]>> {
+        let components = &vex.components.as_ref()?.0;
+        let metadata_component = vex
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.component.as_ref())
+            .filter(|_| self.dedup_metadata_component);
+
+        match metadata_component {
+            Some(metadata_component) if components.contains(metadata_component) => {
+                let kept: Vec<Component> = components
+                    .iter()
+                    .filter(|component| *component != metadata_component)
+                    .cloned()
+                    .collect();
+                (!kept.is_empty()).then_some(Cow::Owned(kept))
+            }
+            _ => Some(Cow::Borrowed(components)),
+        }
+    }
+
+    /// Renders the Document Information section with the document metadata and the basic
+    /// BOM information.
+    ///
+    /// # Arguments
+    ///
+    /// * `doc` - The document to add the section to
+    /// * `vex` - The CycloneDX VEX document to render
+    /// * `source` - Input document the report is generated from
+    pub fn render_metadata(&self, doc: &mut Document, vex: &Bom, source: &ReportSource<'_>) {
+        doc.push(Paragraph::default().styled_string("Document Information", self.header_style));
+        doc.push(self.section_break());
+
+        if let Some(name) = source.name {
+            doc.push(
+                Paragraph::default()
+                    .styled_string("Source file: ", self.normal_style.bold())
+                    .styled_string(self.redacted(name), self.normal_style),
+            );
+        }
+        if let Some(sha256) = source.sha256 {
+            doc.push(
+                Paragraph::default()
+                    .styled_string("Source SHA-256: ", self.normal_style.bold())
+                    .styled_string(sha256, self.normal_style),
+            );
+        }
+
+        // Add metadata if available
+        if let Some(metadata) = &vex.metadata {
+            // Add timestamp if available
+            if let Some(timestamp) = &metadata.timestamp {
+                doc.push(
+                    Paragraph::default()
+                        .styled_string(format!("Date: {}", timestamp), self.normal_style),
+                );
+            }
+
+            // Add lifecycle phases the assessment was made in, if available
+            if let Some(lifecycles) = &metadata.lifecycles {
+                let phases: Vec<String> = lifecycles
+                    .0
+                    .iter()
+                    .map(|lifecycle| match lifecycle {
+                        Lifecycle::Phase(phase) => phase.to_string(),
+                        Lifecycle::Description(description) => description.name.to_string(),
+                    })
+                    .collect();
+
+                if !phases.is_empty() {
+                    doc.push(
+                        Paragraph::default()
+                            .styled_string("Lifecycle: ", self.normal_style)
+                            .styled_string(phases.join(", "), self.indent_style),
+                    );
+                }
+            }
+
+            doc.push(self.section_break());
+
+            // Add tools information if available
+            if let Some(tools) = &metadata.tools {
+                doc.push(Paragraph::default().styled_string("Tools:", self.normal_style));
+
+                let names = tool_names(tools);
+                if self.is_tools_list_collapsed(&names) {
+                    doc.push(Paragraph::default().styled_string(
+                        format!("{} tools (see appendix)", names.len()),
+                        self.indent_style,
+                    ));
+                } else {
+                    let mut ul_tools = genpdf::elements::UnorderedList::new();
+                    for name in &names {
+                        ul_tools.push(Paragraph::default().styled_string(name, self.indent_style));
+                    }
+                    doc.push(ul_tools);
+                }
+                doc.push(self.section_break());
+            }
+
+            let authors = self.author_entries(vex);
+            if !authors.is_empty() {
+                doc.push(Paragraph::default().styled_string("Authors:", self.normal_style));
+
+                let mut ul_authors = UnorderedList::new();
+                for author in authors {
+                    ul_authors.push(Paragraph::default().styled_string(author, self.indent_style));
+                }
+                doc.push(ul_authors);
+                doc.push(self.section_break());
+            }
+
+            let properties = self.property_entries(metadata.properties.as_ref());
+            if !properties.is_empty() {
+                doc.push(Paragraph::default().styled_string("Properties:", self.normal_style));
+
+                let mut ul_properties = UnorderedList::new();
+                for property in properties {
+                    ul_properties
+                        .push(Paragraph::default().styled_string(property, self.indent_style));
+                }
+                doc.push(ul_properties);
+                doc.push(self.section_break());
+            }
+
+            if let Some(component) = &metadata.component {
+                // the top-level component identifies the product, so show which release it is
+                let version = component
+                    .version
+                    .as_ref()
+                    .map(|version| version.to_string());
+                let descriptor =
+                    component_descriptor(&component.component_type.to_string(), version.as_deref());
+                doc.push(
+                    Paragraph::default()
+                        .styled_string("Component name : ", self.normal_style)
+                        .styled_string(
+                            format!("{} ({})", component_name(component), descriptor),
+                            self.indent_style,
+                        ),
+                );
+            }
+
+            doc.push(self.section_break());
+        } else {
+            // Without metadata there is no BOM timestamp or tooling context,
+            // so at least state when the report was generated
+            if let Some(now) = self.report_time(vex) {
+                doc.push(
+                    Paragraph::default()
+                        .styled_string(format!("Report Date: {}", now), self.normal_style),
+                );
+            }
+            doc.push(
+                Paragraph::default()
+                    .styled_string("No document metadata provided", self.indent_style),
+            );
+
+            doc.push(self.section_break());
+        }
+
+        // Add basic BOM information
+        doc.push(Paragraph::default().styled_string("BOM Format: CycloneDX", self.normal_style));
+        doc.push(Paragraph::default().styled_string(
+            format!("Specification Version: {}", vex.spec_version),
+            self.normal_style,
+        ));
+        doc.push(
+            Paragraph::default()
+                .styled_string(format!("Version: {}", vex.version), self.normal_style),
+        );
+
+        if let Some(serial) = &vex.serial_number {
+            doc.push(
+                Paragraph::default()
+                    .styled_string(format!("Serial Number: {}", serial), self.normal_style),
+            );
+        }
+
+        doc.push(genpdf::elements::Break::new(2.0 * self.section_spacing));
+    }
+
+    /// Renders the Vulnerabilities section, or the message shown when the document has no
+    /// vulnerabilities, according to the configuration of this generator.
+    ///
+    /// Together with the other `render_*` methods this allows assembling custom documents.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use genpdf::Document;
+    /// use vex2pdf::model::prelude::Bom;
+    /// use vex2pdf::pdf::font_config::FontsDir;
+    /// use vex2pdf::pdf::generator::PdfGenerator;
+    ///
+    /// let vex = Bom::default();
+    /// let generator = PdfGenerator::default();
+    ///
+    /// let mut doc = Document::new(FontsDir::build().font_family);
+    /// generator.render_vulnerabilities(&mut doc, &vex);
+    /// doc.render_to_file("vulnerabilities.pdf")
+    ///     .expect("failed to render vulnerabilities");
+    /// ```
+    pub fn render_vulnerabilities(&self, doc: &mut Document, vex: &Bom) {
+        // First determine if vulnerabilities exist
+        let mut vulns_available = false;
+        if let Some(vulnerabilities) = &vex.vulnerabilities {
+            vulns_available = !vulnerabilities.0.is_empty();
+        }
+
+        // Decide if we should show the vulnerabilities section at all
+        let show_vulns_section = vulns_available || self.show_novulns_msg;
+
+        if show_vulns_section {
+            doc.push(Paragraph::default().styled_string("Vulnerabilities", self.header_style));
+            doc.push(self.section_break());
+        }
+
+        if let Some(vulnerabilities) = &vex.vulnerabilities {
+            // Lead with the key message if the document declares the product unaffected
+            if is_product_unaffected(&vulnerabilities.0) {
+                let unaffected_style = Style::new()
+                    .bold()
+                    .with_font_size(16)
+                    .with_color(Color::Rgb(0, 100, 0));
+
+                doc.push(
+                    Paragraph::new("Product Not Affected")
+                        .aligned(Alignment::Center)
+                        .padded(genpdf::Margins::vh(10, 0))
+                        .framed()
+                        .styled(unaffected_style),
+                );
+                doc.push(self.section_break());
+            }
+
+            // Name the advisory databases the findings come from for provenance
+            let sources = vulnerability_sources(&vulnerabilities.0);
+            if !sources.is_empty() {
+                doc.push(
+                    Paragraph::default()
+                        .styled_string("Sources: ", self.normal_style.bold())
+                        .styled_string(sources.join(", "), self.normal_style),
+                );
+                doc.push(self.section_break());
+            }
+
+            let rendered_vulns = self.selected_vulns(&vulnerabilities.0);
+
+            // State the date range so that readers know the list is not exhaustive
+            if let Some(range) = self.date_range_text() {
+                doc.push(Paragraph::default().styled_string(
+                    format!("Showing vulnerabilities published {range}"),
+                    self.indent_style.italic(),
+                ));
+                doc.push(self.section_break());
+            }
+
+            if let Some(vuln_id) = self.only_vuln_id {
+                if rendered_vulns.is_empty() {
+                    warn(format!(
+                        "vulnerability with ID {vuln_id} not found in document. The vulnerabilities section will be empty"
+                    ));
+                    doc.push(Paragraph::default().styled_string(
+                        format!("No vulnerability with ID {vuln_id} found in this document"),
+                        self.indent_style,
+                    ));
+                    doc.push(self.section_break());
+                }
+            }
+
+            // Without grouping all vulnerabilities form a single untitled group
+            let vuln_groups = if self.group_by_severity {
+                group_vulns_by_severity(&rendered_vulns, |vuln| self.max_severity(vuln))
+                    .into_iter()
+                    .map(|(title, vulns)| (Some(title), vulns))
+                    .collect::<Vec<_>>()
+            } else if self.group_by_component {
+                let group_refs = vex
+                    .components
+                    .as_ref()
+                    .map(|components| {
+                        component_ref_index(
+                            &self.top_level_components(&components.0),
+                            self.sort_components,
+                        )
+                    })
+                    .unwrap_or_default();
+                group_vulns_by_component(&rendered_vulns, &group_refs)
+                    .into_iter()
+                    .map(|(name, vulns)| (Some(name), vulns))
+                    .collect::<Vec<_>>()
+            } else {
+                vec![(None, rendered_vulns)]
+            };
+
+            // Today as days since the epoch, only needed when aging vulnerabilities are flagged
+            let today = self.aging_days.and_then(|_| {
+                self.report_time(vex)
+                    .and_then(|time| days_since_epoch(&time))
+            });
+
+            let component_refs = self.cross_ref_index(vex);
+            for (group_name, group_vulns) in vuln_groups {
+                if let Some(group_name) = group_name {
+                    doc.push(Paragraph::default().styled_string(
+                        format!("{group_name} ({})", group_vulns.len()),
+                        self.normal_style.bold(),
+                    ));
+                    doc.push(self.item_break());
+                }
+
+                if self.compact {
+                    doc.push(self.compact_vuln_table(&group_vulns));
+                    doc.push(self.item_break());
+                } else {
+                    let mut ordered_list = genpdf::elements::OrderedList::new();
+
+                    // Add each vulnerability
+                    let context = VulnerabilityContext::new(self, &component_refs, today);
+                    for vuln in group_vulns {
+                        ordered_list.push(
+                            self.vulnerability_renderer
+                                .render_vulnerability(vuln, &context),
+                        );
+                    }
+
+                    doc.push(ordered_list);
+                    doc.push(self.item_break());
+                }
+            }
+        }
+
+        //Add message if vulns are not available
+        if !vulns_available && self.show_novulns_msg {
+            // an empty vulnerabilities array means the product was assessed without findings,
+            // a missing one means it was not assessed, which is no good news
+            let (message, color) = if vex.vulnerabilities.is_some() {
+                ("No vulnerabilities found", Color::Rgb(0, 100, 0))
+            } else {
+                ("No vulnerability data", Color::Rgb(90, 90, 90))
+            };
+            let vulns_style = Style::new().bold().with_font_size(16).with_color(color);
+
+            let paragraph = Paragraph::new(message).aligned(Alignment::Center);
+            if self.novulns_framed {
+                doc.push(
+                    paragraph
+                        .padded(genpdf::Margins::vh(10, 0))
+                        .framed()
+                        .styled(vulns_style),
+                );
+            } else {
+                doc.push(paragraph.styled(vulns_style));
+            }
+            doc.push(self.section_break());
+        }
+    }
+
+    /// Renders the Data Completeness section, a scorecard of how many vulnerabilities lack
+    /// an analysis state, ratings or a description. Nothing is rendered unless
+    /// `VEX2PDF_COMPLETENESS_SECTION` is enabled.
+    pub fn render_completeness(&self, doc: &mut Document, vex: &Bom) {
+        if !self.completeness_section {
+            return;
+        }
+        let scorecard = completeness(vex);
+
+        doc.push(Paragraph::default().styled_string("Data Completeness", self.header_style));
+        doc.push(self.item_break());
+        doc.push(
+            Paragraph::default()
+                .styled_string("Completeness: ", self.normal_style.bold())
+                .styled_string(
+                    format!(
+                        "{}% across {} vulnerabilities",
+                        scorecard.percent(),
+                        scorecard.vulnerabilities
+                    ),
+                    self.normal_style,
+                ),
+        );
+
+        let mut list = UnorderedList::new();
+        for (field, count) in scorecard.gaps() {
+            list.push(
+                Paragraph::default()
+                    .styled_string(format!("Without {field}: "), self.indent_style.bold())
+                    .styled_string(count.to_string(), self.indent_style),
+            );
+        }
+        doc.push(list);
+        doc.push(self.section_break());
+    }
+
+    /// Renders the Annotations section listing the annotations of the document. Nothing is
+    /// rendered if the document has no annotations.
+    pub fn render_annotations(&self, doc: &mut Document, vex: &Bom) {
+        let annotations = document_annotations(vex);
+        if annotations.is_empty() {
+            return;
+        }
+
+        doc.push(Paragraph::default().styled_string("Annotations", self.header_style));
+        doc.push(self.item_break());
+
+        for annotation in &annotations {
+            let mut heading = Paragraph::default().styled_string(
+                annotation.timestamp.as_deref().unwrap_or(self.na_text),
+                self.normal_style.bold(),
+            );
+            if let Some(annotator) = &annotation.annotator {
+                heading = heading.styled_string(
+                    format!(" — {}", self.redacted(annotator)),
+                    self.normal_style,
+                );
+            }
+            doc.push(heading);
+
+            if !annotation.subjects.is_empty() {
+                doc.push(
+                    Paragraph::default()
+                        .styled_string("Subjects: ", self.indent_style.bold())
+                        .styled_string(annotation.subjects.join(", "), self.indent_style),
+                );
+            }
+            doc.push(
+                Paragraph::default()
+                    .styled_string(self.redacted(&annotation.text), self.indent_style),
+            );
+            doc.push(self.item_break());
+        }
+        doc.push(self.item_break());
+    }
+
+    /// Renders the Components section listing the components of the document and their
+    /// sub-components. Nothing is rendered if the document has no components.
+    ///
+    /// If `VEX2PDF_VULN_COMPONENTS_ONLY` is enabled, only components targeted by a
+    /// vulnerability and their ancestors are listed, followed by the number of components
+    /// left out.
+    pub fn render_components(&self, doc: &mut Document, vex: &Bom) {
+        if let Some(components) = self.listed_components(vex) {
+            doc.push(Paragraph::default().styled_string("Components", self.header_style));
+            doc.push(self.item_break());
+
+            let component_refs = self.cross_ref_index(vex);
+            let targets = self
+                .vuln_components_only
+                .then(|| vulnerability_target_refs(vex));
+            let mut top_level = self.top_level_components(&components);
+            if let Some(targets) = &targets {
+                top_level.retain(|component| has_targeted_component(component, targets));
+            }
+
+            // Without grouping all components form a single untitled group
+            let component_groups = if self.group_by_ecosystem {
+                group_components_by_ecosystem(&top_level)
+                    .into_iter()
+                    .map(|(ecosystem, group)| (Some(ecosystem), group))
+                    .collect::<Vec<_>>()
+            } else {
+                vec![(None, top_level)]
+            };
+
+            for (ecosystem, group) in component_groups {
+                if let Some(ecosystem) = ecosystem {
+                    doc.push(Paragraph::default().styled_string(
+                        format!("{ecosystem} ({})", group.len()),
+                        self.normal_style.bold(),
+                    ));
+                    doc.push(self.item_break());
+                }
+
+                if let Some(columns) = self.component_columns {
+                    let mut table =
+                        TableLayout::new(columns.iter().map(ComponentColumn::weight).collect());
+                    table.set_cell_decorator(FrameCellDecorator::new(true, true, false));
+                    let mut header = table.row();
+                    for column in columns {
+                        header.push_element(
+                            Paragraph::default()
+                                .styled_string(column.title(), self.indent_style.bold())
+                                .padded(1),
+                        );
+                    }
+                    header
+                        .push()
+                        .expect("components table header has one cell per column");
+
+                    self.push_component_rows(
+                        &mut table,
+                        &group,
+                        columns,
+                        &component_refs,
+                        targets.as_ref(),
+                        0,
+                    );
+                    doc.push(table);
+                    doc.push(self.item_break());
+                } else {
+                    for component in group {
+                        doc.push(self.component_element(
+                            component,
+                            &component_refs,
+                            targets.as_ref(),
+                        ));
+                        doc.push(self.item_break());
+                    }
+                }
+            }
+
+            // Tell readers how many components were left out
+            if let Some(targets) = &targets {
+                let hidden = count_components(&components, &|_| true)
+                    - count_components(&components, &|component| {
+                        has_targeted_component(component, targets)
+                    });
+                if hidden > 0 {
+                    doc.push(Paragraph::default().styled_string(
+                        format!("+{hidden} more components not affected by any vulnerability"),
+                        self.indent_style.italic(),
+                    ));
+                    doc.push(self.item_break());
+                }
+            }
+        }
+    }
+
+    /// Renders the Services section listing the services of the document with a Data Flows
+    /// sub-list of the direction and classification of the data each service exchanges.
+    /// Nothing is rendered if the document has no services.
+    pub fn render_services(&self, doc: &mut Document, vex: &Bom) {
+        let services = document_services(vex);
+        if services.is_empty() {
+            return;
+        }
+
+        doc.push(Paragraph::default().styled_string("Services", self.header_style));
+        doc.push(self.item_break());
+
+        for service in &services {
+            // Nested services are set in the smaller indent style below their parent
+            let style = if service.depth == 0 {
+                self.normal_style
+            } else {
+                self.indent_style
+            };
+            let mut heading = Paragraph::default().styled_string(&service.name, style.bold());
+            if let Some(version) = &service.version {
+                heading = heading.styled_string(format!(" {version}"), style);
+            }
+            doc.push(heading);
+
+            if !service.data_flows.is_empty() {
+                doc.push(
+                    Paragraph::default().styled_string("Data Flows:", self.indent_style.bold()),
+                );
+                let mut list = UnorderedList::new();
+                for data_flow in &service.data_flows {
+                    list.push(
+                        Paragraph::default()
+                            .styled_string(
+                                format!("{}: ", data_flow.flow),
+                                self.indent_style.bold(),
+                            )
+                            .styled_string(
+                                data_flow.classification.as_deref().unwrap_or(self.na_text),
+                                self.indent_style,
+                            ),
+                    );
+                }
+                doc.push(list);
+            }
+            doc.push(self.item_break());
+        }
+        doc.push(self.item_break());
+    }
+
+    /// Renders the Tools appendix listing every tool of the document. Nothing is rendered
+    /// unless the tools list in the metadata section was collapsed because it exceeds
+    /// `VEX2PDF_TOOLS_MAX`.
+    pub fn render_tools_appendix(&self, doc: &mut Document, vex: &Bom) {
+        let names = vex
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.tools.as_ref())
+            .map(tool_names)
+            .unwrap_or_default();
+        if !self.is_tools_list_collapsed(&names) {
+            return;
+        }
+
+        doc.push(genpdf::elements::PageBreak::new());
+        doc.push(Paragraph::default().styled_string("Tools", self.header_style));
+        doc.push(self.section_break());
+
+        let mut ul_tools = genpdf::elements::UnorderedList::new();
+        for name in &names {
+            ul_tools.push(Paragraph::default().styled_string(name, self.indent_style));
+        }
+        doc.push(ul_tools);
+    }
+
+    /// Returns whether the tools list exceeds `tools_max` and is moved to the appendix
+    fn is_tools_list_collapsed(&self, names: &[String]) -> bool {
+        self.tools_max > 0 && names.len() > self.tools_max
+    }
+
+    /// Renders the License Texts appendix reproducing each distinct license text attached
+    /// to the components and services of the document. Nothing is rendered if the document
+    /// has no license texts.
+    pub fn render_license_appendix(&self, doc: &mut Document, vex: &Bom) {
+        let license_texts = document_license_texts(vex);
+        if license_texts.is_empty() {
+            return;
+        }
+
+        doc.push(genpdf::elements::PageBreak::new());
+        doc.push(Paragraph::default().styled_string("License Texts", self.header_style));
+        doc.push(self.section_break());
+
+        for license_text in &license_texts {
+            doc.push(
+                Paragraph::default().styled_string(&license_text.license, self.normal_style.bold()),
+            );
+            if !license_text.used_by.is_empty() {
+                doc.push(
+                    Paragraph::default()
+                        .styled_string("Used by: ", self.indent_style.bold())
+                        .styled_string(license_text.used_by.join(", "), self.indent_style),
+                );
+            }
+            doc.push(self.item_break());
+
+            for paragraph in disclaimer_paragraphs(&license_text.text) {
+                doc.push(Paragraph::default().styled_string(paragraph, self.indent_style));
+                doc.push(self.item_break());
+            }
+            doc.push(self.section_break());
+        }
+    }
+
+    /// Renders the Conversion Notes section listing the warnings noticed while converting
+    /// the document, see [`crate::lib_utils::warnings::NoteCollection`]. Nothing is rendered
+    /// unless `VEX2PDF_SHOW_NOTES` is enabled.
+    pub fn render_notes(&self, doc: &mut Document, notes: &[String]) {
+        if !self.show_notes {
+            return;
+        }
+
+        doc.push(Paragraph::default().styled_string("Conversion Notes", self.header_style));
+        doc.push(self.item_break());
+        if notes.is_empty() {
+            doc.push(Paragraph::default().styled_string(
+                "No issues were noticed while generating this report",
+                self.indent_style,
+            ));
+        } else {
+            let mut list = UnorderedList::new();
+            for note in notes {
+                list.push(
+                    Paragraph::default().styled_string(self.redacted(note), self.indent_style),
+                );
+            }
+            doc.push(list);
+        }
+        doc.push(self.section_break());
+    }
+
+    /// Renders the disclaimer as a final section
+    fn render_disclaimer(&self, doc: &mut Document, disclaimer: &str) {
+        doc.push(self.section_break());
+        doc.push(Paragraph::default().styled_string("Disclaimer", self.header_style));
+        doc.push(self.item_break());
+
+        for paragraph in disclaimer_paragraphs(disclaimer) {
+            doc.push(Paragraph::default().styled_string(paragraph, self.indent_style));
+            doc.push(self.item_break());
+        }
+    }
+
+    /// Returns the report title, blank titles would leave the report without a heading
+    fn document_title(&self) -> &str {
+        self.report_title
+            .filter(|title| !title.trim().is_empty())
+            .unwrap_or(Self::get_default_report_title())
+    }
+
+    /// Renders the report as plain text.
+    ///
+    /// Headings are underlined and details are indented by two spaces per level, which
+    /// suits screen readers, version control diffs and grep. Filters, redaction and the
+    /// section toggles of this generator apply as for the PDF report.
+    ///
+    /// The text report covers the document information, changes, data completeness,
+    /// vulnerabilities, annotations, components, services, notes and disclaimer. The tools
+    /// list and its appendix, lifecycle phases, score gauges, severity badges and the
+    /// grouping of vulnerabilities by severity or component are only rendered in the PDF
+    /// report.
+    ///
+    /// # Arguments
+    ///
+    /// * `vex` - The CycloneDX VEX document to render
+    /// * `source` - Input document the report is generated from
+    pub fn generate_text(&self, vex: &Bom, source: &ReportSource<'_>) -> String {
+        let mut lines: Vec<String> = Vec::new();
+        let heading = |lines: &mut Vec<String>, title: &str, underline: &str| {
+            if !lines.is_empty() {
+                lines.push(String::new());
+            }
+            lines.push(title.to_string());
+            lines.push(underline.repeat(title.chars().count()));
+        };
+
+        // The marking encloses the report as it does every page of the PDF
+        if let Some(classification) = self.classification {
+            lines.push(classification.to_string());
+        }
+        heading(&mut lines, self.document_title(), "=");
+        if let Some(report_date) = self.report_date_text(vex) {
+            lines.push(format!("Report date: {report_date}"));
+        }
+        if let Some(notice) = vulnerability_completeness_notice(vex) {
+            lines.push(notice);
+        }
+
+        // Document Information
+        heading(&mut lines, "Document Information", "-");
+        if let Some(name) = source.name {
+            lines.push(format!("Source file: {}", self.redacted(name)));
+        }
+        if let Some(sha256) = source.sha256 {
+            lines.push(format!("Source SHA-256: {sha256}"));
+        }
+        match &vex.metadata {
+            Some(metadata) => {
+                if let Some(timestamp) = &metadata.timestamp {
+                    lines.push(format!("Date: {timestamp}"));
+                }
+                let authors = self.author_entries(vex);
+                if !authors.is_empty() {
+                    lines.push(format!("Authors: {}", authors.join("; ")));
+                }
+                let properties = self.property_entries(metadata.properties.as_ref());
+                if !properties.is_empty() {
+                    lines.push(format!("Properties: {}", properties.join("; ")));
+                }
+                if let Some(component) = &metadata.component {
+                    let version = component
+                        .version
+                        .as_ref()
+                        .map(|version| version.to_string());
+                    let descriptor = component_descriptor(
+                        &component.component_type.to_string(),
+                        version.as_deref(),
+                    );
+                    lines.push(format!(
+                        "Component name: {} ({descriptor})",
+                        component_name(component)
+                    ));
+                }
+            }
+            None => lines.push("No document metadata provided".to_string()),
+        }
+        lines.push("BOM Format: CycloneDX".to_string());
+        lines.push(format!("Specification Version: {}", vex.spec_version));
+        lines.push(format!("Version: {}", vex.version));
+        if let Some(serial) = &vex.serial_number {
+            lines.push(format!("Serial Number: {serial}"));
+        }
+
+        if let Some(previous) = self.previous_bom {
+            heading(&mut lines, "Changes Since Previous Report", "-");
+            let diff = diff_vulnerabilities(previous, vex);
+            if diff.is_empty() {
+                lines.push("No vulnerabilities were added, removed or changed".to_string());
+            }
+            for (title, entries) in self.change_groups(&diff) {
+                if !entries.is_empty() {
+                    lines.push(format!("{title} ({})", entries.len()));
+                    lines.extend(entries.iter().map(|entry| format!("  - {entry}")));
+                }
+            }
+        }
+
+        if self.completeness_section {
+            heading(&mut lines, "Data Completeness", "-");
+            let scorecard = completeness(vex);
+            lines.push(format!(
+                "Completeness: {}% across {} vulnerabilities",
+                scorecard.percent(),
+                scorecard.vulnerabilities
+            ));
+            for (field, count) in scorecard.gaps() {
+                lines.push(format!("  - Without {field}: {count}"));
+            }
+        }
+
+        // Vulnerabilities
+        let vulnerabilities = vex
+            .vulnerabilities
+            .as_ref()
+            .map(|vulns| vulns.0.as_slice())
+            .unwrap_or_default();
+        if !vulnerabilities.is_empty() || self.show_novulns_msg {
+            heading(&mut lines, "Vulnerabilities", "-");
+        }
+        if vulnerabilities.is_empty() {
+            if self.show_novulns_msg {
+                lines.push(if vex.vulnerabilities.is_some() {
+                    "No vulnerabilities found".to_string()
+                } else {
+                    "No vulnerability data".to_string()
+                });
+            }
+        } else {
+            if is_product_unaffected(vulnerabilities) {
+                lines.push("Product Not Affected".to_string());
+            }
+            let sources = vulnerability_sources(vulnerabilities);
+            if !sources.is_empty() {
+                lines.push(format!("Sources: {}", sources.join(", ")));
+            }
+            if let Some(range) = self.date_range_text() {
+                lines.push(format!("Showing vulnerabilities published {range}"));
+            }
+
+            let component_refs = self.cross_ref_index(vex);
+            for vuln in self.selected_vulns(vulnerabilities) {
+                lines.push(String::new());
+                lines.push(
+                    vuln.id
+                        .as_ref()
+                        .map_or_else(|| self.na_text.to_string(), |id| id.to_string()),
+                );
+
+                let affects: Vec<String> = vuln
+                    .vulnerability_targets
+                    .iter()
+                    .filter(|_| self.cross_refs)
+                    .flat_map(|targets| &targets.0)
+                    .map(|target| match component_refs.get(&target.bom_ref) {
+                        Some((_, name)) => name.clone(),
+                        None => target.bom_ref.clone(),
+                    })
+                    .collect();
+                if !affects.is_empty() {
+                    lines.push(format!("  Affects: {}", affects.join(", ")));
+                }
+
+                let description = vuln.description.as_deref().unwrap_or(self.na_text);
+                lines.push(format!("  Description: {}", self.redacted(description)));
+
+                if let Some(analysis) = &vuln.vulnerability_analysis {
+                    if let Some(state) = &analysis.state {
+                        lines.push(format!("  State: {}", fmt_analysis_state(state)));
+                    }
+                    if let Some(detail) = analysis.detail.as_deref().filter(|d| !d.is_empty()) {
+                        lines.push(format!("  Detail: {}", self.redacted(detail)));
+                    }
+                    if let Some(justification) = &analysis.justification {
+                        lines.push(format!(
+                            "  Justification: {}",
+                            fmt_justification(justification)
+                        ));
+                    }
+                    if let Some(responses) = analysis.responses.as_ref().filter(|rs| !rs.is_empty())
+                    {
+                        lines.push(format!("  Responses: {}", responses_text(responses)));
+                    }
+                }
+
+                for rating in vuln
+                    .vulnerability_ratings
+                    .iter()
+                    .flat_map(|ratings| self.select_ratings(&ratings.0))
+                {
+                    if let Some(text) = self.rating_text(rating) {
+                        lines.push(format!("  Severity: {text}"));
+                    }
+                    if let Some(breakdown) = self.cvss_breakdown_text(rating) {
+                        lines.push(format!("    Scores: {breakdown}"));
+                    }
+                }
+            }
+        }
+
+        // Annotations
+        let annotations = document_annotations(vex);
+        if !annotations.is_empty() {
+            heading(&mut lines, "Annotations", "-");
+            for annotation in &annotations {
+                let timestamp = annotation.timestamp.as_deref().unwrap_or(self.na_text);
+                lines.push(match &annotation.annotator {
+                    Some(annotator) => format!("{timestamp} — {}", self.redacted(annotator)),
+                    None => timestamp.to_string(),
+                });
+                if !annotation.subjects.is_empty() {
+                    lines.push(format!("  Subjects: {}", annotation.subjects.join(", ")));
+                }
+                lines.push(format!("  {}", self.redacted(&annotation.text)));
+            }
+        }
+
+        // Components
+        if let Some(components) = self.listed_components(vex).filter(|_| self.show_components) {
+            heading(&mut lines, "Components", "-");
+            let targets = self
+                .vuln_components_only
+                .then(|| vulnerability_target_refs(vex));
+            let mut top_level = self.top_level_components(&components);
+            if let Some(targets) = &targets {
+                top_level.retain(|component| has_targeted_component(component, targets));
+            }
+            if self.group_by_ecosystem {
+                for (ecosystem, group) in group_components_by_ecosystem(&top_level) {
+                    lines.push(format!("{ecosystem}:"));
+                    self.push_component_lines(&mut lines, &group, targets.as_ref(), 1);
+                }
+            } else {
+                self.push_component_lines(&mut lines, &top_level, targets.as_ref(), 0);
+            }
+        }
+
+        // Services
+        let services = document_services(vex);
+        if !services.is_empty() {
+            heading(&mut lines, "Services", "-");
+            for service in &services {
+                let indent = "  ".repeat(service.depth);
+                lines.push(match &service.version {
+                    Some(version) => format!("{indent}{} {version}", service.name),
+                    None => format!("{indent}{}", service.name),
+                });
+                if !service.data_flows.is_empty() {
+                    lines.push(format!("{indent}  Data Flows:"));
+                }
+                for data_flow in &service.data_flows {
+                    lines.push(format!(
+                        "{indent}    {}: {}",
+                        data_flow.flow,
+                        data_flow.classification.as_deref().unwrap_or(self.na_text)
+                    ));
+                }
+            }
+        }
+
+        if self.show_notes {
+            heading(&mut lines, "Conversion Notes", "-");
+            let notes = collected_notes();
+            if notes.is_empty() {
+                lines.push("No issues were noticed while generating this report".to_string());
+            }
+            for note in &notes {
+                lines.push(format!("- {}", self.redacted(note)));
+            }
+        }
+
+        if let Some(disclaimer) = self.disclaimer {
+            heading(&mut lines, "Disclaimer", "-");
+            lines.push(disclaimer_paragraphs(disclaimer).join("\n\n"));
+        }
+
+        if let Some(classification) = self.classification {
+            lines.push(String::new());
+            lines.push(classification.to_string());
+        }
+        lines.push(String::new());
+        lines.join("\n")
+    }
+
+    /// Adds the components and, indented one level deeper, their sub-components to the
+    /// lines of the text report. Only targeted components and their ancestors are added if
+    /// `targets` is set.
+    fn push_component_lines(
+        &self,
+        lines: &mut Vec<String>,
+        components: &[&Component],
+        targets: Option<&HashSet<String>>,
+        depth: usize,
+    ) {
+        let indent = "  ".repeat(depth);
+        for &component in components {
+            if let Some(targets) = targets {
+                if !has_targeted_component(component, targets) {
+                    continue;
+                }
+            }
+
+            lines.push(format!("{indent}- {}", component_name(component)));
+            if let Some(version) = &component.version {
+                lines.push(format!("{indent}  Version: {version}"));
+            }
+            if component.modified == Some(true) {
+                lines.push(format!("{indent}  Modified: yes"));
+            }
+            if let Some(notes) = component
+                .pedigree
+                .as_ref()
+                .and_then(|pedigree| pedigree.notes.as_ref())
+            {
+                lines.push(format!("{indent}  Notes: {}", self.redacted(notes)));
+            }
+            if let Some(purl) = component.purl.as_ref().filter(|_| self.purl_links) {
+                match self.purl_link(component) {
+                    Some(url) => lines.push(format!("{indent}  Purl: {purl} <{url}>")),
+                    None => lines.push(format!("{indent}  Purl: {purl}")),
+                }
+            }
+            for (label, text) in self.supplier_details(component) {
+                lines.push(format!("{indent}  {label}: {text}"));
+            }
+            for property in self.property_entries(component.properties.as_ref()) {
+                lines.push(format!("{indent}  Property: {property}"));
+            }
+            if let Some(sub_components) = &component.components {
+                self.push_component_lines(
+                    lines,
+                    &ordered_components(&sub_components.0, self.sort_components),
+                    targets,
+                    depth + 1,
+                );
+            }
+        }
+    }
+
+    /// Generates a PDF report from a CycloneDX VEX document.
+    ///
+    /// # Arguments
+    ///
+    /// * `vex` - The CycloneDX VEX document to convert
+    /// * `output_path` - Path where the PDF report will be saved
+    ///
+    /// # Returns
+    ///
+    /// Result indicating success or an error with details. Failing to render or write the
+    /// PDF is reported as an error instead of aborting the process
+    pub fn generate_pdf<P: AsRef<Path>>(&self, vex: &Bom, output_path: P) -> Result<(), io::Error> {
+        self.generate_pdf_with_source(vex, output_path, &ReportSource::default())
+    }
+
+    /// Generates a PDF report like [`PdfGenerator::generate_pdf`] and names the input the
+    /// report was generated from in the Document Information section.
+    ///
+    /// # Arguments
+    ///
+    /// * `vex` - The CycloneDX VEX document to convert
+    /// * `output_path` - Path where the PDF report will be saved
+    /// * `source` - Input document the report is generated from
+    pub fn generate_pdf_with_source<P: AsRef<Path>>(
+        &self,
+        vex: &Bom,
+        output_path: P,
+        source: &ReportSource<'_>,
+    ) -> Result<(), io::Error> {
+        // Set up the document with default fonts
+
+        let document_title = self.document_title();
+        let pdf_title = self
+            .pdf_meta_name
+            .filter(|title| !title.trim().is_empty())
+            .unwrap_or(Self::get_default_pdf_meta_name());
+
+        // Empty names render as placeholders, point authors to the malformed components
+        let unnamed = vex
+            .components
+            .as_ref()
+            .map_or(0, |components| count_components(&components.0, &is_unnamed))
+            + vex
+                .metadata
+                .as_ref()
+                .and_then(|metadata| metadata.component.as_ref())
+                .map_or(0, |component| usize::from(is_unnamed(component)));
+        if unnamed > 0 {
+            warn(format!(
+                "{unnamed} components have an empty name, rendering them as {UNNAMED_COMPONENT}"
+            ));
+        }
+
+        // Number the links of this report, they are added once the PDF was written
+        let link_collection = LinkCollection::start();
+        let mut doc = Document::new(self.font_family());
 
         doc.set_title(pdf_title);
         let mut decorator = genpdf::SimplePageDecorator::new();
         decorator.set_margins(10);
-        let header_title = document_title.to_string();
+        let header_title = self.header_text.unwrap_or(document_title).to_string();
+        let org_name = self.org_name.map(str::to_string);
+        let page_number_offset = self.page_number_offset;
         decorator.set_header(move |page| {
             let mut layout = genpdf::elements::LinearLayout::vertical();
-            if page > 1 {
+            if let Some(number) = displayed_page_number(page, page_number_offset) {
+                if let Some(org_name) = &org_name {
+                    layout.push(
+                        Paragraph::default()
+                            .styled_string(org_name, Style::new().bold())
+                            .aligned(Alignment::Right),
+                    );
+                }
                 layout.push(Paragraph::new(&header_title).aligned(Alignment::Left));
 
-                layout.push(Paragraph::new(format!("Page {}", page)).aligned(Alignment::Center));
+                layout.push(Paragraph::new(format!("Page {}", number)).aligned(Alignment::Center));
                 layout.push(genpdf::elements::Break::new(2));
             }
             layout.styled(
@@ -183,305 +3551,871 @@ impl<'a> PdfGenerator<'a> {
             )
         });
 
-        doc.set_page_decorator(decorator);
+        // A loose printed page can be traced back to its source document
+        let footer = self
+            .meta_footer
+            .then(|| meta_footer_text(vex, self.na_text));
+        let page_decorator =
+            ReportPageDecorator::new(decorator, self.page_border, self.page_background)
+                .with_footer(footer)
+                .with_classification(self.classification.map(str::to_string));
+        let page_counter = page_decorator.page_counter();
+        doc.set_page_decorator(page_decorator);
 
-        // Add title and basic information
-        doc.push(Paragraph::default().styled_string(document_title, self.title_style));
-        doc.push(genpdf::elements::Break::new(1.0));
-
-        // Add metadata if available
-        if let Some(metadata) = &vex.metadata {
-            doc.push(Paragraph::default().styled_string("Document Information", self.header_style));
-            doc.push(genpdf::elements::Break::new(1));
+        // Add the custom cover page if configured, or the built-in title otherwise
+        if let Some(template) = self.cover_template {
+            self.push_cover(&mut doc, template, document_title, vex);
+        } else {
+            doc.push(Paragraph::default().styled_string(document_title, self.title_style));
+            // When the report was produced, as opposed to the BOM timestamp in the metadata
+            if let Some(report_date) = self.report_date_text(vex) {
+                doc.push(
+                    Paragraph::default()
+                        .styled_string("Report date: ", self.normal_style.bold())
+                        .styled_string(report_date, self.normal_style),
+                );
+            }
+        }
 
-            // Add timestamp if available
-            if let Some(timestamp) = &metadata.timestamp {
+        // Surface the scanner provenance right below the title, reviewers check it first
+        if self.show_generated_by {
+            if let Some(tool) = vex
+                .metadata
+                .as_ref()
+                .and_then(|metadata| metadata.tools.as_ref())
+                .and_then(primary_tool)
+            {
                 doc.push(
                     Paragraph::default()
-                        .styled_string(format!("Date: {}", timestamp), self.normal_style),
+                        .styled_string("Generated by: ", self.normal_style.bold())
+                        .styled_string(tool, self.normal_style),
                 );
             }
+        }
+        // A clean report of incomplete data must not be mistaken for a clean product
+        if let Some(notice) = vulnerability_completeness_notice(vex) {
+            doc.push(self.section_break());
+            doc.push(
+                Paragraph::new(notice)
+                    .aligned(Alignment::Center)
+                    .padded(genpdf::Margins::vh(4, 0))
+                    .framed()
+                    .styled(self.normal_style.bold().with_color(Color::Rgb(180, 0, 0))),
+            );
+        }
+        doc.push(self.section_break());
 
-            doc.push(genpdf::elements::Break::new(1));
+        self.render_metadata(&mut doc, vex, source);
 
-            // Add tools information if available
-            if let Some(tools) = &metadata.tools {
-                doc.push(Paragraph::default().styled_string("Tools:", self.normal_style));
+        if let Some(previous) = self.previous_bom {
+            self.push_changes(&mut doc, previous, vex);
+        }
 
-                let mut ul_tools = genpdf::elements::UnorderedList::new();
-
-                match tools {
-                    Tools::List(tools_list) => {
-                        for tool in tools_list {
-                            if let Some(tool_name) = &tool.name {
-                                ul_tools.push(
-                                    Paragraph::default()
-                                        .styled_string(tool_name.to_string(), self.indent_style),
-                                );
-                            }
-                        }
-                    }
-                    Tools::Object {
-                        services: services_obj,
-                        components: components_obj,
-                    } => {
-                        // Handle components used as tools
-                        if let Some(components) = &components_obj {
-                            for component in &components.0 {
-                                let component_name = &component.name;
-                                let display_name = if let Some(version) = &component.version {
-                                    format!("{} (v{})", component_name, version)
-                                } else {
-                                    component_name.clone().to_string()
-                                };
-
-                                ul_tools.push(
-                                    Paragraph::default()
-                                        .styled_string(&display_name, self.indent_style),
-                                );
-                            }
-                        }
+        self.render_completeness(&mut doc, vex);
+        self.render_vulnerabilities(&mut doc, vex);
+        self.render_annotations(&mut doc, vex);
+        if self.show_components {
+            self.render_components(&mut doc, vex);
+        }
+        self.render_services(&mut doc, vex);
+        self.render_tools_appendix(&mut doc, vex);
+        if self.license_appendix {
+            self.render_license_appendix(&mut doc, vex);
+        }
+        // Warnings of the rendering itself, e.g. page limits, are noticed too late to be listed
+        self.render_notes(&mut doc, &collected_notes());
+        if let Some(disclaimer) = self.disclaimer {
+            self.render_disclaimer(&mut doc, disclaimer);
+        }
 
-                        // Handle services used as tools
-                        if let Some(services) = &services_obj {
-                            for service in &services.0 {
-                                let service_name = &service.name;
-                                let display_name = if let Some(version) = &service.version {
-                                    format!("{} (v{})", service_name, version)
-                                } else {
-                                    service_name.clone().to_string()
-                                };
-
-                                ul_tools.push(
-                                    Paragraph::default()
-                                        .styled_string(&display_name, self.indent_style),
-                                );
-                            }
-                        }
-                    }
-                }
+        // Render into memory first so that only writing the file is retried
+        let mut rendered = Vec::new();
+        doc.render(&mut rendered)
+            .map_err(|e| io::Error::other(format!("failed to render PDF: {e}")))?;
+        retry_io(self.io_retries, || fs::write(&output_path, &rendered))
+            .map_err(|e| io::Error::other(format!("failed to write PDF: {e}")))?;
 
-                doc.push(ul_tools);
-                doc.push(genpdf::elements::Break::new(1));
+        // genpdf embeds the complete fonts, keep only the glyphs drawn in the report
+        if self.subset_fonts {
+            if let Err(err) = subset_fonts(&output_path) {
+                warn(format!("failed to subset fonts: {err}"));
             }
+        }
 
-            if let Some(component) = &metadata.component {
-                doc.push(
-                    Paragraph::default()
-                        .styled_string("Component name : ", self.normal_style)
-                        .styled_string(component.name.to_string(), self.indent_style),
-                );
-                if let Some(version) = component.version.as_ref() {
-                    doc.push(
-                        Paragraph::default()
-                            .styled_string("Version: ", self.normal_style)
-                            .styled_string(format!("{}", version), self.indent_style),
-                    );
-                }
+        let links = link_collection.finish();
+        if !links.is_empty() {
+            if let Err(err) = add_links(&output_path, &links) {
+                warn(format!("failed to add links: {err}"));
             }
-
-            doc.push(genpdf::elements::Break::new(1.0));
         }
 
-        // Add basic BOM information
-        doc.push(Paragraph::default().styled_string("BOM Format: CycloneDX", self.normal_style));
-        doc.push(Paragraph::default().styled_string(
-            format!("Specification Version: {}", vex.spec_version),
-            self.normal_style,
-        ));
-        doc.push(
-            Paragraph::default()
-                .styled_string(format!("Version: {}", vex.version), self.normal_style),
-        );
+        // Mirror the info dictionary as XMP for tools that only read XMP metadata
+        let author = vex
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.authors.as_ref())
+            .map(|authors| {
+                authors
+                    .iter()
+                    .filter_map(|author| author.name.as_ref().map(|name| name.to_string()))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            })
+            .filter(|author| !author.is_empty())
+            .map(|author| self.redacted(&author));
+        // White-label reports may credit another tool or none at all
+        let keywords = match self.attribution {
+            Some(name) if name == Config::get_default_attribution() => {
+                vec![format!("{name}/{}", env!("CARGO_PKG_VERSION"))]
+            }
+            Some(name) => vec![name.to_string()],
+            None => Vec::new(),
+        };
+        let report_metadata = ReportMetadata {
+            title: pdf_title.to_string(),
+            author,
+            subject: Some(document_title.to_string()),
+            creation_date: self.report_time(vex).unwrap_or_default(),
+            keywords,
+            creator_tool: self.attribution.map(str::to_string),
+            deterministic: self.deterministic,
+        };
+        if let Err(err) = embed_metadata(&output_path, &report_metadata) {
+            warn(format!("failed to embed XMP metadata: {err}"));
+        }
 
-        if let Some(serial) = &vex.serial_number {
-            doc.push(
-                Paragraph::default()
-                    .styled_string(format!("Serial Number: {}", serial), self.normal_style),
-            );
+        if let Some(version) = self.pdf_version {
+            if let Err(err) = set_pdf_version(&output_path, version) {
+                warn(format!("failed to set PDF version {version}: {err}"));
+            }
         }
 
-        doc.push(genpdf::elements::Break::new(2.0));
+        // Runs last so that objects left behind by the other passes are removed as well
+        if self.optimize {
+            if let Err(err) = optimize_pdf(&output_path) {
+                warn(format!("failed to optimize PDF: {err}"));
+            }
+        }
 
-        // First determine if vulnerabilities exist
-        let mut vulns_available = false;
-        if let Some(vulnerabilities) = &vex.vulnerabilities {
-            vulns_available = !vulnerabilities.0.is_empty();
+        let page_count = page_counter.get();
+        if self.max_pages > 0 && page_count > self.max_pages {
+            warn(format!(
+                "generated report has {page_count} pages which exceeds the limit of {} pages. \
+                 Check the input for a runaway BOM before distributing the report",
+                self.max_pages
+            ));
         }
 
-        // Decide if we should show the vulnerabilities section at all
-        let show_vulns_section = vulns_available || self.show_novulns_msg;
+        Ok(())
+    }
+}
 
-        if show_vulns_section {
-            doc.push(Paragraph::default().styled_string("Vulnerabilities", self.header_style));
-            doc.push(genpdf::elements::Break::new(1.0));
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-        if let Some(vulnerabilities) = &vex.vulnerabilities {
-            let mut ordered_list = genpdf::elements::OrderedList::new();
+    // tests for private helpers that cannot be tested in lib
+    #[test]
+    fn test_component_name_replaces_empty_names() {
+        let vex = crate::lib_utils::run_utils::parse_vex_json_bytes(
+            br#"{
+                "bomFormat": "CycloneDX",
+                "specVersion": "1.5",
+                "version": 1,
+                "components": [
+                    {"type": "library", "name": "openssl"},
+                    {"type": "library", "name": " "}
+                ]
+            }"#,
+        )
+        .expect("failed to parse test document");
+        let components = &vex.components.as_ref().unwrap().0;
 
-            // Add each vulnerability
-            for vuln in &vulnerabilities.0 {
-                let mut vuln_layout = genpdf::elements::LinearLayout::vertical();
+        assert_eq!(component_name(&components[0]), "openssl");
+        assert_eq!(component_name(&components[1]), UNNAMED_COMPONENT);
+        assert_eq!(count_components(components, &is_unnamed), 1);
+    }
 
-                let id_paragraph = if let Some(vuln_id) = &vuln.id {
-                    Paragraph::default()
-                        .styled_string("ID: ", self.normal_style)
-                        .styled_string(format!("{}", vuln_id), self.normal_style)
-                } else {
-                    Paragraph::default().styled_string("ID: N/A", self.normal_style)
-                };
+    #[test]
+    fn test_primary_tool_prefers_first_named_tool() {
+        use cyclonedx_bom::models::tool::Tool;
+        use cyclonedx_bom::prelude::NormalizedString;
 
-                vuln_layout.push(id_paragraph);
+        let tools = Tools::List(vec![
+            Tool {
+                name: None,
+                ..Tool::default()
+            },
+            Tool {
+                name: Some(NormalizedString::new("trivy")),
+                version: Some(NormalizedString::new("v0.50.1")),
+                ..Tool::default()
+            },
+        ]);
 
-                let desc_paragraph = if let Some(desc) = &vuln.description {
-                    Paragraph::default()
-                        .styled_string("Description: ", self.indent_style.bold())
-                        .styled_string(desc, self.indent_style)
-                } else {
-                    Paragraph::default()
-                        .styled_string("Description: ", self.indent_style.bold())
-                        .styled_string("N/A", self.indent_style)
-                };
+        assert_eq!(primary_tool(&tools), Some("trivy v0.50.1".to_string()));
+        assert_eq!(primary_tool(&Tools::List(vec![])), None);
+    }
 
-                vuln_layout.push(desc_paragraph);
-                vuln_layout.push(genpdf::elements::Break::new(0.5));
+    #[test]
+    fn test_tools_list_is_collapsed_above_tools_max() {
+        use cyclonedx_bom::models::tool::Tool;
+        use cyclonedx_bom::prelude::NormalizedString;
 
-                // --- Analysis (CycloneDX 'analysis' / Rust: vulnerability_analysis) ---
-                if let Some(analysis) = &vuln.vulnerability_analysis {
-                    // Überschrift
-                    vuln_layout.push(
-                        Paragraph::default().styled_string("Analysis:", self.indent_style.bold())
-                    );
-                
-                    // state
-                    if let Some(state) = analysis.state.as_ref() {
-                        vuln_layout.push(
-                            Paragraph::default()
-                                .styled_string("  state: ", self.indent_style.bold())
-                                .styled_string(fmt_analysis_state(state), self.indent_style)
-                        );
-                    }
-                
-                    // detail
-                    if let Some(detail) = analysis.detail.as_deref() {
-                        if !detail.is_empty() {
-                            vuln_layout.push(
-                                Paragraph::default()
-                                    .styled_string("  detail: ", self.indent_style.bold())
-                                    .styled_string(detail, self.indent_style)
-                            );
-                        }
-                    }
-                
-                    if let Some(just) = analysis.justification.as_ref() {
-                        let txt = just.to_string();
-                        vuln_layout.push(
-                            Paragraph::default()
-                                .styled_string("  justification: ", self.indent_style.bold())
-                                .styled_string(txt, self.indent_style)
-                        );
-                    }
-                    // optional: responses ausgeben
-                    // if let Some(rs) = analysis.responses.as_ref() { ... }
-                
-                    vuln_layout.push(genpdf::elements::Break::new(0.5));
-                }
-                
-                let mut ratings_list = genpdf::elements::UnorderedList::new();
-
-                if let Some(ratings) = &vuln.vulnerability_ratings {
-                    for rating in &ratings.0 {
-                        let rating_method = if let Some(method) = &rating.score_method {
-                            method.to_string()
-                        } else {
-                            "N/A".to_string()
-                        };
-
-                        let source_str: Option<String> =
-                            rating.vulnerability_source.as_ref().and_then(|source| {
-                                source
-                                    .name
-                                    .as_ref()
-                                    .map(|source_name| source_name.to_string())
-                            });
-
-                        if let Some(severity) = &rating.severity {
-                            // add Severity ratings and sources
-
-                            let mut severity_par = Paragraph::default()
-                                .styled_string("Severity: ", self.indent_style.bold())
-                                .styled_string(
-                                    format!("{} ({}", severity, rating_method),
-                                    self.indent_style,
-                                );
-
-                            if let Some(source_name) = source_str {
-                                severity_par = severity_par
-                                    .styled_string(" — Source: ", self.indent_style)
-                                    .styled_string(source_name, self.indent_style);
-                            }
-
-                            severity_par = severity_par.styled_string(")", self.indent_style);
-                            ratings_list.push(severity_par);
-                        }
-                    }
-                }
-                vuln_layout.push(ratings_list);
-                vuln_layout.push(genpdf::elements::Break::new(1));
-                ordered_list.push(vuln_layout);
-            }
+        let tool = |name: &str| Tool {
+            name: Some(NormalizedString::new(name)),
+            ..Tool::default()
+        };
+        let names = tool_names(&Tools::List(vec![
+            tool("trivy"),
+            Tool::default(),
+            tool("grype"),
+            tool("syft"),
+        ]));
+        assert_eq!(names, vec!["trivy", "grype", "syft"]);
 
-            // list_layout.push(ordered_list);
-            doc.push(ordered_list);
-            doc.push(genpdf::elements::Break::new(0.5));
-        }
+        let mut generator = PdfGenerator::default();
+        assert!(!generator.is_tools_list_collapsed(&names));
 
-        //Add message if vulns are not available
-        if !vulns_available && self.show_novulns_msg {
-            let vulns_style = Style::new()
-                .bold()
-                .with_font_size(16)
-                .with_color(Color::Rgb(0, 100, 0));
+        generator.tools_max = 3;
+        assert!(!generator.is_tools_list_collapsed(&names));
 
-            doc.push(
-                Paragraph::new("No Vulnerabilities reported")
-                    .aligned(Alignment::Center)
-                    .padded(genpdf::Margins::vh(10, 0))
-                    .framed()
-                    .styled(vulns_style),
-            );
-            doc.push(genpdf::elements::Break::new(1.0));
-        }
+        generator.tools_max = 2;
+        assert!(generator.is_tools_list_collapsed(&names));
+    }
 
-        // Add Components section if available
-        if self.show_components {
-            if let Some(components) = &vex.components {
-                doc.push(Paragraph::default().styled_string("Components", self.header_style));
-                doc.push(genpdf::elements::Break::new(0.5));
+    #[test]
+    fn test_sort_ratings_by_severity_puts_most_severe_first() {
+        use cyclonedx_bom::models::vulnerability_rating::Score;
 
-                for component in &components.0 {
-                    doc.push(
-                        Paragraph::default()
-                            .styled_string(format!("Name: {}", component.name), self.normal_style),
-                    );
+        let rating = |score: Option<f32>, severity: Option<Severity>| VulnerabilityRating {
+            score: score.map(Score::from),
+            severity,
+            score_method: None,
+            vector: None,
+            vulnerability_source: None,
+            justification: None,
+        };
+        let low = rating(None, Some(Severity::Low));
+        let unrated = rating(None, None);
+        let critical_by_score = rating(Some(9.8), None);
+        let medium = rating(None, Some(Severity::Medium));
 
-                    if let Some(version) = &component.version {
-                        doc.push(
-                            Paragraph::default()
-                                .styled_string(format!("Version: {}", version), self.indent_style),
-                        );
-                    }
+        let mut ratings = vec![&low, &unrated, &critical_by_score, &medium];
+        sort_ratings_by_severity(&mut ratings, &SeverityBands::default());
+        assert_eq!(ratings, vec![&critical_by_score, &medium, &low, &unrated]);
+
+        // Stricter bands can only lower the severity derived from the score
+        let bands = SeverityBands::new(9.9, 8.0, 5.0).unwrap();
+        let mut ratings = vec![&critical_by_score, &medium, &low, &unrated];
+        sort_ratings_by_severity(&mut ratings, &bands);
+        assert_eq!(ratings, vec![&critical_by_score, &medium, &low, &unrated]);
+        assert_eq!(
+            bands.rating_severity(&critical_by_score),
+            Some(Severity::High)
+        );
+    }
+
+    #[test]
+    fn test_dedup_ratings_keeps_first_of_identical_ratings() {
+        use cyclonedx_bom::models::vulnerability_rating::{Score, ScoreMethod};
+        use cyclonedx_bom::prelude::NormalizedString;
+
+        let rating = |score: f32, method: ScoreMethod, vector: &str| VulnerabilityRating {
+            score: Some(Score::from(score)),
+            severity: Some(Severity::High),
+            score_method: Some(method),
+            vector: Some(NormalizedString::new(vector)),
+            vulnerability_source: None,
+            justification: None,
+        };
+        let first = rating(7.5, ScoreMethod::CVSSv31, "AV:N");
+        // Identical apart from the vector, which is not compared
+        let repeated = rating(7.5, ScoreMethod::CVSSv31, "AV:L");
+        let other_method = rating(7.5, ScoreMethod::CVSSv3, "AV:N");
+        let other_score = rating(7.8, ScoreMethod::CVSSv31, "AV:N");
+
+        let mut ratings = vec![&first, &repeated, &other_method, &other_score, &first];
+        dedup_ratings(&mut ratings);
+
+        assert_eq!(ratings, vec![&first, &other_method, &other_score]);
+    }
+
+    #[test]
+    fn test_format_score_uses_locale_decimal_separator() {
+        assert_eq!(format_score(8.1, None), "8.1");
+        assert_eq!(format_score(8.1, Some("en_US")), "8.1");
+        assert_eq!(format_score(8.1, Some("de")), "8,1");
+        assert_eq!(format_score(7.0, Some("fr_FR.UTF-8")), "7,0");
+    }
+
+    #[test]
+    fn test_component_descriptor_includes_type_and_version() {
+        assert_eq!(
+            component_descriptor("application", Some("2.3.1")),
+            "application v2.3.1"
+        );
+        assert_eq!(
+            component_descriptor("library", Some("v1.0")),
+            "library v1.0"
+        );
+        assert_eq!(component_descriptor("firmware", None), "firmware");
+    }
+
+    #[test]
+    fn test_annotations_from_json() {
+        let json = serde_json::json!({
+            "annotations": [{
+                "subjects": ["pkg:cargo/fixture-lib@1.0.0"],
+                "annotator": { "individual": { "name": "Jane Doe" } },
+                "timestamp": "2025-05-01T12:00:00Z",
+                "text": "Confirmed unreachable during triage"
+            }]
+        });
+
+        assert_eq!(
+            annotations_from_json(&json),
+            vec![AnnotationEntry {
+                text: "Confirmed unreachable during triage".to_string(),
+                subjects: vec!["pkg:cargo/fixture-lib@1.0.0".to_string()],
+                annotator: Some("Jane Doe".to_string()),
+                timestamp: Some("2025-05-01T12:00:00Z".to_string()),
+            }]
+        );
+        assert!(annotations_from_json(&serde_json::json!({})).is_empty());
+    }
+
+    #[test]
+    fn test_vulnerability_aggregate_from_json() {
+        let json = serde_json::json!({
+            "compositions": [
+                {"aggregate": "incomplete", "assemblies": ["pkg:cargo/fixture-lib@1.0.0"]},
+                {"aggregate": "complete", "vulnerabilities": ["vuln-1"]},
+                {"aggregate": "incomplete_first_party_only", "vulnerabilities": ["vuln-2"]}
+            ]
+        });
+        assert_eq!(
+            vulnerability_aggregate_from_json(&json).as_deref(),
+            Some("incomplete_first_party_only")
+        );
+
+        let complete = serde_json::json!({
+            "compositions": [{"aggregate": "complete", "vulnerabilities": ["vuln-1"]}]
+        });
+        assert_eq!(vulnerability_aggregate_from_json(&complete), None);
+        assert_eq!(
+            vulnerability_aggregate_from_json(&serde_json::json!({})),
+            None
+        );
+    }
+
+    #[test]
+    fn test_days_since_epoch() {
+        assert_eq!(days_since_epoch("1970-01-01"), Some(0));
+        assert_eq!(days_since_epoch("2000-03-01T00:00:00Z"), Some(11_017));
+        assert_eq!(days_since_epoch("2024-12-31T23:59:59+02:00"), Some(20_088));
+        assert_eq!(days_since_epoch("2024-13-01"), None);
+        assert_eq!(days_since_epoch("yesterday"), None);
+    }
+
+    #[test]
+    fn test_fmt_justification() {
+        let vex = crate::lib_utils::run_utils::parse_vex_json_bytes(
+            br#"{
+                "bomFormat": "CycloneDX",
+                "specVersion": "1.5",
+                "version": 1,
+                "vulnerabilities": [
+                    {"id": "CVE-2025-0001", "analysis": {"justification": "code_not_reachable"}},
+                    {"id": "CVE-2025-0002", "analysis": {"justification": "requires_configuration"}},
+                    {"id": "CVE-2025-0003", "analysis": {"justification": "protected_by_mitigating_control"}}
+                ]
+            }"#,
+        )
+        .expect("failed to parse test document");
+
+        let phrases: Vec<String> = vex
+            .vulnerabilities
+            .iter()
+            .flat_map(|vulns| vulns.0.iter())
+            .filter_map(|vuln| vuln.vulnerability_analysis.as_ref()?.justification.as_ref())
+            .map(fmt_justification)
+            .collect();
+        assert_eq!(
+            phrases,
+            vec![
+                "Code not reachable",
+                "Requires configuration",
+                "Protected by mitigating control"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_seconds_since_epoch() {
+        assert_eq!(seconds_since_epoch("1970-01-02"), Some(86_400));
+        assert_eq!(
+            seconds_since_epoch("2025-05-01T12:00:00Z"),
+            Some(1_746_100_800)
+        );
+        assert_eq!(seconds_since_epoch("2025-05-01T12:00:00+02:00"), None);
+        assert_eq!(seconds_since_epoch("2025-05-01T24:00:00Z"), None);
+        assert_eq!(seconds_since_epoch("2025-05-01 12:00"), None);
+    }
+
+    #[test]
+    fn test_timestamp_from_epoch() {
+        assert_eq!(timestamp_from_epoch(0), "1970-01-01T00:00:00Z");
+        assert_eq!(timestamp_from_epoch(951_782_400), "2000-02-29T00:00:00Z");
+        assert_eq!(timestamp_from_epoch(1_746_100_800), "2025-05-01T12:00:00Z");
+        assert_eq!(timestamp_from_epoch(-1), "1969-12-31T23:59:59Z");
+        assert_eq!(
+            days_since_epoch(&timestamp_from_epoch(1_746_100_800)),
+            days_since_epoch("2025-05-01")
+        );
+    }
+
+    #[test]
+    fn test_with_severity_icon() {
+        let mut generator = PdfGenerator::default();
+        assert_eq!(
+            generator.with_severity_icon(Some(&Severity::High), "high".to_string()),
+            "high"
+        );
+
+        generator.severity_icons = true;
+        assert_eq!(
+            generator.with_severity_icon(Some(&Severity::High), "high".to_string()),
+            "▲ high"
+        );
+        assert_eq!(
+            generator.with_severity_icon(Some(&Severity::None), "none".to_string()),
+            "none"
+        );
+        assert_eq!(generator.with_severity_icon(None, "N/A".to_string()), "N/A");
+    }
+
+    #[test]
+    fn test_cvss_breakdown_text() {
+        use cyclonedx_bom::prelude::NormalizedString;
+
+        let vector = "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/E:P/RL:O/RC:C";
+        let rating = VulnerabilityRating {
+            score: None,
+            severity: Some(Severity::Critical),
+            score_method: None,
+            vector: Some(NormalizedString::new(vector)),
+            vulnerability_source: None,
+            justification: None,
+        };
+
+        let mut generator = PdfGenerator::default();
+        assert_eq!(generator.cvss_breakdown_text(&rating), None);
+
+        generator.cvss_breakdown = true;
+        assert_eq!(
+            generator.cvss_breakdown_text(&rating),
+            Some(format!("Base 9.8, Temporal 8.8 ({vector})"))
+        );
+
+        generator.lang = Some("de_DE.UTF-8");
+        assert_eq!(
+            generator.cvss_breakdown_text(&rating),
+            Some(format!("Base 9,8, Temporal 8,8 ({vector})"))
+        );
+    }
+
+    #[test]
+    fn test_severity_badge_combines_max_severity_and_score() {
+        let vex = crate::lib_utils::run_utils::parse_vex_json_bytes(
+            br#"{
+                "bomFormat": "CycloneDX",
+                "specVersion": "1.5",
+                "version": 1,
+                "vulnerabilities": [
+                    {"id": "CVE-2025-0001", "ratings": [{"severity": "low", "score": 2.5}, {"severity": "high", "score": 8.1}]},
+                    {"id": "CVE-2025-0002", "ratings": [{"severity": "medium"}]},
+                    {"id": "CVE-2025-0003"}
+                ]
+            }"#,
+        )
+        .expect("failed to parse test document");
+        let vulns = &vex.vulnerabilities.as_ref().unwrap().0;
+        let generator = PdfGenerator::default();
+
+        assert_eq!(
+            generator.severity_badge(&vulns[0]),
+            Some(("High (8.1)".to_string(), severity_color(&Severity::High)))
+        );
+        assert_eq!(
+            generator.severity_badge(&vulns[1]),
+            Some(("Medium".to_string(), severity_color(&Severity::Medium)))
+        );
+        assert_eq!(generator.severity_badge(&vulns[2]), None);
+    }
+
+    #[test]
+    fn test_group_vulns_by_severity_orders_groups_and_skips_empty_ones() {
+        let vex = crate::lib_utils::run_utils::parse_vex_json_bytes(
+            br#"{
+                "bomFormat": "CycloneDX",
+                "specVersion": "1.5",
+                "version": 1,
+                "vulnerabilities": [
+                    {"id": "CVE-2025-0001", "ratings": [{"severity": "low"}]},
+                    {"id": "CVE-2025-0002", "ratings": [{"severity": "low"}, {"score": 9.8}]},
+                    {"id": "CVE-2025-0003"},
+                    {"id": "CVE-2025-0004", "ratings": [{"severity": "medium"}]}
+                ]
+            }"#,
+        )
+        .expect("failed to parse test document");
+        let vulns: Vec<&Vulnerability> = vex.vulnerabilities.as_ref().unwrap().0.iter().collect();
+        let generator = PdfGenerator::default();
+
+        let groups: Vec<(String, Vec<String>)> =
+            group_vulns_by_severity(&vulns, |vuln| generator.max_severity(vuln))
+                .into_iter()
+                .map(|(title, vulns)| {
+                    let ids = vulns
+                        .iter()
+                        .map(|vuln| vuln.id.as_ref().unwrap().to_string())
+                        .collect();
+                    (title, ids)
+                })
+                .collect();
+
+        assert_eq!(
+            groups,
+            vec![
+                ("Critical".to_string(), vec!["CVE-2025-0002".to_string()]),
+                ("Medium".to_string(), vec!["CVE-2025-0004".to_string()]),
+                ("Low".to_string(), vec!["CVE-2025-0001".to_string()]),
+                ("Unrated".to_string(), vec!["CVE-2025-0003".to_string()]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_meta_footer_text() {
+        let vex = crate::lib_utils::run_utils::parse_vex_json_bytes(
+            br#"{
+                "bomFormat": "CycloneDX",
+                "specVersion": "1.5",
+                "serialNumber": "urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79",
+                "version": 1
+            }"#,
+        )
+        .expect("failed to parse test document");
+        assert_eq!(
+            meta_footer_text(&vex, "N/A"),
+            "Serial: urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79 | CycloneDX 1.5"
+        );
+
+        let vex = Bom {
+            serial_number: None,
+            ..vex
+        };
+        assert_eq!(meta_footer_text(&vex, "N/A"), "Serial: N/A | CycloneDX 1.5");
+    }
+
+    #[test]
+    fn test_vulnerability_sources_are_distinct() {
+        let vex = crate::lib_utils::run_utils::parse_vex_json_bytes(
+            br#"{
+                "bomFormat": "CycloneDX",
+                "specVersion": "1.5",
+                "version": 1,
+                "vulnerabilities": [
+                    {
+                        "id": "CVE-2025-0001",
+                        "source": {"name": "NVD"},
+                        "ratings": [{"source": {"name": "GitHub"}}, {"source": {"name": "NVD"}}]
+                    },
+                    {"id": "GHSA-xxxx-yyyy-zzzz", "source": {"name": "OSV"}},
+                    {"id": "CVE-2025-0002"}
+                ]
+            }"#,
+        )
+        .expect("failed to parse test document");
+
+        assert_eq!(
+            vulnerability_sources(&vex.vulnerabilities.unwrap().0),
+            vec!["NVD", "GitHub", "OSV"]
+        );
+    }
 
-                    doc.push(genpdf::elements::Break::new(0.5));
+    #[test]
+    fn test_decode_base64() {
+        assert_eq!(decode_base64("TWFu"), Some(b"Man".to_vec()));
+        assert_eq!(decode_base64("SGVs\nbG8="), Some(b"Hello".to_vec()));
+        assert_eq!(decode_base64("not base64!"), None);
+    }
+
+    #[test]
+    fn test_services_from_json_reads_data_flows() {
+        let json = serde_json::json!({
+            "services": [
+                {
+                    "name": "billing-api",
+                    "version": "2.1.0",
+                    "data": [
+                        {"flow": "inbound", "classification": "PII"},
+                        {"flow": "bi-directional", "classification": ""}
+                    ],
+                    "services": [{"name": "invoice-store"}]
                 }
-            }
-        }
+            ]
+        });
 
-        // Render the document
-        doc.render_to_file(output_path)
-            .expect("failed to write file");
+        assert_eq!(
+            services_from_json(&json),
+            vec![
+                ServiceEntry {
+                    name: "billing-api".to_string(),
+                    version: Some("2.1.0".to_string()),
+                    depth: 0,
+                    data_flows: vec![
+                        DataFlow {
+                            flow: "inbound".to_string(),
+                            classification: Some("PII".to_string()),
+                        },
+                        DataFlow {
+                            flow: "bi-directional".to_string(),
+                            classification: None,
+                        },
+                    ],
+                },
+                ServiceEntry {
+                    name: "invoice-store".to_string(),
+                    version: None,
+                    depth: 1,
+                    data_flows: Vec::new(),
+                },
+            ]
+        );
+    }
 
-        Ok(())
+    #[test]
+    fn test_license_texts_from_json_decodes_and_merges() {
+        let json = serde_json::json!({
+            "components": [
+                {
+                    "name": "lib-a",
+                    "licenses": [{"license": {"id": "MIT", "text": {"content": "TUlUIHRleHQ=", "encoding": "base64"}}}],
+                    "components": [
+                        {"name": "lib-b", "licenses": [{"license": {"id": "MIT", "text": {"content": "MIT text"}}}]}
+                    ]
+                },
+                {"name": "lib-c", "licenses": [{"expression": "MIT OR Apache-2.0"}]}
+            ],
+            "services": [
+                {"name": "api", "licenses": [{"license": {"name": "Custom", "text": {"content": "Custom text"}}}]}
+            ]
+        });
+
+        assert_eq!(
+            license_texts_from_json(&json),
+            vec![
+                LicenseText {
+                    license: "MIT".to_string(),
+                    text: "MIT text".to_string(),
+                    used_by: vec!["lib-a".to_string(), "lib-b".to_string()],
+                },
+                LicenseText {
+                    license: "Custom".to_string(),
+                    text: "Custom text".to_string(),
+                    used_by: vec!["api".to_string()],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_targeted_components_are_counted_with_ancestors() {
+        let vex = crate::lib_utils::run_utils::parse_vex_json_bytes(
+            br#"{
+                "bomFormat": "CycloneDX",
+                "specVersion": "1.5",
+                "version": 1,
+                "components": [
+                    {
+                        "type": "library",
+                        "name": "parent",
+                        "bom-ref": "parent",
+                        "components": [
+                            {"type": "library", "name": "vulnerable", "bom-ref": "vulnerable"},
+                            {"type": "library", "name": "sibling", "bom-ref": "sibling"}
+                        ]
+                    },
+                    {"type": "library", "name": "unrelated", "bom-ref": "unrelated"}
+                ],
+                "vulnerabilities": [{"id": "CVE-2025-0001", "affects": [{"ref": "vulnerable"}]}]
+            }"#,
+        )
+        .expect("failed to parse test document");
+        let components = &vex.components.as_ref().unwrap().0;
+        let targets = vulnerability_target_refs(&vex);
+
+        assert!(has_targeted_component(&components[0], &targets));
+        assert!(!has_targeted_component(&components[1], &targets));
+        assert_eq!(count_components(components, &|_| true), 4);
+        assert_eq!(
+            count_components(components, &|component| {
+                has_targeted_component(component, &targets)
+            }),
+            2
+        );
+    }
+
+    #[test]
+    fn test_is_in_date_range() {
+        let vex = crate::lib_utils::run_utils::parse_vex_json_bytes(
+            br#"{
+                "bomFormat": "CycloneDX",
+                "specVersion": "1.5",
+                "version": 1,
+                "vulnerabilities": [
+                    {"id": "CVE-2025-0001", "published": "2025-03-10T00:00:00Z"},
+                    {"id": "CVE-2025-0002", "updated": "2025-05-20T00:00:00Z"},
+                    {"id": "CVE-2025-0003"}
+                ]
+            }"#,
+        )
+        .expect("failed to parse test document");
+        let vulns = &vex.vulnerabilities.as_ref().unwrap().0;
+        let since = days_since_epoch("2025-04-01");
+        let until = days_since_epoch("2025-04-30");
+
+        assert!(!is_in_date_range(&vulns[0], since, None));
+        assert!(is_in_date_range(&vulns[0], None, until));
+        assert!(is_in_date_range(&vulns[1], since, None));
+        assert!(!is_in_date_range(&vulns[1], since, until));
+        assert!(is_in_date_range(&vulns[2], since, until));
+    }
+
+    #[test]
+    fn test_disclaimer_paragraphs_split_on_blank_lines() {
+        let text = "First line\nstill first\n\n\n  Second paragraph  \n";
+        assert_eq!(
+            disclaimer_paragraphs(text),
+            vec!["First line still first", "Second paragraph"]
+        );
+        assert!(disclaimer_paragraphs("\n \n").is_empty());
+    }
+
+    #[test]
+    fn test_listed_components_leave_out_the_metadata_component() {
+        let vex = crate::lib_utils::run_utils::parse_vex_json_bytes(
+            br#"{
+                "bomFormat": "CycloneDX",
+                "specVersion": "1.5",
+                "version": 1,
+                "metadata": {
+                    "component": {"type": "application", "name": "acme-shop", "version": "2.3.1"}
+                },
+                "components": [
+                    {"type": "application", "name": "acme-shop", "version": "2.3.1"},
+                    {"type": "library", "name": "acme-shop", "version": "2.3.0"}
+                ]
+            }"#,
+        )
+        .expect("failed to parse test document");
+        let mut generator = PdfGenerator::default();
+        assert_eq!(generator.listed_components(&vex).map(|c| c.len()), Some(2));
+
+        generator.dedup_metadata_component = true;
+        let listed = generator.listed_components(&vex).unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].version.as_ref().unwrap().to_string(), "2.3.0");
+
+        // A document listing only its metadata component has no Components section
+        let mut vex = vex;
+        vex.components.as_mut().unwrap().0.pop();
+        assert!(generator.listed_components(&vex).is_none());
+    }
+
+    #[test]
+    fn test_components_grouped_by_ecosystem() {
+        let vex = crate::lib_utils::run_utils::parse_vex_json_bytes(
+            br#"{
+                "bomFormat": "CycloneDX",
+                "specVersion": "1.5",
+                "version": 1,
+                "components": [
+                    {"type": "library", "name": "serde", "purl": "pkg:cargo/serde@1.0.219", "bom-ref": "serde"},
+                    {"type": "library", "name": "vendored-zlib", "bom-ref": "zlib"},
+                    {"type": "library", "name": "lodash", "purl": "pkg:npm/lodash@4.17.21", "bom-ref": "lodash"},
+                    {"type": "library", "name": "anyhow", "purl": "pkg:Cargo/anyhow@1.0.86", "bom-ref": "anyhow"}
+                ]
+            }"#,
+        )
+        .expect("failed to parse test document");
+        let components = &vex.components.as_ref().unwrap().0;
+        let names = |group: &[&Component]| -> Vec<String> {
+            group
+                .iter()
+                .map(|component| component_name(component))
+                .collect()
+        };
+
+        let groups = group_components_by_ecosystem(&ordered_components(components, false));
+        let titles: Vec<&str> = groups.iter().map(|(title, _)| title.as_str()).collect();
+        assert_eq!(titles, ["cargo", "npm", "unknown"]);
+        assert_eq!(names(&groups[0].1), ["serde", "anyhow"]);
+
+        // Numbers follow the grouped order of the Components section
+        let mut generator = PdfGenerator::default();
+        generator.group_by_ecosystem = true;
+        generator.sort_components = true;
+        let top_level = generator.top_level_components(components);
+        assert_eq!(
+            names(&top_level),
+            ["anyhow", "serde", "lodash", "vendored-zlib"]
+        );
+        let index = component_ref_index(&top_level, true);
+        assert_eq!(index["lodash"].0, 3);
+        assert_eq!(index["zlib"].0, 4);
+    }
+
+    #[test]
+    fn test_rating_text_keeps_sources_of_unscored_ratings() {
+        let vex = crate::lib_utils::run_utils::parse_vex_json_bytes(
+            br#"{
+                "bomFormat": "CycloneDX",
+                "specVersion": "1.5",
+                "version": 1,
+                "vulnerabilities": [
+                    {
+                        "id": "CVE-2025-10001",
+                        "ratings": [
+                            {"severity": "high", "method": "CVSSv31"},
+                            {"source": {"name": "GHSA"}},
+                            {}
+                        ]
+                    }
+                ]
+            }"#,
+        )
+        .expect("failed to parse test document");
+        let ratings = &vex.vulnerabilities.as_ref().unwrap().0[0]
+            .vulnerability_ratings
+            .as_ref()
+            .unwrap()
+            .0;
+        let generator = PdfGenerator::default();
+
+        let texts: Vec<Option<String>> = ratings
+            .iter()
+            .map(|rating| generator.rating_text(rating))
+            .collect();
+        assert_eq!(
+            texts,
+            vec![
+                Some("high (CVSSv31)".to_string()),
+                Some("N/A (N/A — Source: GHSA)".to_string()),
+                None,
+            ]
+        );
     }
 }
@@ -0,0 +1,552 @@
+//! Clickable links in generated reports.
+//!
+//! genpdf cannot create link annotations, so [`LinkedText`] draws a marker line across its
+//! text whose stroke color encodes the number of the link. After the document was
+//! rendered, [`add_links`] removes the markers from the page contents and adds a link
+//! annotation covering the area of each marker.
+//!
+//! Links within the report jump to an [`Anchored`] element, which draws a marker the same
+//! way. [`add_links`] turns the page and position of that marker into the destination of
+//! the links to the anchor.
+//!
+//! Links are numbered while a [`LinkCollection`] is active on the current thread. Outside
+//! of a collection linked text is rendered as plain text.
+//!
+
+use genpdf::elements::Paragraph;
+use genpdf::error::Error;
+use genpdf::render::Area;
+use genpdf::style::{Color, Style};
+use genpdf::{Context, Element, Mm, Position, RenderResult, Size};
+use lopdf::content::{Content, Operation};
+use lopdf::{dictionary, Dictionary, Object, ObjectId};
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+
+/// Red channel of marker colors in hundredths, the green and blue channels encode the link.
+/// PDF writers round numbers to two decimals, so channels are chosen in hundredths.
+const MARKER_RED: u32 = 7;
+
+/// Color of linked text
+pub const LINK_COLOR: Color = Color::Rgb(0, 0, 238);
+
+/// Number of links that can be encoded in the green and blue channels of a marker
+const MAX_LINKS: usize = 100 * 100;
+
+/// What a link number created while a [`LinkCollection`] is active stands for
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LinkTarget {
+    /// Link to an external URL
+    Uri(String),
+    /// Link to the anchor with the given name in the same document
+    Internal(String),
+    /// Position of the anchor with the given name, see [`Anchored`]
+    Anchor(String),
+}
+
+thread_local! {
+    /// Targets of the links created while a collection is active, indexed by link number
+    static LINKS: RefCell<Option<Vec<LinkTarget>>> = const { RefCell::new(None) };
+}
+
+/// Collects the targets of the links created on the current thread while it is alive.
+///
+/// Start a collection before building and rendering a document, then pass the targets
+/// returned by [`LinkCollection::finish`] to [`add_links`].
+pub struct LinkCollection {
+    _private: (),
+}
+
+impl LinkCollection {
+    /// Starts collecting links, replacing any collection already active on this thread
+    pub fn start() -> Self {
+        LINKS.with(|links| *links.borrow_mut() = Some(Vec::new()));
+        Self { _private: () }
+    }
+
+    /// Stops collecting and returns the targets of the collected links in link number order
+    pub fn finish(self) -> Vec<LinkTarget> {
+        LINKS
+            .with(|links| links.borrow_mut().take())
+            .unwrap_or_default()
+    }
+}
+
+impl Drop for LinkCollection {
+    fn drop(&mut self) {
+        LINKS.with(|links| links.borrow_mut().take());
+    }
+}
+
+/// Returns the number of a new link to `target`, `None` if no collection is active or the
+/// collection is full
+fn register_link(target: LinkTarget) -> Option<usize> {
+    LINKS.with(|links| {
+        let mut links = links.borrow_mut();
+        let links = links.as_mut()?;
+        if links.len() >= MAX_LINKS {
+            return None;
+        }
+        links.push(target);
+        Some(links.len() - 1)
+    })
+}
+
+/// Returns the stroke color of the marker of a link
+fn marker_color(link: usize) -> Color {
+    // Hundredths as written by PDF writers map back to the same channel values
+    let channel = |hundredths: u32| (f64::from(hundredths) * 2.55).round() as u8;
+    Color::Rgb(
+        channel(MARKER_RED),
+        channel((link / 100) as u32),
+        channel((link % 100) as u32),
+    )
+}
+
+/// Draws the marker of a link across an element of the given size rendered to `area`
+fn draw_marker(area: &Area<'_>, link: usize, size: Size) {
+    if size.width > Mm::from(0) && size.height > Mm::from(0) {
+        // The diagonal spans the rendered element, add_links turns it into the link area
+        area.draw_line(
+            vec![Position::new(0, 0), Position::new(size.width, size.height)],
+            Style::new().with_color(marker_color(link)),
+        );
+    }
+}
+
+/// Text that links to a URL or an anchor, rendered like a paragraph
+pub struct LinkedText {
+    paragraph: Paragraph,
+    /// Number of the link, `None` if the text is not linked
+    link: Option<usize>,
+}
+
+impl LinkedText {
+    /// Creates text linking to `url`. The text is rendered without a link if no
+    /// [`LinkCollection`] is active.
+    pub fn new(text: impl Into<String>, url: impl Into<String>, style: Style) -> Self {
+        Self {
+            paragraph: Paragraph::default().styled_string(text.into(), style),
+            link: register_link(LinkTarget::Uri(url.into())),
+        }
+    }
+
+    /// Creates text linking to the [`Anchored`] element named `anchor` in the same
+    /// document. The text is rendered without a link if no [`LinkCollection`] is active or
+    /// the anchor is not rendered.
+    pub fn internal(text: impl Into<String>, anchor: impl Into<String>, style: Style) -> Self {
+        Self {
+            paragraph: Paragraph::default().styled_string(text.into(), style),
+            link: register_link(LinkTarget::Internal(anchor.into())),
+        }
+    }
+}
+
+impl Element for LinkedText {
+    fn render(
+        &mut self,
+        context: &Context,
+        area: Area<'_>,
+        style: Style,
+    ) -> Result<RenderResult, Error> {
+        let result = self.paragraph.render(context, area.clone(), style)?;
+
+        if let Some(link) = self.link {
+            draw_marker(&area, link, result.size);
+        }
+
+        Ok(result)
+    }
+}
+
+/// An element that [`LinkedText::internal`] links can jump to, rendered like the wrapped
+/// element
+pub struct Anchored<E: Element> {
+    element: E,
+    /// Number of the anchor's marker until it was drawn, `None` outside a collection
+    anchor: Option<usize>,
+}
+
+impl<E: Element> Anchored<E> {
+    /// Wraps `element` into an anchor named `name`. Links jump to the top of the first page
+    /// the element is rendered on.
+    pub fn new(element: E, name: impl Into<String>) -> Self {
+        Self {
+            element,
+            anchor: register_link(LinkTarget::Anchor(name.into())),
+        }
+    }
+}
+
+impl<E: Element> Element for Anchored<E> {
+    fn render(
+        &mut self,
+        context: &Context,
+        area: Area<'_>,
+        style: Style,
+    ) -> Result<RenderResult, Error> {
+        let result = self.element.render(context, area.clone(), style)?;
+
+        if result.size.height > Mm::from(0) {
+            // Only the first part of an element split across pages is the destination
+            if let Some(anchor) = self.anchor.take() {
+                draw_marker(&area, anchor, result.size);
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+/// Returns the value of a numeric operand
+fn number(object: &Object) -> Option<f64> {
+    match object {
+        Object::Integer(value) => Some(*value as f64),
+        Object::Real(value) => Some(*value),
+        _ => None,
+    }
+}
+
+/// Returns the link encoded in the operands of a stroke color operation, `None` if the
+/// color is not a marker color
+fn marker_link(operands: &[Object]) -> Option<usize> {
+    let hundredths: Vec<u32> = operands
+        .iter()
+        .map(|operand| number(operand).map(|value| (value * 100.0).round() as u32))
+        .collect::<Option<_>>()?;
+    match hundredths.as_slice() {
+        [MARKER_RED, high, low] if *high < 100 && *low < 100 => {
+            Some(*high as usize * 100 + *low as usize)
+        }
+        _ => None,
+    }
+}
+
+/// Removes the link markers from content operations and returns the number and the
+/// rectangle `[x1, y1, x2, y2]` of each marker.
+///
+/// A marker is a stroked path drawn with a marker color. The color operation preceding the
+/// path is removed with it.
+fn take_markers(operations: &mut Vec<Operation>) -> Vec<(usize, [f64; 4])> {
+    let mut markers = Vec::new();
+    let mut kept = Vec::with_capacity(operations.len());
+    // Position in `kept` and link of the current stroke color operation
+    let mut color: Option<(usize, Option<usize>)> = None;
+    let mut path_start: Option<usize> = None;
+
+    for operation in operations.drain(..) {
+        match operation.operator.as_str() {
+            "RG" => color = Some((kept.len(), marker_link(&operation.operands))),
+            "m" => path_start = Some(kept.len()),
+            "l" => {}
+            "S" => {
+                if let (Some((color_at, Some(link))), Some(start)) = (color, path_start) {
+                    let points: Vec<f64> = kept[start..]
+                        .iter()
+                        .flat_map(|operation: &Operation| &operation.operands)
+                        .filter_map(number)
+                        .collect();
+                    let xs = points.iter().step_by(2);
+                    let ys = points.iter().skip(1).step_by(2);
+                    let rect = [
+                        xs.clone().cloned().fold(f64::INFINITY, f64::min),
+                        ys.clone().cloned().fold(f64::INFINITY, f64::min),
+                        xs.cloned().fold(f64::NEG_INFINITY, f64::max),
+                        ys.cloned().fold(f64::NEG_INFINITY, f64::max),
+                    ];
+                    markers.push((link, rect));
+
+                    // Drop the path and the color operation that only served the marker
+                    kept.truncate(start);
+                    if color_at < kept.len() {
+                        kept.remove(color_at);
+                    }
+                    color = None;
+                    path_start = None;
+                    continue;
+                }
+                path_start = None;
+            }
+            _ => path_start = None,
+        }
+        kept.push(operation);
+    }
+
+    *operations = kept;
+    markers
+}
+
+/// Returns the action of a link annotation to `target`, `None` for anchors and for links
+/// to anchors that were not rendered. `anchors` holds the page and top edge of each anchor.
+fn link_action(
+    target: &LinkTarget,
+    anchors: &HashMap<&str, (ObjectId, f64)>,
+) -> Option<Dictionary> {
+    match target {
+        LinkTarget::Uri(url) => Some(dictionary! {
+            "S" => "URI",
+            "URI" => Object::string_literal(url.as_str()),
+        }),
+        LinkTarget::Internal(name) => {
+            let (page_id, top) = anchors.get(name.as_str())?;
+            Some(dictionary! {
+                "S" => "GoTo",
+                // Explicit destination showing the anchor at the top of the window
+                "D" => vec![
+                    Object::Reference(*page_id),
+                    Object::Name(b"XYZ".to_vec()),
+                    Object::Null,
+                    Object::Real(*top),
+                    Object::Null,
+                ],
+            })
+        }
+        LinkTarget::Anchor(_) => None,
+    }
+}
+
+/// Replaces the link markers in the PDF at `path` with link annotations to `targets`, see
+/// the module documentation. Markers of unknown links are removed without a link.
+pub fn add_links<P: AsRef<Path>>(path: P, targets: &[LinkTarget]) -> Result<(), lopdf::Error> {
+    let path = path.as_ref();
+    let mut pdf = lopdf::Document::load(path)?;
+
+    // Links may jump to anchors on later pages, so all markers are collected first
+    let mut markers = Vec::new();
+    for page_id in pdf.get_pages().into_values() {
+        for content_id in pdf.get_page_contents(page_id) {
+            let stream = pdf.get_object_mut(content_id)?.as_stream_mut()?;
+            stream.decompress();
+            let mut content = stream.decode_content()?;
+            let page_markers = take_markers(&mut content.operations);
+            if page_markers.is_empty() {
+                continue;
+            }
+            stream.set_plain_content(Content::encode(&content)?);
+            markers.extend(
+                page_markers
+                    .into_iter()
+                    .map(|(link, rect)| (page_id, link, rect)),
+            );
+        }
+    }
+
+    // The first marker of an anchor is its destination
+    let mut anchors = HashMap::new();
+    for (page_id, link, rect) in &markers {
+        if let Some(LinkTarget::Anchor(name)) = targets.get(*link) {
+            anchors.entry(name.as_str()).or_insert((*page_id, rect[3]));
+        }
+    }
+
+    let mut annotations: BTreeMap<ObjectId, Vec<Dictionary>> = BTreeMap::new();
+    for (page_id, link, rect) in &markers {
+        if let Some(action) = targets
+            .get(*link)
+            .and_then(|target| link_action(target, &anchors))
+        {
+            annotations.entry(*page_id).or_default().push(dictionary! {
+                "Type" => "Annot",
+                "Subtype" => "Link",
+                "Rect" => rect.iter().map(|value| Object::Real(*value)).collect::<Vec<_>>(),
+                "Border" => vec![Object::Integer(0), Object::Integer(0), Object::Integer(0)],
+                "A" => action,
+            });
+        }
+    }
+
+    for (page_id, page_annotations) in annotations {
+        let annotation_ids: Vec<Object> = page_annotations
+            .into_iter()
+            .map(|annotation| pdf.add_object(annotation).into())
+            .collect();
+        let page = pdf.get_object_mut(page_id)?.as_dict_mut()?;
+        match page.get_mut(b"Annots").and_then(Object::as_array_mut) {
+            Ok(existing) => existing.extend(annotation_ids),
+            Err(_) => page.set("Annots", annotation_ids),
+        }
+    }
+
+    pdf.save(path)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn operation(operator: &str, operands: Vec<Object>) -> Operation {
+        Operation::new(operator, operands)
+    }
+
+    #[test]
+    fn test_marker_colors_survive_rounding() {
+        for link in [0, 1, 99, 100, 4_217, MAX_LINKS - 1] {
+            let operands: Vec<Object> = match marker_color(link) {
+                Color::Rgb(r, g, b) => [r, g, b]
+                    .iter()
+                    // Written with two decimals like PDF writers do
+                    .map(|channel| {
+                        Object::Real((f64::from(*channel) / 255.0 * 100.0).round() / 100.0)
+                    })
+                    .collect(),
+                _ => unreachable!("marker colors are RGB"),
+            };
+            assert_eq!(marker_link(&operands), Some(link));
+        }
+    }
+
+    #[test]
+    fn test_take_markers_removes_marker_paths_only() {
+        let mut operations = vec![
+            operation(
+                "RG",
+                vec![Object::Real(0.0), Object::Real(0.0), Object::Real(0.0)],
+            ),
+            operation("w", vec![Object::Real(0.85)]),
+            operation("m", vec![Object::Real(0.0), Object::Real(0.0)]),
+            operation("l", vec![Object::Real(10.0), Object::Real(0.0)]),
+            operation("S", vec![]),
+            operation(
+                "RG",
+                vec![Object::Real(0.07), Object::Real(0.0), Object::Real(0.03)],
+            ),
+            operation("m", vec![Object::Real(20.0), Object::Real(700.0)]),
+            operation("l", vec![Object::Real(120.0), Object::Real(690.0)]),
+            operation("S", vec![]),
+            operation("BT", vec![]),
+        ];
+
+        let markers = take_markers(&mut operations);
+
+        assert_eq!(markers, vec![(3, [20.0, 690.0, 120.0, 700.0])]);
+        let operators: Vec<&str> = operations
+            .iter()
+            .map(|operation| operation.operator.as_str())
+            .collect();
+        assert_eq!(operators, ["RG", "w", "m", "l", "S", "BT"]);
+    }
+
+    /// Returns the content of a page stroking the marker of `link` from `from` to `to`
+    fn marker_content(link: usize, from: (f64, f64), to: (f64, f64)) -> Vec<u8> {
+        let color = match marker_color(link) {
+            Color::Rgb(r, g, b) => [r, g, b]
+                .iter()
+                .map(|channel| Object::Real(f64::from(*channel) / 255.0))
+                .collect(),
+            _ => unreachable!("marker colors are RGB"),
+        };
+        Content {
+            operations: vec![
+                operation("RG", color),
+                operation("m", vec![Object::Real(from.0), Object::Real(from.1)]),
+                operation("l", vec![Object::Real(to.0), Object::Real(to.1)]),
+                operation("S", vec![]),
+            ],
+        }
+        .encode()
+        .expect("failed to encode content")
+    }
+
+    #[test]
+    fn test_add_links_jumps_to_anchors() {
+        let mut pdf = lopdf::Document::with_version("1.3");
+        let pages_id = pdf.new_object_id();
+        let contents = [
+            marker_content(0, (20.0, 700.0), (120.0, 690.0)),
+            marker_content(1, (20.0, 500.0), (180.0, 480.0)),
+        ];
+        let page_ids: Vec<ObjectId> = contents
+            .into_iter()
+            .map(|content| {
+                let content_id = pdf.add_object(lopdf::Stream::new(dictionary! {}, content));
+                pdf.add_object(dictionary! {
+                    "Type" => "Page",
+                    "Parent" => pages_id,
+                    "Contents" => content_id,
+                })
+            })
+            .collect();
+        pdf.objects.insert(
+            pages_id,
+            Object::Dictionary(dictionary! {
+                "Type" => "Pages",
+                "Kids" => page_ids.iter().map(|id| Object::Reference(*id)).collect::<Vec<_>>(),
+                "Count" => 2,
+                "MediaBox" => vec![0.into(), 0.into(), 595.into(), 842.into()],
+            }),
+        );
+        let catalog_id = pdf.add_object(dictionary! { "Type" => "Catalog", "Pages" => pages_id });
+        pdf.trailer.set("Root", catalog_id);
+
+        let path = std::env::temp_dir().join("test_add_links_jumps_to_anchors.pdf");
+        pdf.save(&path).expect("failed to save test pdf");
+        let targets = [
+            LinkTarget::Internal("component".to_string()),
+            LinkTarget::Anchor("component".to_string()),
+        ];
+        add_links(&path, &targets).expect("failed to add links");
+        let pdf = lopdf::Document::load(&path).expect("failed to load pdf");
+        std::fs::remove_file(&path).expect("Failed to remove temp file");
+
+        let pages: Vec<ObjectId> = pdf.get_pages().into_values().collect();
+        let annotations = |page_id: ObjectId| -> Vec<Dictionary> {
+            let page = pdf.get_dictionary(page_id).expect("missing page");
+            match page.get(b"Annots").and_then(Object::as_array) {
+                Ok(annotations) => annotations
+                    .iter()
+                    .map(|annotation| {
+                        let id = annotation
+                            .as_reference()
+                            .expect("annotation not referenced");
+                        pdf.get_dictionary(id).expect("missing annotation").clone()
+                    })
+                    .collect(),
+                Err(_) => Vec::new(),
+            }
+        };
+
+        // The anchor only serves as destination, the link jumps to its page and top edge
+        assert!(annotations(pages[1]).is_empty());
+        let links = annotations(pages[0]);
+        assert_eq!(links.len(), 1);
+        let action = links[0]
+            .get(b"A")
+            .and_then(Object::as_dict)
+            .expect("link without action");
+        assert_eq!(
+            action.get(b"S").and_then(Object::as_name).ok(),
+            Some(&b"GoTo"[..])
+        );
+        let destination = action
+            .get(b"D")
+            .and_then(Object::as_array)
+            .expect("link without destination");
+        assert_eq!(destination[0].as_reference().ok(), Some(pages[1]));
+        assert_eq!(destination[1].as_name().ok(), Some(&b"XYZ"[..]));
+        assert_eq!(number(&destination[3]), Some(500.0));
+    }
+
+    #[test]
+    fn test_links_are_only_numbered_in_a_collection() {
+        let uri = |url: &str| LinkTarget::Uri(url.to_string());
+        assert_eq!(register_link(uri("https://example.com")), None);
+
+        let collection = LinkCollection::start();
+        assert_eq!(register_link(uri("https://example.com/a")), Some(0));
+        assert_eq!(
+            register_link(LinkTarget::Anchor("component".to_string())),
+            Some(1)
+        );
+        assert_eq!(
+            collection.finish(),
+            [
+                uri("https://example.com/a"),
+                LinkTarget::Anchor("component".to_string())
+            ]
+        );
+
+        assert_eq!(register_link(uri("https://example.com")), None);
+    }
+}
@@ -0,0 +1,102 @@
+//! Appending of generated reports.
+//!
+//! Supports workflows where findings arrive over time: a base report is generated once and
+//! reports rendered for newly discovered vulnerabilities are appended to it later, instead
+//! of regenerating the whole document.
+//!
+
+use lopdf::{Document, Object, ObjectId};
+use std::path::Path;
+
+/// Page attributes that a page may inherit from its parent page tree node
+const INHERITABLE_PAGE_ATTRIBUTES: [&[u8]; 4] = [b"Resources", b"MediaBox", b"CropBox", b"Rotate"];
+
+/// Appends all pages of the `addition` PDF to the end of the `base` PDF.
+///
+/// The `base` file is rewritten in place; `addition` is left untouched. Document-level
+/// information such as the title and XMP metadata is kept from `base`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use vex2pdf::pdf::merge::append_pdf;
+///
+/// append_pdf("report.pdf", "new_findings.pdf").expect("failed to append report");
+/// ```
+pub fn append_pdf<P: AsRef<Path>, Q: AsRef<Path>>(
+    base: P,
+    addition: Q,
+) -> Result<(), lopdf::Error> {
+    let base = base.as_ref();
+    let mut base_doc = Document::load(base)?;
+    let mut addition_doc = Document::load(addition)?;
+
+    // move the object ids of the addition behind the ones of the base to avoid collisions
+    addition_doc.renumber_objects_with(base_doc.max_id + 1);
+
+    let addition_pages: Vec<ObjectId> = addition_doc.get_pages().into_values().collect();
+    for page_id in &addition_pages {
+        materialize_inherited_attributes(&mut addition_doc, *page_id)?;
+    }
+
+    base_doc.max_id = addition_doc.max_id;
+    base_doc.objects.extend(addition_doc.objects);
+
+    let pages_id = base_doc.catalog()?.get(b"Pages")?.as_reference()?;
+    for page_id in &addition_pages {
+        base_doc
+            .get_object_mut(*page_id)?
+            .as_dict_mut()?
+            .set("Parent", pages_id);
+    }
+
+    let pages = base_doc.get_object_mut(pages_id)?.as_dict_mut()?;
+    let page_count = pages.get(b"Count")?.as_i64()?;
+    let kids = pages.get_mut(b"Kids")?.as_array_mut()?;
+    kids.extend(
+        addition_pages
+            .iter()
+            .map(|page_id| Object::Reference(*page_id)),
+    );
+    pages.set("Count", page_count + addition_pages.len() as i64);
+
+    // the catalog and page tree of the addition are no longer referenced
+    base_doc.prune_objects();
+    base_doc.save(base)?;
+
+    Ok(())
+}
+
+/// Copies attributes a page inherits from its page tree ancestors onto the page itself,
+/// so that the page keeps them when it is moved into another page tree
+fn materialize_inherited_attributes(
+    doc: &mut Document,
+    page_id: ObjectId,
+) -> Result<(), lopdf::Error> {
+    let mut inherited = Vec::new();
+    let mut parent = doc
+        .get_dictionary(page_id)?
+        .get(b"Parent")
+        .and_then(Object::as_reference)
+        .ok();
+
+    while let Some(node_id) = parent {
+        let node = doc.get_dictionary(node_id)?;
+        for key in INHERITABLE_PAGE_ATTRIBUTES {
+            if let Ok(value) = node.get(key) {
+                inherited.push((key, value.clone()));
+            }
+        }
+        parent = node.get(b"Parent").and_then(Object::as_reference).ok();
+    }
+
+    let page = doc.get_object_mut(page_id)?.as_dict_mut()?;
+    // the nearest ancestor comes first and wins
+    for (key, value) in inherited {
+        if !page.has(key) {
+            page.set(key, value);
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,99 @@
+//! Size optimization of generated reports.
+//!
+//! genpdf leaves some streams uncompressed, and the post-processing passes for links and
+//! XMP metadata can leave objects behind that are no longer referenced. [`optimize_pdf`]
+//! rewrites a rendered file with its streams Flate-compressed and its unreferenced objects
+//! removed. Used by `VEX2PDF_OPTIMIZE`.
+//!
+//! lopdf cannot write compressed object streams, so objects outside of streams stay as they
+//! are. Embedded fonts are subset by [`crate::pdf::font_subset`].
+//!
+
+use lopdf::{Document, Object, Stream};
+use std::path::Path;
+
+/// Returns whether a stream holds an XMP packet. XMP packets must stay uncompressed so
+/// that tools can find them without parsing the PDF.
+fn is_metadata_stream(stream: &Stream) -> bool {
+    stream
+        .dict
+        .get(b"Type")
+        .and_then(Object::as_name)
+        .is_ok_and(|name| name == b"Metadata")
+}
+
+/// Compresses the uncompressed streams of the PDF at `path` except XMP packets, removes
+/// unreferenced objects and renumbers the remaining ones. The file is rewritten in place.
+///
+/// Loading and saving the document again costs roughly as much time as the other
+/// post-processing passes of a report.
+///
+/// # Examples
+///
+/// ```no_run
+/// use vex2pdf::pdf::optimize::optimize_pdf;
+///
+/// optimize_pdf("report.pdf").expect("failed to optimize report");
+/// ```
+pub fn optimize_pdf<P: AsRef<Path>>(path: P) -> Result<(), lopdf::Error> {
+    let path = path.as_ref();
+    let mut pdf = Document::load(path)?;
+
+    pdf.prune_objects();
+    for object in pdf.objects.values_mut() {
+        if let Object::Stream(stream) = object {
+            if is_metadata_stream(stream) {
+                stream.allows_compression = false;
+            }
+        }
+    }
+    // Streams that already have a filter are left as they are
+    pdf.compress();
+    pdf.renumber_objects();
+
+    pdf.save(path)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lopdf::dictionary;
+
+    #[test]
+    fn test_optimize_pdf_compresses_streams_and_drops_unused_objects() {
+        let mut pdf = Document::with_version("1.5");
+        let content_id = pdf.add_object(Stream::new(
+            dictionary! {},
+            b"BT /F1 12 Tf (Vulnerability Report) Tj ET\n".repeat(50),
+        ));
+        let metadata_id = pdf.add_object(Stream::new(
+            dictionary! { "Type" => "Metadata", "Subtype" => "XML" },
+            b"<x:xmpmeta xmlns:x=\"adobe:ns:meta/\"></x:xmpmeta>\n".repeat(50),
+        ));
+        pdf.add_object(Object::string_literal("unreferenced"));
+        let catalog_id = pdf.add_object(dictionary! {
+            "Type" => "Catalog",
+            "Metadata" => metadata_id,
+            "Contents" => content_id,
+        });
+        pdf.trailer.set("Root", catalog_id);
+
+        let path = std::env::temp_dir().join("test_optimize_pdf.pdf");
+        pdf.save(&path).expect("failed to save test pdf");
+        let size_before = std::fs::metadata(&path).unwrap().len();
+
+        optimize_pdf(&path).expect("failed to optimize pdf");
+        let size_after = std::fs::metadata(&path).unwrap().len();
+        let optimized = Document::load(&path).expect("failed to load optimized pdf");
+        std::fs::remove_file(&path).expect("Failed to remove temp file");
+
+        assert!(size_after < size_before);
+        assert_eq!(optimized.objects.len(), 3);
+        for object in optimized.objects.values() {
+            if let Object::Stream(stream) = object {
+                assert_eq!(stream.dict.has(b"Filter"), !is_metadata_stream(stream));
+            }
+        }
+    }
+}
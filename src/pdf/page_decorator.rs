@@ -0,0 +1,258 @@
+//! Page decoration for generated reports.
+//!
+//! genpdf's `SimplePageDecorator` only handles margins and a running header. This module
+//! wraps it to add optional page-level ornaments such as a border, a background tint, a
+//! footer line or a classification marking.
+//!
+
+use genpdf::error::Error;
+use genpdf::render::Area;
+use genpdf::style::{Color, Style};
+use genpdf::{Context, Margins, Mm, PageDecorator, Position, SimplePageDecorator, Size};
+use std::cell::Cell;
+use std::rc::Rc;
+
+/// Distance in mm between the page edge and the optional page border
+const BORDER_MARGIN: i32 = 5;
+
+/// Distance in mm between the bottom page edge and the top of the footer line, which is
+/// printed in the bottom margin inside the page border
+const FOOTER_TOP: f64 = 9.0;
+
+/// Font size of the footer line
+const FOOTER_FONT_SIZE: u8 = 8;
+
+/// Height in mm of the bands at the top and bottom page edges that hold the classification
+/// marking. The rest of the page decoration and the content move inwards by this height.
+const CLASSIFICATION_BAND: f64 = 7.0;
+
+/// Distance in mm between the page edge and the classification marking
+const CLASSIFICATION_OFFSET: f64 = 2.5;
+
+/// Font size of the classification marking
+const CLASSIFICATION_FONT_SIZE: u8 = 9;
+
+/// Color of the classification marking, set apart from the dark blue of header and footer
+const CLASSIFICATION_COLOR: Color = Color::Rgb(180, 0, 0);
+
+/// Distance in mm between the lines that fill an area, slightly less than the 1pt width
+/// of the lines genpdf draws so that neighbouring lines overlap
+const FILL_LINE_SPACING: f64 = 0.3;
+
+/// Fills the rectangle with the top left corner `origin` and the given size with a color.
+///
+/// genpdf has no fill primitive and draws all lines with the same width, so the rectangle
+/// is covered with horizontal lines close enough to overlap.
+pub(crate) fn fill_rect(area: &Area<'_>, origin: Position, size: Size, color: Color) {
+    let bottom = origin.y + size.height;
+    let mut y = origin.y + Mm::from(FILL_LINE_SPACING / 2.0);
+    while y < bottom {
+        area.draw_line(
+            vec![
+                Position::new(origin.x, y),
+                Position::new(origin.x + size.width, y),
+            ],
+            Style::new().with_color(color),
+        );
+        y = y + Mm::from(FILL_LINE_SPACING);
+    }
+}
+
+/// Page decorator used by `PdfGenerator` for every page of a report.
+///
+/// Draws the optional page background, border and footer, then delegates margins and
+/// the running header to the wrapped `SimplePageDecorator`.
+pub struct ReportPageDecorator {
+    inner: SimplePageDecorator,
+    /// Whether a thin border rectangle is drawn around each page
+    border: bool,
+    /// Background color that fills each page, if any
+    background: Option<Color>,
+    /// Text printed centered in the bottom margin of each page, if any
+    footer: Option<String>,
+    /// Marking printed centered at the top and bottom edge of each page, if any
+    classification: Option<String>,
+    /// Number of pages decorated so far, shared with the caller to read after rendering
+    page_count: Rc<Cell<usize>>,
+}
+
+impl ReportPageDecorator {
+    /// Creates a new decorator wrapping the given `SimplePageDecorator`
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - The decorator responsible for margins and the running header
+    /// * `border` - Whether a thin border is drawn around each page
+    /// * `background` - Optional color used to fill each page's background
+    pub fn new(inner: SimplePageDecorator, border: bool, background: Option<Color>) -> Self {
+        Self {
+            inner,
+            border,
+            background,
+            footer: None,
+            classification: None,
+            page_count: Rc::new(Cell::new(0)),
+        }
+    }
+
+    /// Prints the given text centered in the bottom margin of each page
+    pub fn with_footer(mut self, footer: Option<String>) -> Self {
+        self.footer = footer;
+        self
+    }
+
+    /// Prints the given classification marking, e.g. `UNCLASSIFIED//FOUO`, centered at the
+    /// top and bottom edge of each page
+    pub fn with_classification(mut self, classification: Option<String>) -> Self {
+        self.classification = classification;
+        self
+    }
+
+    /// Returns a handle to the page counter of this decorator.
+    ///
+    /// genpdf does not report how many pages a rendered document has, so the decorator
+    /// counts the pages it decorates. Read the handle after the document was rendered.
+    pub fn page_counter(&self) -> Rc<Cell<usize>> {
+        Rc::clone(&self.page_count)
+    }
+}
+
+impl PageDecorator for ReportPageDecorator {
+    fn decorate_page<'a>(
+        &mut self,
+        context: &Context,
+        mut area: Area<'a>,
+        style: Style,
+    ) -> Result<Area<'a>, Error> {
+        self.page_count.set(self.page_count.get() + 1);
+        let mut size = area.size();
+
+        if let Some(color) = self.background {
+            fill_rect(&area, Position::new(0, 0), size, color);
+        }
+
+        if let Some(classification) = &self.classification {
+            let marking_style = style
+                .bold()
+                .with_font_size(CLASSIFICATION_FONT_SIZE)
+                .with_color(CLASSIFICATION_COLOR);
+            let x = centered_x(
+                marking_style.str_width(&context.font_cache, classification),
+                size.width,
+            );
+            for y in [
+                Mm::from(CLASSIFICATION_OFFSET),
+                size.height - Mm::from(CLASSIFICATION_BAND) + Mm::from(CLASSIFICATION_OFFSET),
+            ] {
+                area.print_str(
+                    &context.font_cache,
+                    Position::new(x, y),
+                    marking_style,
+                    classification,
+                )?;
+            }
+
+            // Border, footer and content stay clear of the markings
+            area.add_margins(Margins::trbl(
+                CLASSIFICATION_BAND,
+                0,
+                CLASSIFICATION_BAND,
+                0,
+            ));
+            size = area.size();
+        }
+
+        if self.border {
+            let mut border_area = area.clone();
+            border_area.add_margins(BORDER_MARGIN);
+            let border_size = border_area.size();
+            border_area.draw_line(
+                vec![
+                    Position::new(0, 0),
+                    Position::new(border_size.width, 0),
+                    Position::new(border_size.width, border_size.height),
+                    Position::new(0, border_size.height),
+                    Position::new(0, 0),
+                ],
+                Style::new().with_color(Color::Rgb(0, 0, 80)),
+            );
+        }
+
+        if let Some(footer) = &self.footer {
+            let footer_style = style
+                .with_font_size(FOOTER_FONT_SIZE)
+                .with_color(Color::Rgb(0, 0, 80));
+            let x = centered_x(
+                footer_style.str_width(&context.font_cache, footer),
+                size.width,
+            );
+            area.print_str(
+                &context.font_cache,
+                Position::new(x, size.height - Mm::from(FOOTER_TOP)),
+                footer_style,
+                footer,
+            )?;
+        }
+
+        self.inner.decorate_page(context, area, style)
+    }
+}
+
+/// Returns the x position that centers a text of the given width on a line, 0 if the text
+/// is wider than the line
+fn centered_x(text_width: Mm, line_width: Mm) -> Mm {
+    if text_width < line_width {
+        (line_width - text_width) / 2.0
+    } else {
+        Mm::from(0)
+    }
+}
+
+/// Returns the page number shown in the header of a page, or `None` if the page has no
+/// header.
+///
+/// `page` is the one based page of the rendered document. The first `offset` pages are
+/// front matter, such as a cover, and are left unnumbered so that the page following them
+/// is numbered 1. The first page never has a header, as it carries the report title.
+///
+/// # Examples
+///
+/// ```
+/// use vex2pdf::pdf::page_decorator::displayed_page_number;
+///
+/// assert_eq!(displayed_page_number(1, 0), None);
+/// assert_eq!(displayed_page_number(2, 0), Some(2));
+/// assert_eq!(displayed_page_number(2, 1), Some(1));
+/// assert_eq!(displayed_page_number(3, 3), None);
+/// ```
+pub fn displayed_page_number(page: usize, offset: usize) -> Option<usize> {
+    if page > offset.max(1) {
+        Some(page - offset)
+    } else {
+        None
+    }
+}
+
+/// Parses a hex color string such as `#F5F5F5` or `f5f5f5` into a genpdf `Color`.
+///
+/// Returns `None` if the value is not a valid six digit hex color.
+///
+/// # Examples
+///
+/// ```
+/// use genpdf::style::Color;
+/// use vex2pdf::pdf::page_decorator::parse_hex_color;
+///
+/// assert_eq!(parse_hex_color("#F5F5F5"), Some(Color::Rgb(245, 245, 245)));
+/// assert_eq!(parse_hex_color("not a color"), None);
+/// ```
+pub fn parse_hex_color(value: &str) -> Option<Color> {
+    let hex = value.trim().trim_start_matches('#');
+    if hex.len() != 6 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let channel = |range: std::ops::Range<usize>| u8::from_str_radix(&hex[range], 16).ok();
+
+    Some(Color::Rgb(channel(0..2)?, channel(2..4)?, channel(4..6)?))
+}
@@ -0,0 +1,70 @@
+//! PDF version of generated reports.
+//!
+//! genpdf writes reports with a PDF 1.3 header. Some document management systems only
+//! accept specific versions, so [`set_pdf_version`] rewrites the header of a rendered file
+//! to the version selected with `VEX2PDF_PDF_VERSION`.
+//!
+//! The header is the only thing that changes. Reports use no feature newer than PDF 1.4,
+//! and the only 1.4 feature they use is the XMP metadata of [`crate::pdf::xmp`], which
+//! readers of older versions ignore.
+//!
+
+use lopdf::Document;
+use std::path::Path;
+
+/// Version of the PDF files written by genpdf
+pub const DEFAULT_PDF_VERSION: &str = "1.3";
+
+/// Versions the header of a report can be set to
+pub const PDF_VERSIONS: [&str; 5] = ["1.3", "1.4", "1.5", "1.6", "1.7"];
+
+/// Returns the version from the header of a PDF file, e.g. `1.3`
+pub fn pdf_version<P: AsRef<Path>>(path: P) -> Result<String, lopdf::Error> {
+    Ok(Document::load(path)?.version)
+}
+
+/// Sets the version in the header of the PDF at `path` to `version`, one of
+/// [`PDF_VERSIONS`]. The file is rewritten in place.
+///
+/// # Examples
+///
+/// ```no_run
+/// use vex2pdf::pdf::pdf_version::set_pdf_version;
+///
+/// set_pdf_version("report.pdf", "1.4").expect("failed to set PDF version");
+/// ```
+pub fn set_pdf_version<P: AsRef<Path>>(path: P, version: &str) -> Result<(), lopdf::Error> {
+    let path = path.as_ref();
+    let mut pdf = Document::load(path)?;
+    if pdf.version == version {
+        return Ok(());
+    }
+
+    pdf.version = version.to_string();
+    pdf.save(path)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lopdf::dictionary;
+
+    #[test]
+    fn test_set_pdf_version_rewrites_header() {
+        let mut pdf = Document::with_version(DEFAULT_PDF_VERSION);
+        let catalog_id = pdf.add_object(dictionary! { "Type" => "Catalog" });
+        pdf.trailer.set("Root", catalog_id);
+
+        let path = std::env::temp_dir().join("test_set_pdf_version.pdf");
+        pdf.save(&path).expect("failed to save test pdf");
+
+        set_pdf_version(&path, "1.4").expect("failed to set pdf version");
+        let content = std::fs::read(&path).expect("failed to read pdf");
+        let version = pdf_version(&path).expect("failed to load pdf");
+        std::fs::remove_file(&path).expect("Failed to remove temp file");
+
+        assert!(content.starts_with(b"%PDF-1.4"));
+        assert_eq!(version, "1.4");
+    }
+}
@@ -0,0 +1,81 @@
+//! Redaction of sensitive values in rendered text.
+//!
+//! Used by the PDF generator when `VEX2PDF_REDACT` is enabled to produce reports
+//! that can be shared externally without maintaining a sanitized copy of each BOM.
+//!
+
+/// Replacement text for redacted values
+pub const REDACTED: &str = "[REDACTED]";
+
+/// Redacts email addresses and any word containing one of the given patterns.
+///
+/// The text is processed word by word; every whitespace separated word that looks like
+/// an email address or contains one of `patterns` (case-insensitive) is replaced by
+/// `[REDACTED]`. Whitespace is normalized to single spaces, which matches how the
+/// generator renders text anyway.
+///
+/// # Examples
+///
+/// ```
+/// use vex2pdf::pdf::redaction::redact_text;
+///
+/// let patterns = vec!["intranet.example.com".to_string()];
+/// assert_eq!(
+///     redact_text("Contact jane@example.com or see https://intranet.example.com/x", &patterns),
+///     "Contact [REDACTED] or see [REDACTED]"
+/// );
+/// ```
+pub fn redact_text(text: &str, patterns: &[String]) -> String {
+    text.split_whitespace()
+        .map(|word| {
+            if is_email(word) || matches_pattern(word, patterns) {
+                REDACTED
+            } else {
+                word
+            }
+        })
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+/// Returns true if a word looks like an email address, ignoring surrounding punctuation
+fn is_email(word: &str) -> bool {
+    let word = word.trim_matches(|c: char| !c.is_alphanumeric());
+    match word.split_once('@') {
+        Some((local, domain)) => {
+            !local.is_empty() && domain.contains('.') && !domain.starts_with('.')
+        }
+        None => false,
+    }
+}
+
+/// Returns true if a word contains any of the patterns, ignoring case
+pub(crate) fn matches_pattern(word: &str, patterns: &[String]) -> bool {
+    let word = word.to_lowercase();
+    patterns
+        .iter()
+        .filter(|pattern| !pattern.is_empty())
+        .any(|pattern| word.contains(&pattern.to_lowercase()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_email() {
+        assert!(is_email("jane@example.com"));
+        assert!(is_email("<jane.doe@example.co.uk>,"));
+        assert!(!is_email("@example.com"));
+        assert!(!is_email("jane@localhost"));
+        assert!(!is_email("no-email-here"));
+    }
+
+    #[test]
+    fn test_redact_text_without_patterns_only_redacts_emails() {
+        assert_eq!(
+            redact_text("Reported by bob@corp.example via https://tracker", &[]),
+            "Reported by [REDACTED] via https://tracker"
+        );
+    }
+}
@@ -0,0 +1,102 @@
+//! Horizontal gauge visualizing a CVSS score.
+//!
+//! genpdf has no element for bar charts, so the gauge fills its track and bar with
+//! [`fill_rect`], the same way the page background is drawn by the page decorator.
+//!
+
+use crate::pdf::page_decorator::fill_rect;
+use genpdf::error::Error;
+use genpdf::render::Area;
+use genpdf::style::{Color, Style};
+use genpdf::{Context, Element, Position, RenderResult, Size};
+
+/// Highest score of the gauge scale
+pub const MAX_SCORE: f64 = 10.0;
+
+/// Width of the gauge in mm
+const GAUGE_WIDTH: f64 = 60.0;
+
+/// Height of the gauge in mm
+const GAUGE_HEIGHT: f64 = 3.0;
+
+/// Color of the unfilled part of the gauge
+const TRACK_COLOR: Color = Color::Rgb(225, 225, 225);
+
+/// A bar filled proportionally to a score on a scale from 0 to [`MAX_SCORE`]
+pub struct ScoreGauge {
+    /// Score clamped to the scale of the gauge
+    score: f64,
+    /// Color of the filled part
+    color: Color,
+}
+
+impl ScoreGauge {
+    /// Creates a gauge for the given score, scores outside the scale are clamped
+    ///
+    /// # Arguments
+    ///
+    /// * `score` - The score to visualize, usually a CVSS base score
+    /// * `color` - Color of the filled part, usually the color of the score's severity
+    pub fn new(score: f64, color: Color) -> Self {
+        Self {
+            score: score.clamp(0.0, MAX_SCORE),
+            color,
+        }
+    }
+
+    /// Returns the share of the gauge that is filled, between 0 and 1
+    pub fn fill_ratio(&self) -> f64 {
+        self.score / MAX_SCORE
+    }
+}
+
+impl Element for ScoreGauge {
+    fn render(
+        &mut self,
+        _context: &Context,
+        area: Area<'_>,
+        _style: Style,
+    ) -> Result<RenderResult, Error> {
+        let size = Size::new(GAUGE_WIDTH, GAUGE_HEIGHT);
+        if area.size().height < size.height {
+            // Not enough space left on this page, render the gauge on the next one
+            return Ok(RenderResult {
+                size: Size::new(0, 0),
+                has_more: true,
+            });
+        }
+
+        let bar = |width: f64, color: Color| {
+            fill_rect(
+                &area,
+                Position::new(0, 0),
+                Size::new(width, GAUGE_HEIGHT),
+                color,
+            );
+        };
+
+        bar(GAUGE_WIDTH, TRACK_COLOR);
+        if self.score > 0.0 {
+            bar(GAUGE_WIDTH * self.fill_ratio(), self.color);
+        }
+
+        Ok(RenderResult {
+            size,
+            has_more: false,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_score_is_clamped_to_scale() {
+        let black = Color::Rgb(0, 0, 0);
+
+        assert_eq!(ScoreGauge::new(8.1, black).fill_ratio(), 8.1 / MAX_SCORE);
+        assert_eq!(ScoreGauge::new(12.0, black).fill_ratio(), 1.0);
+        assert_eq!(ScoreGauge::new(-1.0, black).fill_ratio(), 0.0);
+    }
+}
@@ -0,0 +1,105 @@
+//! Customization of how each vulnerability is rendered.
+//!
+//! [`PdfGenerator`] builds the entry of every vulnerability in the detailed list of the
+//! Vulnerabilities section through a [`VulnerabilityRenderer`]. Library users can pass their
+//! own implementation with [`PdfGenerator::with_vulnerability_renderer`] to add fields or
+//! change the layout of an entry, while the rest of the report stays as it is. The compact
+//! table of `VEX2PDF_COMPACT` is not affected.
+//!
+
+use crate::pdf::generator::PdfGenerator;
+use cyclonedx_bom::models::vulnerability::Vulnerability;
+use genpdf::elements::LinearLayout;
+use genpdf::style::Style;
+use std::collections::HashMap;
+
+/// Builds the entry of a vulnerability in the Vulnerabilities section.
+///
+/// The default implementation renders the entry the way vex2pdf does without a custom
+/// renderer. Renderers are shared by all reports of a generator and must therefore be
+/// `Send` and `Sync`.
+///
+/// # Examples
+///
+/// ```
+/// use genpdf::elements::{LinearLayout, Paragraph};
+/// use vex2pdf::model::models::vulnerability::Vulnerability;
+/// use vex2pdf::pdf::generator::PdfGenerator;
+/// use vex2pdf::pdf::vulnerability_renderer::{VulnerabilityContext, VulnerabilityRenderer};
+///
+/// /// Adds the ticket of the internal tracker below every vulnerability
+/// struct TicketRenderer;
+///
+/// impl VulnerabilityRenderer for TicketRenderer {
+///     fn render_vulnerability(
+///         &self,
+///         vuln: &Vulnerability,
+///         context: &VulnerabilityContext<'_>,
+///     ) -> LinearLayout {
+///         let mut layout = context.default_element(vuln);
+///         layout.push(Paragraph::default().styled_string("Ticket: SEC-1234", context.normal_style()));
+///         layout
+///     }
+/// }
+///
+/// let renderer = TicketRenderer;
+/// let generator = PdfGenerator::default().with_vulnerability_renderer(&renderer);
+/// ```
+pub trait VulnerabilityRenderer: Send + Sync {
+    /// Builds the entry of `vuln`. `context` gives access to the default entry and the
+    /// styles of the report.
+    fn render_vulnerability(
+        &self,
+        vuln: &Vulnerability,
+        context: &VulnerabilityContext<'_>,
+    ) -> LinearLayout {
+        context.default_element(vuln)
+    }
+}
+
+/// Renders vulnerabilities the way vex2pdf does, used unless a custom renderer is set
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DefaultVulnerabilityRenderer;
+
+impl VulnerabilityRenderer for DefaultVulnerabilityRenderer {}
+
+/// State of the report a vulnerability is rendered in, passed to
+/// [`VulnerabilityRenderer::render_vulnerability`]
+pub struct VulnerabilityContext<'r> {
+    generator: &'r PdfGenerator<'r>,
+    /// Numbers and names of the components, keyed by `bom-ref`, see `VEX2PDF_CROSS_REFS`
+    component_refs: &'r HashMap<String, (usize, String)>,
+    /// Current date as days since the epoch, if aging vulnerabilities are flagged
+    today: Option<i64>,
+}
+
+impl<'r> VulnerabilityContext<'r> {
+    pub(crate) fn new(
+        generator: &'r PdfGenerator<'r>,
+        component_refs: &'r HashMap<String, (usize, String)>,
+        today: Option<i64>,
+    ) -> Self {
+        Self {
+            generator,
+            component_refs,
+            today,
+        }
+    }
+
+    /// Builds the entry vex2pdf renders for the vulnerability with the options of the
+    /// generator
+    pub fn default_element(&self, vuln: &Vulnerability) -> LinearLayout {
+        self.generator
+            .vulnerability_element(vuln, self.component_refs, self.today)
+    }
+
+    /// Style of regular text in the report
+    pub fn normal_style(&self) -> Style {
+        self.generator.normal_style()
+    }
+
+    /// Style of secondary, indented text in the report such as the details of a vulnerability
+    pub fn indent_style(&self) -> Style {
+        self.generator.indent_style()
+    }
+}
@@ -0,0 +1,331 @@
+//! XMP metadata embedding for generated reports.
+//!
+//! genpdf only writes the classic PDF info dictionary with the document title. Asset
+//! management systems that only read XMP need the same information as an XMP packet
+//! referenced from the document catalog, so this module post-processes the rendered
+//! file to add it.
+//!
+
+use lopdf::{dictionary, Object, Stream};
+use std::path::Path;
+
+/// Document metadata written to both the info dictionary and the XMP packet
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReportMetadata {
+    /// Title shown by PDF readers, the same as the info dictionary title
+    pub title: String,
+    /// Author of the report, usually the authors listed in the BOM metadata
+    pub author: Option<String>,
+    /// Subject of the report, usually the report title
+    pub subject: Option<String>,
+    /// Creation date in RFC 3339 format
+    pub creation_date: String,
+    /// Keywords added to the ones already present in the document
+    pub keywords: Vec<String>,
+    /// Tool credited as creator and producer of the document, omitted if `None`
+    pub creator_tool: Option<String>,
+    /// Whether the render time and random IDs written by the PDF backend are replaced by
+    /// values derived from this metadata, so that identical input yields identical files
+    pub deterministic: bool,
+}
+
+/// Embeds the metadata into an already rendered PDF file.
+///
+/// The author and subject are added to the info dictionary, and an XMP packet carrying
+/// title, author, subject, keywords and creation date replaces any packet referenced from
+/// the document catalog. Keywords already present in the info dictionary are kept and the
+/// new ones are appended to them.
+///
+/// In deterministic mode the info dictionary dates are set to the creation date, the
+/// document ID is derived from the metadata and objects no longer referenced, such as the
+/// XMP packet of the PDF backend, are removed.
+///
+/// # Arguments
+///
+/// * `path` - Path of the rendered PDF file, which is rewritten in place
+/// * `metadata` - The metadata to embed
+pub fn embed_metadata<P: AsRef<Path>>(
+    path: P,
+    metadata: &ReportMetadata,
+) -> Result<(), lopdf::Error> {
+    let path = path.as_ref();
+    let mut pdf = lopdf::Document::load(path)?;
+
+    let mut keywords = String::new();
+    if let Ok(info_id) = pdf.trailer.get(b"Info").and_then(Object::as_reference) {
+        let info = pdf.get_object_mut(info_id)?.as_dict_mut()?;
+        if let Some(author) = &metadata.author {
+            info.set("Author", Object::string_literal(author.as_str()));
+        }
+        if let Some(subject) = &metadata.subject {
+            info.set("Subject", Object::string_literal(subject.as_str()));
+        }
+
+        let existing = info
+            .get(b"Keywords")
+            .and_then(Object::as_str)
+            .map(|keywords| String::from_utf8_lossy(keywords).into_owned())
+            .unwrap_or_default();
+        keywords = merge_keywords(&existing, &metadata.keywords);
+        if !keywords.is_empty() {
+            info.set("Keywords", Object::string_literal(keywords.as_str()));
+        }
+
+        if metadata.deterministic {
+            if let Some(date) = pdf_date(&metadata.creation_date) {
+                info.set("CreationDate", Object::string_literal(date.as_str()));
+                info.set("ModDate", Object::string_literal(date));
+            }
+        }
+    }
+
+    // XMP packets must stay uncompressed so that tools can find them without parsing the PDF
+    let packet = xmp_packet(metadata, &keywords);
+    if metadata.deterministic {
+        // Both parts of the ID are random by default, derive them from the metadata instead
+        let id = document_id(&packet);
+        pdf.trailer.set(
+            "ID",
+            vec![
+                Object::string_literal(id.as_str()),
+                Object::string_literal(id),
+            ],
+        );
+    }
+
+    let stream = Stream::new(
+        dictionary! { "Type" => "Metadata", "Subtype" => "XML" },
+        packet.into_bytes(),
+    )
+    .with_compression(false);
+    let metadata_id = pdf.add_object(stream);
+
+    let catalog_id = pdf.trailer.get(b"Root").and_then(Object::as_reference)?;
+    pdf.get_object_mut(catalog_id)?
+        .as_dict_mut()?
+        .set("Metadata", metadata_id);
+
+    if metadata.deterministic {
+        // The replaced XMP packet of the PDF backend carries the render time and random IDs
+        pdf.prune_objects();
+    }
+
+    pdf.save(path)?;
+
+    Ok(())
+}
+
+/// Appends the keywords that are not yet part of the comma or semicolon separated
+/// `existing` keywords and returns the combined list separated by commas
+fn merge_keywords(existing: &str, additional: &[String]) -> String {
+    let mut keywords: Vec<&str> = existing
+        .split([',', ';'])
+        .map(str::trim)
+        .filter(|keyword| !keyword.is_empty())
+        .collect();
+    for keyword in additional {
+        if !keywords.contains(&keyword.as_str()) {
+            keywords.push(keyword);
+        }
+    }
+
+    keywords.join(", ")
+}
+
+/// Builds the XMP packet for the given metadata and the already merged keywords
+fn xmp_packet(metadata: &ReportMetadata, keywords: &str) -> String {
+    let mut dublin_core = format!(
+        "<dc:format>application/pdf</dc:format>\n\
+         <dc:title><rdf:Alt><rdf:li xml:lang=\"x-default\">{}</rdf:li></rdf:Alt></dc:title>\n",
+        escape_xml(&metadata.title)
+    );
+    if let Some(author) = &metadata.author {
+        dublin_core.push_str(&format!(
+            "<dc:creator><rdf:Seq><rdf:li>{}</rdf:li></rdf:Seq></dc:creator>\n",
+            escape_xml(author)
+        ));
+    }
+    if let Some(subject) = &metadata.subject {
+        dublin_core.push_str(&format!(
+            "<dc:description><rdf:Alt><rdf:li xml:lang=\"x-default\">{}</rdf:li></rdf:Alt></dc:description>\n",
+            escape_xml(subject)
+        ));
+    }
+
+    let pdf_keywords = if keywords.is_empty() {
+        String::new()
+    } else {
+        format!("<pdf:Keywords>{}</pdf:Keywords>\n", escape_xml(keywords))
+    };
+
+    let date = escape_xml(&metadata.creation_date);
+    let tool = match &metadata.creator_tool {
+        Some(tool) => {
+            let tool = escape_xml(tool);
+            format!(
+                "<xmp:CreatorTool>{tool}</xmp:CreatorTool>\n<pdf:Producer>{tool}</pdf:Producer>\n"
+            )
+        }
+        None => String::new(),
+    };
+
+    format!(
+        "<?xpacket begin=\"\u{feff}\" id=\"W5M0MpCehiHzreSzNTczkc9d\"?>\n\
+         <x:xmpmeta xmlns:x=\"adobe:ns:meta/\">\n\
+         <rdf:RDF xmlns:rdf=\"http://www.w3.org/1999/02/22-rdf-syntax-ns#\">\n\
+         <rdf:Description rdf:about=\"\" \
+         xmlns:dc=\"http://purl.org/dc/elements/1.1/\" \
+         xmlns:xmp=\"http://ns.adobe.com/xap/1.0/\" \
+         xmlns:pdf=\"http://ns.adobe.com/pdf/1.3/\">\n\
+         {dublin_core}\
+         <xmp:CreateDate>{date}</xmp:CreateDate>\n\
+         <xmp:ModifyDate>{date}</xmp:ModifyDate>\n\
+         <xmp:MetadataDate>{date}</xmp:MetadataDate>\n\
+         {tool}\
+         {pdf_keywords}\
+         </rdf:Description>\n\
+         </rdf:RDF>\n\
+         </x:xmpmeta>\n\
+         <?xpacket end=\"w\"?>"
+    )
+}
+
+/// Converts an RFC 3339 timestamp such as `2025-05-01T12:00:00Z` into the date format of
+/// the info dictionary, e.g. `D:20250501120000Z`. Fractional seconds are dropped.
+fn pdf_date(timestamp: &str) -> Option<String> {
+    let digits = |range: std::ops::Range<usize>| {
+        timestamp
+            .get(range)
+            .filter(|part| part.chars().all(|c| c.is_ascii_digit()))
+    };
+    let date_time = [
+        digits(0..4)?,
+        digits(5..7)?,
+        digits(8..10)?,
+        digits(11..13)?,
+        digits(14..16)?,
+        digits(17..19)?,
+    ]
+    .concat();
+
+    let offset = timestamp[19..].trim_start_matches(|c: char| c == '.' || c.is_ascii_digit());
+    let offset = match offset {
+        "Z" | "z" => "Z".to_string(),
+        _ => {
+            let (sign, hours, minutes) = (offset.get(..1)?, offset.get(1..3)?, offset.get(4..6)?);
+            if !["+", "-"].contains(&sign) {
+                return None;
+            }
+            format!("{sign}{hours}'{minutes}'")
+        }
+    };
+
+    Some(format!("D:{date_time}{offset}"))
+}
+
+/// Derives a 32 character hex document ID from the XMP packet using two FNV-1a hashes
+fn document_id(packet: &str) -> String {
+    let fnv1a = |seed: u64| {
+        packet.bytes().fold(seed, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        })
+    };
+
+    format!(
+        "{:016x}{:016x}",
+        fnv1a(0xcbf2_9ce4_8422_2325),
+        fnv1a(0x8422_2325_cbf2_9ce4)
+    )
+}
+
+/// Escapes the characters that are not allowed verbatim in XML text
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xmp_packet_contains_all_fields() {
+        let metadata = ReportMetadata {
+            title: "VEX Report".to_string(),
+            author: Some("Jane & Co".to_string()),
+            subject: Some("Vulnerability Report Document".to_string()),
+            creation_date: "2025-05-01T12:00:00Z".to_string(),
+            keywords: vec!["vex2pdf/1.0.0".to_string()],
+            creator_tool: Some("vex2pdf".to_string()),
+            deterministic: false,
+        };
+
+        let packet = xmp_packet(&metadata, "vex2pdf/1.0.0");
+
+        assert!(packet.contains("<rdf:li xml:lang=\"x-default\">VEX Report</rdf:li>"));
+        assert!(packet.contains("<dc:creator><rdf:Seq><rdf:li>Jane &amp; Co</rdf:li>"));
+        assert!(packet.contains(">Vulnerability Report Document</rdf:li>"));
+        assert!(packet.contains("<xmp:CreateDate>2025-05-01T12:00:00Z</xmp:CreateDate>"));
+        assert!(packet.contains("<pdf:Keywords>vex2pdf/1.0.0</pdf:Keywords>"));
+        assert!(packet.contains("<xmp:CreatorTool>vex2pdf</xmp:CreatorTool>"));
+    }
+
+    #[test]
+    fn test_xmp_packet_omits_missing_author_and_subject() {
+        let metadata = ReportMetadata {
+            title: "VEX Report".to_string(),
+            author: None,
+            subject: None,
+            creation_date: "2025-05-01T12:00:00Z".to_string(),
+            keywords: Vec::new(),
+            creator_tool: None,
+            deterministic: false,
+        };
+
+        let packet = xmp_packet(&metadata, "");
+
+        assert!(!packet.contains("dc:creator"));
+        assert!(!packet.contains("dc:description"));
+        assert!(!packet.contains("pdf:Keywords"));
+        assert!(!packet.contains("CreatorTool"));
+    }
+
+    #[test]
+    fn test_pdf_date_converts_rfc3339() {
+        assert_eq!(
+            pdf_date("2025-05-01T12:00:00Z"),
+            Some("D:20250501120000Z".to_string())
+        );
+        assert_eq!(
+            pdf_date("2025-05-01T12:00:00.123+02:00"),
+            Some("D:20250501120000+02'00'".to_string())
+        );
+        assert_eq!(pdf_date("2025-05-01"), None);
+    }
+
+    #[test]
+    fn test_document_id_is_stable() {
+        let id = document_id("<x:xmpmeta/>");
+
+        assert_eq!(id.len(), 32);
+        assert_eq!(id, document_id("<x:xmpmeta/>"));
+        assert_ne!(id, document_id("<x:xmpmeta />"));
+    }
+
+    #[test]
+    fn test_merge_keywords_keeps_existing_keywords() {
+        let tool_keyword = vec!["vex2pdf/1.0.0".to_string()];
+
+        assert_eq!(merge_keywords("", &tool_keyword), "vex2pdf/1.0.0");
+        assert_eq!(
+            merge_keywords("security; sbom", &tool_keyword),
+            "security, sbom, vex2pdf/1.0.0"
+        );
+        assert_eq!(
+            merge_keywords("vex2pdf/1.0.0, sbom", &tool_keyword),
+            "vex2pdf/1.0.0, sbom"
+        );
+    }
+}
@@ -0,0 +1,1193 @@
+//! Renders fixture documents and asserts on the text of the produced PDFs.
+//!
+//! These tests catch fields that silently stop rendering, which the serialization
+//! round-trip tests of the library cannot detect.
+
+#![cfg(feature = "pdf")]
+
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+use vex2pdf::lib_utils::config::Config;
+use vex2pdf::lib_utils::run_utils::parse_vex_json_bytes;
+use vex2pdf::lib_utils::warnings::{warn, NoteCollection};
+use vex2pdf::model::models::vulnerability::Vulnerability;
+use vex2pdf::pdf::font_config::FontsDir;
+use vex2pdf::pdf::generator::{ComponentColumn, PdfGenerator, ReportSource};
+use vex2pdf::pdf::vulnerability_renderer::{VulnerabilityContext, VulnerabilityRenderer};
+
+/// Minimal document without any vulnerability information
+const BOM_WITHOUT_VULNERABILITIES: &str = r#"{
+    "bomFormat": "CycloneDX",
+    "specVersion": "1.5",
+    "version": 1
+}"#;
+
+/// Renders the fixture with the given configuration and returns the extracted text with
+/// all whitespace removed, since the extractor does not preserve line breaks and spacing
+fn render_fixture_text(fixture: &str, output_name: &str, config: &Config) -> String {
+    let fixture_path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join(fixture);
+    let content = fs::read(&fixture_path).expect("failed to read fixture");
+
+    render_text(&content, output_name, config)
+}
+
+/// Renders JSON content like [`render_fixture_text`]
+fn render_text(content: &[u8], output_name: &str, config: &Config) -> String {
+    let vex = parse_vex_json_bytes(content).expect("failed to parse document");
+
+    let output_path = std::env::temp_dir().join(output_name);
+    PdfGenerator::from_config(config)
+        .generate_pdf(&vex, &output_path)
+        .expect("failed to generate pdf");
+
+    let text = pdf_extract::extract_text(&output_path).expect("failed to extract pdf text");
+    fs::remove_file(&output_path).expect("Failed to remove temp file");
+
+    text.split_whitespace().collect()
+}
+
+/// Renders the fixture like [`render_fixture_text`] and returns the bytes of the PDF
+fn render_fixture_pdf(fixture: &str, output_name: &str, config: &Config) -> Vec<u8> {
+    let fixture_path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join(fixture);
+    let content = fs::read(&fixture_path).expect("failed to read fixture");
+    let vex = parse_vex_json_bytes(&content).expect("failed to parse document");
+
+    let output_path = std::env::temp_dir().join(output_name);
+    PdfGenerator::from_config(config)
+        .generate_pdf(&vex, &output_path)
+        .expect("failed to generate pdf");
+    let pdf = fs::read(&output_path).expect("failed to read pdf");
+    fs::remove_file(&output_path).expect("Failed to remove temp file");
+
+    pdf
+}
+
+/// Returns the number of pages of a rendered PDF
+fn page_count(pdf: &[u8]) -> usize {
+    lopdf::Document::load_mem(pdf)
+        .expect("failed to load pdf")
+        .get_pages()
+        .len()
+}
+
+/// Returns the number of stroked paths on all pages of a rendered PDF
+fn stroke_count(pdf: &[u8]) -> usize {
+    let pdf = lopdf::Document::load_mem(pdf).expect("failed to load pdf");
+    pdf.get_pages()
+        .into_values()
+        .map(|page_id| {
+            pdf.get_and_decode_page_content(page_id)
+                .expect("failed to decode page content")
+                .operations
+                .iter()
+                .filter(|operation| operation.operator == "S")
+                .count()
+        })
+        .sum()
+}
+
+/// Returns whether the raw PDF bytes contain `needle`, e.g. in uncompressed metadata
+fn contains_bytes(pdf: &[u8], needle: &str) -> bool {
+    pdf.windows(needle.len())
+        .any(|window| window == needle.as_bytes())
+}
+
+#[test]
+fn test_pdf_contains_vulnerability_id_and_severity() {
+    let text = render_fixture_text(
+        "sample_vex.json",
+        "test_pdf_content_vulnerability.pdf",
+        &Config::default(),
+    );
+
+    assert!(text.contains("CVE-2025-10001"), "vulnerability ID missing");
+    assert!(text.contains("Severity:high"), "severity missing");
+    assert!(
+        text.contains("Pathtraversalinfixture-libarchiveextraction"),
+        "description missing"
+    );
+    assert!(text.contains("exploitable"), "analysis state missing");
+}
+
+#[test]
+fn test_pdf_contains_metadata_component() {
+    let text = render_fixture_text(
+        "sample_vex.json",
+        "test_pdf_content_metadata.pdf",
+        &Config::default(),
+    );
+
+    assert!(
+        text.contains("acme-shop(applicationv2.3.1)"),
+        "metadata component missing"
+    );
+    assert!(text.contains("fixture-lib"), "component missing");
+}
+
+#[test]
+fn test_pdf_distinguishes_missing_and_empty_vulnerabilities() {
+    let missing = render_text(
+        BOM_WITHOUT_VULNERABILITIES.as_bytes(),
+        "test_pdf_content_missing_vulns.pdf",
+        &Config::default(),
+    );
+    assert!(missing.contains("Novulnerabilitydata"));
+    assert!(!missing.contains("Novulnerabilitiesfound"));
+
+    let empty_bom = BOM_WITHOUT_VULNERABILITIES.replace(
+        "\"version\": 1",
+        "\"version\": 1,\n    \"vulnerabilities\": []",
+    );
+    let empty = render_text(
+        empty_bom.as_bytes(),
+        "test_pdf_content_empty_vulns.pdf",
+        &Config::default(),
+    );
+    assert!(empty.contains("Novulnerabilitiesfound"));
+    assert!(!empty.contains("Novulnerabilitydata"));
+}
+
+#[test]
+fn test_pdf_groups_vulnerabilities_by_component() {
+    let config = Config {
+        group_by_component: true,
+        ..Config::default()
+    };
+    let text = render_fixture_text("sample_vex.json", "test_pdf_content_grouped.pdf", &config);
+
+    let component_group = text
+        .find("fixture-lib1.0.0(1)")
+        .expect("component group missing");
+    let unassigned_group = text
+        .find("Unassigned(1)")
+        .expect("unassigned group missing");
+    let assigned_vuln = text
+        .find("CVE-2025-10001")
+        .expect("vulnerability ID missing");
+    let unassigned_vuln = text
+        .find("CVE-2025-10002")
+        .expect("vulnerability ID missing");
+
+    assert!(component_group < assigned_vuln && assigned_vuln < unassigned_group);
+    assert!(unassigned_group < unassigned_vuln);
+}
+
+#[test]
+fn test_pdf_lists_changes_since_previous_document() {
+    let previous = parse_vex_json_bytes(BOM_WITHOUT_VULNERABILITIES.as_bytes())
+        .expect("failed to parse previous document");
+    let config = Config {
+        previous_bom: Some(previous),
+        ..Config::default()
+    };
+    let text = render_fixture_text("sample_vex.json", "test_pdf_content_changes.pdf", &config);
+
+    assert!(text.contains("ChangesSincePreviousReport"));
+    assert!(text.contains("Added(2)"));
+    assert!(text.contains("CVE-2025-10001(exploitable)"));
+}
+
+#[test]
+fn test_pdf_contains_annotations() {
+    let text = render_fixture_text(
+        "sample_vex.json",
+        "test_pdf_content_annotations.pdf",
+        &Config::default(),
+    );
+
+    assert!(text.contains("Annotations"), "annotations section missing");
+    assert!(text.contains("TriageTeam"), "annotator missing");
+    assert!(
+        text.contains("Uploadendpointisexposedtoauthenticatedusersonly"),
+        "annotation text missing"
+    );
+}
+
+#[test]
+fn test_custom_document_from_section_builders() {
+    let fixture_path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join("sample_vex.json");
+    let content = fs::read(&fixture_path).expect("failed to read fixture");
+    let vex = parse_vex_json_bytes(&content).expect("failed to parse document");
+
+    let generator = PdfGenerator::default();
+    let mut doc = genpdf::Document::new(FontsDir::build().font_family);
+    generator.render_vulnerabilities(&mut doc, &vex);
+
+    let output_path = std::env::temp_dir().join("test_pdf_content_custom_document.pdf");
+    doc.render_to_file(&output_path)
+        .expect("failed to render custom document");
+    let text: String = pdf_extract::extract_text(&output_path)
+        .expect("failed to extract pdf text")
+        .split_whitespace()
+        .collect();
+    fs::remove_file(&output_path).expect("Failed to remove temp file");
+
+    assert!(text.contains("CVE-2025-10001"), "vulnerability ID missing");
+    assert!(
+        !text.contains("DocumentInformation"),
+        "unrequested section rendered"
+    );
+}
+
+#[test]
+fn test_pdf_contains_pedigree_notes() {
+    let text = render_fixture_text(
+        "sample_vex.json",
+        "test_pdf_content_pedigree.pdf",
+        &Config::default(),
+    );
+
+    assert!(
+        text.contains("Notes:Forkedfromupstream-lib0.9withthearchivemodulerewritten"),
+        "pedigree notes missing"
+    );
+}
+
+#[test]
+fn test_pdf_contains_supplier_contacts() {
+    let text = render_fixture_text(
+        "sample_vex.json",
+        "test_pdf_content_contacts_hidden.pdf",
+        &Config::default(),
+    );
+    assert!(!text.contains("FixtureLabs"), "supplier shown by default");
+
+    let config = Config {
+        show_contacts: true,
+        ..Config::default()
+    };
+    let text = render_fixture_text("sample_vex.json", "test_pdf_content_contacts.pdf", &config);
+    assert!(text.contains("Supplier:FixtureLabs"), "supplier missing");
+    assert!(
+        text.contains("Contact:JaneDoe,jane.doe@example.com,+15550100"),
+        "supplier contact missing"
+    );
+
+    let config = Config {
+        show_contacts: true,
+        redact: true,
+        ..Config::default()
+    };
+    let text = render_fixture_text(
+        "sample_vex.json",
+        "test_pdf_content_contacts_redacted.pdf",
+        &config,
+    );
+    assert!(
+        !text.contains("jane.doe@example.com"),
+        "contact email not redacted"
+    );
+}
+
+#[test]
+fn test_pdf_lists_document_authors() {
+    let content = br#"{
+        "bomFormat": "CycloneDX",
+        "specVersion": "1.5",
+        "version": 1,
+        "metadata": {
+            "authors": [
+                {"name": "Jane Doe", "email": "jane.doe@example.com"},
+                {"name": "John Roe", "phone": "+15550101"},
+                {"name": "  "}
+            ]
+        }
+    }"#;
+    let text = render_text(content, "test_pdf_content_authors.pdf", &Config::default());
+    assert!(text.contains("Authors:"), "authors heading missing");
+    assert!(
+        text.contains("JaneDoe,jane.doe@example.com"),
+        "first author missing"
+    );
+    assert!(text.contains("JohnRoe,+15550101"), "second author missing");
+
+    let config = Config {
+        redact: true,
+        ..Config::default()
+    };
+    let text = render_text(content, "test_pdf_content_authors_redacted.pdf", &config);
+    assert!(text.contains("JaneDoe"), "redacted author name missing");
+    assert!(
+        !text.contains("jane.doe@example.com"),
+        "author email not redacted"
+    );
+}
+
+#[test]
+fn test_pdf_redacts_properties() {
+    let content = br#"{
+        "bomFormat": "CycloneDX",
+        "specVersion": "1.5",
+        "version": 1,
+        "metadata": {
+            "properties": [{"name": "owner", "value": "jane.doe@example.com"}]
+        },
+        "components": [{
+            "type": "library",
+            "name": "left-pad",
+            "version": "1.3.0",
+            "properties": [
+                {"name": "internal:build-host", "value": "ci-7"},
+                {"name": "channel", "value": "nightly"}
+            ]
+        }]
+    }"#;
+    let text = render_text(
+        content,
+        "test_pdf_content_properties.pdf",
+        &Config::default(),
+    );
+    assert!(
+        text.contains("owner:jane.doe@example.com"),
+        "metadata property missing"
+    );
+    assert!(
+        text.contains("Property:internal:build-host:ci-7"),
+        "component property missing"
+    );
+
+    let config = Config {
+        redact: true,
+        redact_patterns: vec!["internal".to_string()],
+        ..Config::default()
+    };
+    let text = render_text(content, "test_pdf_content_properties_redacted.pdf", &config);
+    assert!(
+        !text.contains("jane.doe@example.com"),
+        "property value not redacted"
+    );
+    assert!(
+        !text.contains("build-host") && !text.contains("ci-7"),
+        "property with a redacted name not redacted"
+    );
+    assert!(
+        text.contains("Property:channel:nightly"),
+        "unredacted property missing"
+    );
+}
+
+#[test]
+fn test_pdf_renders_placeholder_for_unnamed_components() {
+    let content = br#"{
+        "bomFormat": "CycloneDX",
+        "specVersion": "1.5",
+        "version": 1,
+        "components": [{"type": "library", "name": "  ", "version": "2.0.0"}]
+    }"#;
+    let text = render_text(content, "test_pdf_content_unnamed.pdf", &Config::default());
+
+    assert!(text.contains("Name:(unnamed)"), "placeholder missing");
+}
+
+#[test]
+fn test_pdf_notes_incomplete_vulnerability_data() {
+    let content = br#"{
+        "bomFormat": "CycloneDX",
+        "specVersion": "1.5",
+        "version": 1,
+        "vulnerabilities": [{"bom-ref": "vuln-1", "id": "CVE-2025-10001"}],
+        "compositions": [{"aggregate": "unknown", "vulnerabilities": ["vuln-1"]}]
+    }"#;
+    let text = render_text(
+        content,
+        "test_pdf_content_incomplete.pdf",
+        &Config::default(),
+    );
+
+    assert!(
+        text.contains("Note:vulnerabilitydataismarkedunknown"),
+        "completeness notice missing"
+    );
+
+    let text = render_fixture_text(
+        "sample_vex.json",
+        "test_pdf_content_complete.pdf",
+        &Config::default(),
+    );
+    assert!(!text.contains("vulnerabilitydataismarked"));
+}
+
+#[test]
+fn test_pdf_groups_components_by_ecosystem() {
+    let content = br#"{
+        "bomFormat": "CycloneDX",
+        "specVersion": "1.5",
+        "version": 1,
+        "components": [
+            {"type": "library", "name": "lodash", "purl": "pkg:npm/lodash@4.17.21"},
+            {"type": "library", "name": "vendored-zlib"},
+            {"type": "library", "name": "serde", "purl": "pkg:cargo/serde@1.0.219"}
+        ]
+    }"#;
+    let config = Config {
+        group_by_ecosystem: true,
+        ..Config::default()
+    };
+    let text = render_text(content, "test_pdf_content_ecosystems.pdf", &config);
+
+    let cargo = text.find("cargo(1)").expect("cargo group missing");
+    let npm = text.find("npm(1)").expect("npm group missing");
+    let unknown = text.find("unknown(1)").expect("unknown group missing");
+    assert!(cargo < text.find("Name:serde").unwrap());
+    assert!(cargo < npm && npm < unknown);
+    assert!(unknown < text.find("Name:vendored-zlib").unwrap());
+}
+
+/// Appends a tracker ticket to the default entry of every vulnerability
+struct TicketRenderer;
+
+impl VulnerabilityRenderer for TicketRenderer {
+    fn render_vulnerability(
+        &self,
+        vuln: &Vulnerability,
+        context: &VulnerabilityContext<'_>,
+    ) -> genpdf::elements::LinearLayout {
+        let mut layout = context.default_element(vuln);
+        let id = vuln
+            .id
+            .as_ref()
+            .map(|id| id.to_string())
+            .unwrap_or_default();
+        layout.push(
+            genpdf::elements::Paragraph::default()
+                .styled_string(format!("Ticket: SEC-{id}"), context.normal_style()),
+        );
+        layout
+    }
+}
+
+#[test]
+fn test_pdf_uses_custom_vulnerability_renderer() {
+    let fixture_path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join("sample_vex.json");
+    let vex = parse_vex_json_bytes(&fs::read(fixture_path).expect("failed to read fixture"))
+        .expect("failed to parse fixture");
+
+    let output_path = std::env::temp_dir().join("test_pdf_content_custom_renderer.pdf");
+    PdfGenerator::default()
+        .with_vulnerability_renderer(&TicketRenderer)
+        .generate_pdf(&vex, &output_path)
+        .expect("failed to generate pdf");
+    let text: String = pdf_extract::extract_text(&output_path)
+        .expect("failed to extract pdf text")
+        .split_whitespace()
+        .collect();
+    fs::remove_file(&output_path).expect("Failed to remove temp file");
+
+    // The default entry is kept and extended
+    assert!(text.contains("ID:CVE-2025-10001"), "default entry missing");
+    assert!(
+        text.contains("Ticket:SEC-CVE-2025-10001"),
+        "custom field missing"
+    );
+}
+
+#[test]
+fn test_pdf_section_spacing_moves_sections_apart() {
+    let compact = page_count(&render_fixture_pdf(
+        "sample_vex.json",
+        "test_pdf_content_spacing_default.pdf",
+        &Config::default(),
+    ));
+
+    let config = Config {
+        section_spacing: 30.0,
+        item_spacing: 10.0,
+        ..Config::default()
+    };
+    let spaced = page_count(&render_fixture_pdf(
+        "sample_vex.json",
+        "test_pdf_content_spacing_wide.pdf",
+        &config,
+    ));
+
+    assert!(
+        spaced > compact,
+        "{spaced} pages, expected more than {compact}"
+    );
+}
+
+#[test]
+fn test_pdf_renders_service_data_flows() {
+    let content = br#"{
+        "bomFormat": "CycloneDX",
+        "specVersion": "1.5",
+        "version": 1,
+        "services": [
+            {
+                "name": "billing-api",
+                "version": "2.1.0",
+                "data": [{"flow": "outbound", "classification": "PII"}]
+            }
+        ]
+    }"#;
+    let text = render_text(content, "test_pdf_content_services.pdf", &Config::default());
+
+    assert!(text.contains("Services"), "services section missing");
+    assert!(text.contains("billing-api2.1.0"), "service missing");
+    assert!(text.contains("DataFlows:"), "data flows missing");
+    assert!(text.contains("outbound:PII"), "data classification missing");
+}
+
+#[test]
+fn test_pdf_groups_vulnerabilities_by_severity() {
+    let config = Config {
+        group_by_severity: true,
+        ..Config::default()
+    };
+    let text = render_fixture_text(
+        "sample_vex.json",
+        "test_pdf_content_severity_groups.pdf",
+        &config,
+    );
+
+    let high = text.find("High(1)").expect("high severity group missing");
+    let medium = text
+        .find("Medium(1)")
+        .expect("medium severity group missing");
+    assert!(high < medium, "severity groups not ordered by severity");
+    assert!(!text.contains("Critical("), "empty severity group rendered");
+}
+
+#[test]
+fn test_pdf_prints_meta_footer() {
+    let config = Config {
+        meta_footer: true,
+        ..Config::default()
+    };
+    let text = render_fixture_text(
+        "sample_vex.json",
+        "test_pdf_content_meta_footer.pdf",
+        &config,
+    );
+
+    assert!(
+        text.contains("Serial:urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79|CycloneDX1.5"),
+        "meta footer missing"
+    );
+}
+
+#[test]
+fn test_pdf_derives_severity_with_configured_bands() {
+    let fixture_path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join("sample_vex.json");
+    // Leaves the rating with its score only
+    let content = fs::read_to_string(&fixture_path)
+        .expect("failed to read fixture")
+        .replace("\"severity\": \"high\",", "");
+    let config = Config {
+        severity_bands: "8.0,6.0,3.0".parse().unwrap(),
+        ..Config::default()
+    };
+    let text = render_text(
+        content.as_bytes(),
+        "test_pdf_content_severity_bands.pdf",
+        &config,
+    );
+
+    assert!(
+        text.contains("Critical,score8.1"),
+        "severity not derived with the configured bands"
+    );
+}
+
+#[test]
+fn test_pdf_links_purls_to_registry() {
+    let config = Config {
+        purl_links: true,
+        ..Config::default()
+    };
+    let text = render_fixture_text("sample_vex.json", "test_pdf_content_purls.pdf", &config);
+    assert!(
+        text.contains("Purl:pkg:cargo/fixture-lib@1.0.0"),
+        "purl missing"
+    );
+
+    let pdf = render_fixture_pdf(
+        "sample_vex.json",
+        "test_pdf_content_purl_links.pdf",
+        &config,
+    );
+    assert!(
+        contains_bytes(&pdf, "https://crates.io/crates/fixture-lib/1.0.0"),
+        "registry link missing"
+    );
+}
+
+#[test]
+fn test_pdf_links_affected_components_to_their_entry() {
+    let config = Config {
+        cross_refs: true,
+        ..Config::default()
+    };
+    let text = render_fixture_text(
+        "sample_vex.json",
+        "test_pdf_content_cross_refs.pdf",
+        &config,
+    );
+    assert!(
+        text.contains("Affects:[C1]fixture-lib"),
+        "affected component missing"
+    );
+
+    let pdf = render_fixture_pdf(
+        "sample_vex.json",
+        "test_pdf_content_cross_ref_links.pdf",
+        &config,
+    );
+    let pdf = lopdf::Document::load_mem(&pdf).expect("failed to load pdf");
+    let pages: Vec<lopdf::ObjectId> = pdf.get_pages().into_values().collect();
+    let destinations: Vec<lopdf::ObjectId> = pdf
+        .objects
+        .values()
+        .filter_map(|object| object.as_dict().ok())
+        .filter_map(|annotation| annotation.get(b"A").and_then(lopdf::Object::as_dict).ok())
+        .filter(|action| {
+            action
+                .get(b"S")
+                .and_then(lopdf::Object::as_name)
+                .is_ok_and(|name| name == b"GoTo")
+        })
+        .filter_map(|action| {
+            let destination = action.get(b"D").and_then(lopdf::Object::as_array).ok()?;
+            destination.first()?.as_reference().ok()
+        })
+        .collect();
+
+    assert!(!destinations.is_empty(), "cross-reference link missing");
+    assert!(
+        destinations.iter().all(|page_id| pages.contains(page_id)),
+        "cross-reference link does not jump to a page"
+    );
+}
+
+#[test]
+fn test_pdf_optimization_keeps_content_and_metadata() {
+    let plain = render_fixture_pdf(
+        "sample_vex.json",
+        "test_pdf_content_unoptimized.pdf",
+        &Config::default(),
+    );
+    let config = Config {
+        optimize: true,
+        ..Config::default()
+    };
+    let optimized =
+        render_fixture_pdf("sample_vex.json", "test_pdf_content_optimized.pdf", &config);
+
+    assert!(optimized.len() <= plain.len(), "optimized report is larger");
+    assert!(contains_bytes(
+        &optimized,
+        "<xmp:CreatorTool>vex2pdf</xmp:CreatorTool>"
+    ));
+
+    let text = render_fixture_text(
+        "sample_vex.json",
+        "test_pdf_content_optimized_text.pdf",
+        &config,
+    );
+    assert!(text.contains("CVE-2025-10001"), "vulnerability missing");
+}
+
+#[test]
+fn test_pdf_font_subsetting_shrinks_reports_and_keeps_text() {
+    let config = Config {
+        subset_fonts: false,
+        ..Config::default()
+    };
+    let complete = render_fixture_pdf(
+        "sample_vex.json",
+        "test_pdf_content_full_fonts.pdf",
+        &config,
+    );
+    let subset = render_fixture_pdf(
+        "sample_vex.json",
+        "test_pdf_content_subset_fonts.pdf",
+        &Config::default(),
+    );
+    assert!(
+        subset.len() * 2 < complete.len(),
+        "fonts were not subset: {} of {} bytes",
+        subset.len(),
+        complete.len()
+    );
+
+    let complete_text = render_fixture_text(
+        "sample_vex.json",
+        "test_pdf_content_full_fonts_text.pdf",
+        &config,
+    );
+    let subset_text = render_fixture_text(
+        "sample_vex.json",
+        "test_pdf_content_subset_fonts_text.pdf",
+        &Config::default(),
+    );
+    assert_eq!(subset_text, complete_text);
+}
+
+#[test]
+fn test_pdf_attribution_can_be_replaced_or_removed() {
+    let pdf = render_fixture_pdf(
+        "sample_vex.json",
+        "test_pdf_content_attribution_default.pdf",
+        &Config::default(),
+    );
+    assert!(contains_bytes(
+        &pdf,
+        "<xmp:CreatorTool>vex2pdf</xmp:CreatorTool>"
+    ));
+
+    let config = Config {
+        attribution: Some("Acme Reports".to_string()),
+        ..Config::default()
+    };
+    let pdf = render_fixture_pdf(
+        "sample_vex.json",
+        "test_pdf_content_attribution_custom.pdf",
+        &config,
+    );
+    assert!(contains_bytes(
+        &pdf,
+        "<xmp:CreatorTool>Acme Reports</xmp:CreatorTool>"
+    ));
+    assert!(!contains_bytes(&pdf, "vex2pdf"), "vex2pdf still credited");
+
+    let config = Config {
+        attribution: None,
+        ..Config::default()
+    };
+    let pdf = render_fixture_pdf(
+        "sample_vex.json",
+        "test_pdf_content_attribution_none.pdf",
+        &config,
+    );
+    assert!(!contains_bytes(&pdf, "CreatorTool"), "tool still credited");
+    assert!(!contains_bytes(&pdf, "vex2pdf"), "vex2pdf still credited");
+}
+
+#[test]
+fn test_pdf_renders_component_columns() {
+    let config = Config {
+        component_columns: Some(vec![
+            ComponentColumn::Name,
+            ComponentColumn::Purl,
+            ComponentColumn::License,
+        ]),
+        ..Config::default()
+    };
+    let text = render_fixture_text(
+        "sample_vex.json",
+        "test_pdf_content_component_columns.pdf",
+        &config,
+    );
+
+    assert!(text.contains("NamePurlLicense"), "column headers missing");
+    assert!(
+        text.contains("fixture-libpkg:cargo/fixture-lib@1.0.0N/A"),
+        "component row missing"
+    );
+    assert!(
+        !text.contains("Version:1.0.0"),
+        "component list rendered next to the table"
+    );
+}
+
+#[test]
+fn test_pdf_contains_completeness_section() {
+    let text = render_fixture_text(
+        "sample_vex.json",
+        "test_pdf_content_completeness_hidden.pdf",
+        &Config::default(),
+    );
+    assert!(
+        !text.contains("DataCompleteness"),
+        "completeness section shown by default"
+    );
+
+    let config = Config {
+        completeness_section: true,
+        ..Config::default()
+    };
+    let text = render_fixture_text(
+        "sample_vex.json",
+        "test_pdf_content_completeness.pdf",
+        &config,
+    );
+    assert!(
+        text.contains("DataCompleteness"),
+        "completeness section missing"
+    );
+    assert!(
+        text.contains("Completeness:100%across2vulnerabilities"),
+        "completeness score missing"
+    );
+    assert!(
+        text.contains("Withoutanalysisstate:0"),
+        "missing analysis states not counted"
+    );
+}
+
+#[test]
+fn test_pdf_lists_conversion_notes() {
+    let config = Config {
+        show_notes: true,
+        ..Config::default()
+    };
+    let text = render_fixture_text("sample_vex.json", "test_pdf_content_no_notes.pdf", &config);
+    assert!(text.contains("ConversionNotes"), "notes section missing");
+    assert!(
+        text.contains("Noissueswerenoticed"),
+        "empty notes not stated"
+    );
+
+    let collection = NoteCollection::start();
+    warn("downgraded from CycloneDX spec version 1.6 to 1.5");
+    let text = render_fixture_text("sample_vex.json", "test_pdf_content_notes.pdf", &config);
+    drop(collection);
+    assert!(
+        text.contains("downgradedfromCycloneDXspecversion1.6to1.5"),
+        "collected warning not listed"
+    );
+}
+
+#[test]
+fn test_pdf_renders_report_date_below_title() {
+    let config = Config {
+        report_date: Some("2026-01-15".to_string()),
+        ..Config::default()
+    };
+    let text = render_fixture_text(
+        "sample_vex.json",
+        "test_pdf_content_report_date.pdf",
+        &config,
+    );
+    assert!(
+        text.contains("Reportdate:2026-01-15"),
+        "configured report date missing"
+    );
+
+    // "now" follows the date the report is stamped with
+    let config = Config {
+        report_date: Some("now".to_string()),
+        deterministic: true,
+        source_date_epoch: Some(1_700_000_000),
+        ..Config::default()
+    };
+    let text = render_fixture_text(
+        "sample_vex.json",
+        "test_pdf_content_report_date_now.pdf",
+        &config,
+    );
+    assert!(
+        text.contains("Reportdate:2023-11-14"),
+        "report date of now missing"
+    );
+}
+
+#[test]
+fn test_pdf_filters_vulnerabilities_by_date_range() {
+    let config = Config {
+        since: Some("2025-04-01".to_string()),
+        ..Config::default()
+    };
+    let text = render_fixture_text("sample_vex.json", "test_pdf_content_since.pdf", &config);
+
+    assert!(text.contains("Showingvulnerabilitiespublishedsince2025-04-01"));
+    assert!(
+        !text.contains("CVE-2025-10001"),
+        "vulnerability published before the range rendered"
+    );
+    assert!(
+        text.contains("CVE-2025-10002"),
+        "vulnerability without date missing"
+    );
+}
+
+#[test]
+fn test_pdf_prefixes_severities_with_icons() {
+    let config = Config {
+        severity_icons: true,
+        ..Config::default()
+    };
+    let text = render_fixture_text("sample_vex.json", "test_pdf_content_icons.pdf", &config);
+
+    assert!(
+        text.contains("Severity:▲high"),
+        "high severity icon missing"
+    );
+    assert!(
+        text.contains("Severity:●medium"),
+        "medium severity icon missing"
+    );
+}
+
+#[test]
+fn test_pdf_lists_vulnerability_sources() {
+    let text = render_fixture_text(
+        "sample_vex.json",
+        "test_pdf_content_sources.pdf",
+        &Config::default(),
+    );
+
+    assert!(text.contains("Sources:NVD"), "sources summary missing");
+}
+
+#[test]
+fn test_pdf_renders_score_gauge_label() {
+    let config = Config {
+        score_gauge: true,
+        ..Config::default()
+    };
+    let text = render_fixture_text("sample_vex.json", "test_pdf_content_gauge.pdf", &config);
+
+    assert!(
+        text.contains("Maxscore:8.1/10"),
+        "score gauge label missing"
+    );
+}
+
+#[test]
+fn test_pdf_draws_score_gauge_bar() {
+    let config = Config {
+        score_gauge: true,
+        ..Config::default()
+    };
+    let without_gauge = render_fixture_pdf(
+        "sample_vex.json",
+        "test_pdf_content_no_gauge_bar.pdf",
+        &Config::default(),
+    );
+    let with_gauge =
+        render_fixture_pdf("sample_vex.json", "test_pdf_content_gauge_bar.pdf", &config);
+
+    // Track and bar of a 3mm gauge are filled with lines 0.3mm apart
+    assert!(
+        stroke_count(&with_gauge) >= stroke_count(&without_gauge) + 20,
+        "score gauge bar not drawn"
+    );
+}
+
+#[test]
+fn test_pdf_renders_severity_badge_next_to_id() {
+    let config = Config {
+        show_severity_badge: true,
+        ..Config::default()
+    };
+    let text = render_fixture_text("sample_vex.json", "test_pdf_content_badge.pdf", &config);
+
+    let id = text
+        .find("ID:CVE-2025-10001")
+        .expect("vulnerability ID missing");
+    let badge = text.find("High(8.1)").expect("severity badge missing");
+    assert!(id < badge, "severity badge not rendered next to the ID");
+}
+
+#[test]
+fn test_pdf_header_uses_configured_version() {
+    let pdf = render_fixture_pdf(
+        "sample_vex.json",
+        "test_pdf_content_default_version.pdf",
+        &Config::default(),
+    );
+    assert!(pdf.starts_with(b"%PDF-1.3"), "unexpected default version");
+
+    let config = Config {
+        pdf_version: Some("1.4".to_string()),
+        ..Config::default()
+    };
+    let pdf = render_fixture_pdf("sample_vex.json", "test_pdf_content_version.pdf", &config);
+    assert!(pdf.starts_with(b"%PDF-1.4"), "PDF version not set");
+}
+
+#[test]
+fn test_pdf_prints_classification_on_every_page() {
+    let config = Config {
+        classification: Some("UNCLASSIFIED//FOUO".to_string()),
+        ..Config::default()
+    };
+    let text = render_fixture_text(
+        "sample_vex.json",
+        "test_pdf_content_classification.pdf",
+        &config,
+    );
+    let pdf = render_fixture_pdf(
+        "sample_vex.json",
+        "test_pdf_content_classification_pages.pdf",
+        &config,
+    );
+    let pages = page_count(&pdf);
+
+    assert_eq!(
+        text.matches("UNCLASSIFIED//FOUO").count(),
+        2 * pages,
+        "classification not printed at the top and bottom of every page"
+    );
+}
+
+#[test]
+fn test_pdf_lists_analysis_responses() {
+    let text = render_fixture_text(
+        "sample_vex.json",
+        "test_pdf_content_responses.pdf",
+        &Config::default(),
+    );
+    assert!(
+        text.contains("responses:update,workaround_available"),
+        "comma separated responses missing"
+    );
+
+    let config = Config {
+        response_badges: true,
+        ..Config::default()
+    };
+    let text = render_fixture_text("sample_vex.json", "test_pdf_content_badges.pdf", &config);
+    assert!(
+        text.contains("update") && text.contains("workaround_available"),
+        "response badges missing"
+    );
+    assert!(
+        !text.contains("update,workaround_available"),
+        "responses rendered as text instead of badges"
+    );
+}
+
+#[test]
+fn test_deterministic_pdfs_are_identical() {
+    let fixture_path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join("sample_vex.json");
+    let content = fs::read(&fixture_path).expect("failed to read fixture");
+    let vex = parse_vex_json_bytes(&content).expect("failed to parse document");
+
+    let config = Config {
+        deterministic: true,
+        ..Config::default()
+    };
+    let generator = PdfGenerator::from_config(&config);
+    let render = |output_name: &str| {
+        let output_path = std::env::temp_dir().join(output_name);
+        generator
+            .generate_pdf(&vex, &output_path)
+            .expect("failed to generate pdf");
+        let bytes = fs::read(&output_path).expect("failed to read pdf");
+        fs::remove_file(&output_path).expect("Failed to remove temp file");
+        bytes
+    };
+
+    let first = render("test_pdf_content_deterministic_1.pdf");
+    let second = render("test_pdf_content_deterministic_2.pdf");
+
+    assert!(first == second, "deterministic renders differ");
+    assert!(
+        String::from_utf8_lossy(&first).contains("<xmp:CreateDate>2025-05-01T12:00:00Z"),
+        "creation date not taken from the BOM timestamp"
+    );
+}
+
+#[test]
+fn test_text_report_contains_sections() {
+    let fixture_path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join("sample_vex.json");
+    let content = fs::read(&fixture_path).expect("failed to read fixture");
+    let vex = parse_vex_json_bytes(&content).expect("failed to parse document");
+
+    let source_hash = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+    let text = PdfGenerator::default().generate_text(
+        &vex,
+        &ReportSource {
+            name: Some("sample_vex.json"),
+            sha256: Some(source_hash),
+        },
+    );
+
+    assert!(text.starts_with("Vulnerability Report Document\n=============================\n"));
+    assert!(text.contains("Source file: sample_vex.json\n"));
+    assert!(text.contains(&format!("Source SHA-256: {source_hash}\n")));
+    assert!(text.contains("\nCVE-2025-10001\n  Description:"));
+    assert!(text.contains("  Severity: high (CVSSv31 — Source: NVD)"));
+    assert!(text.contains("Components\n----------\n- fixture-lib\n  Version: 1.0.0\n"));
+
+    // Affected components are listed with cross-references only, like in the PDF
+    let config = Config {
+        cross_refs: true,
+        ..Config::default()
+    };
+    let text = PdfGenerator::from_config(&config).generate_text(&vex, &ReportSource::default());
+    assert!(text.contains("\nCVE-2025-10001\n  Affects: fixture-lib 1.0.0\n"));
+}
+
+#[test]
+fn test_shared_generator_renders_concurrently() {
+    let fixture_path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join("sample_vex.json");
+    let content = fs::read(&fixture_path).expect("failed to read fixture");
+    let vex = parse_vex_json_bytes(&content).expect("failed to parse document");
+    // Deterministic reports carry no render time, so every rendering has the same text
+    let config = Config {
+        deterministic: true,
+        ..Config::default()
+    };
+    let generator = Arc::new(PdfGenerator::from_config(&config));
+
+    let render = |generator: &PdfGenerator, output_name: String| {
+        let output_path = std::env::temp_dir().join(output_name);
+        generator
+            .generate_pdf(&vex, &output_path)
+            .expect("failed to generate pdf");
+        let text = pdf_extract::extract_text(&output_path).expect("failed to extract pdf text");
+        fs::remove_file(&output_path).expect("Failed to remove temp file");
+
+        text.split_whitespace().collect::<String>()
+    };
+    let expected = render(
+        &generator,
+        "test_pdf_content_concurrent_reference.pdf".to_string(),
+    );
+
+    let texts: Vec<String> = std::thread::scope(|scope| {
+        let handles: Vec<_> = (0..4)
+            .map(|thread| {
+                let generator = Arc::clone(&generator);
+                scope.spawn(move || {
+                    (0..2)
+                        .map(|run| {
+                            render(
+                                &generator,
+                                format!("test_pdf_content_concurrent_{thread}_{run}.pdf"),
+                            )
+                        })
+                        .collect::<Vec<String>>()
+                })
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().expect("rendering thread panicked"))
+            .collect()
+    });
+
+    assert_eq!(texts.len(), 8);
+    for text in texts {
+        assert_eq!(text, expected, "concurrent rendering differs");
+    }
+}